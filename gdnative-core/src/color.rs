@@ -5,6 +5,11 @@ use std::mem::transmute;
 /// RGBA color with 32 bits floating point components.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct Color {
     pub r: f32,
     pub g: f32,
@@ -13,14 +18,147 @@ pub struct Color {
 }
 
 impl Color {
-    pub fn rgba(r: f32, g: f32, b: f32, a: f32) -> Color {
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Color {
         Color { r, g, b, a }
     }
 
-    pub fn rgb(r: f32, g: f32, b: f32) -> Color {
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Color {
         Color { r, g, b, a: 1.0 }
     }
 
+    /// Creates a color from 8 bit components in the range of 0 - 255.
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color::rgba(
+            f32::from(r) / 255.0,
+            f32::from(g) / 255.0,
+            f32::from(b) / 255.0,
+            f32::from(a) / 255.0,
+        )
+    }
+
+    /// Creates a color from hue, saturation and value, in the range of 0.0 - 1.0.
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Color {
+        if s == 0.0 {
+            return Color::rgba(v, v, v, a);
+        }
+
+        let h = (h * 6.0) % 6.0;
+        let i = h.floor();
+        let f = h - i;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+
+        match i as i32 {
+            0 => Color::rgba(v, t, p, a),
+            1 => Color::rgba(q, v, p, a),
+            2 => Color::rgba(p, v, t, a),
+            3 => Color::rgba(p, q, v, a),
+            4 => Color::rgba(t, p, v, a),
+            _ => Color::rgba(v, p, q, a),
+        }
+    }
+
+    /// Parses a color from an HTML hexadecimal color string, with an optional leading `#`.
+    ///
+    /// The accepted formats are `rgb`, `argb`, `rrggbb` and `aarrggbb`, the same as in Godot.
+    /// Returns `None` if the string isn't a valid color.
+    pub fn from_html(html: &str) -> Option<Color> {
+        let html = html.strip_prefix('#').unwrap_or(html);
+        if !html.is_ascii() {
+            return None;
+        }
+
+        let expanded;
+        let html = match html.len() {
+            3 | 4 => {
+                expanded = html.chars().flat_map(|c| vec![c, c]).collect::<String>();
+                expanded.as_str()
+            }
+            6 | 8 => html,
+            _ => return None,
+        };
+
+        let component = |i: usize| u8::from_str_radix(&html[i..i + 2], 16).ok();
+        let (a, rgb) = if html.len() == 8 {
+            (component(0)?, 2)
+        } else {
+            (255, 0)
+        };
+
+        Some(Color::from_rgba8(
+            component(rgb)?,
+            component(rgb + 2)?,
+            component(rgb + 4)?,
+            a,
+        ))
+    }
+
+    /// Returns the color as an HTML hexadecimal color string, without the leading `#`.
+    ///
+    /// The format is `rrggbb`, or `aarrggbb` if `with_alpha` is `true`, the same as in Godot.
+    pub fn to_html(&self, with_alpha: bool) -> String {
+        let [r, g, b, a] = self.to_rgba8();
+        if with_alpha {
+            format!("{:02x}{:02x}{:02x}{:02x}", a, r, g, b)
+        } else {
+            format!("{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+
+    /// Returns the components as 8 bit values, in RGBA order.
+    fn to_rgba8(self) -> [u8; 4] {
+        let to_u8 = |c: f32| (c * 255.0).round().clamp(0.0, 255.0) as u8;
+        [to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)]
+    }
+
+    /// Returns the components as 16 bit values, in RGBA order.
+    fn to_rgba16(self) -> [u16; 4] {
+        let to_u16 = |c: f32| (c * 65535.0).round().clamp(0.0, 65535.0) as u16;
+        [
+            to_u16(self.r),
+            to_u16(self.g),
+            to_u16(self.b),
+            to_u16(self.a),
+        ]
+    }
+
+    /// Returns the color packed as a 32 bit integer with 8 bits per component, in RGBA order.
+    pub fn to_rgba32(&self) -> u32 {
+        let [r, g, b, a] = self.to_rgba8();
+        u32::from_be_bytes([r, g, b, a])
+    }
+
+    /// Returns the color packed as a 32 bit integer with 8 bits per component, in ARGB order.
+    pub fn to_argb32(&self) -> u32 {
+        let [r, g, b, a] = self.to_rgba8();
+        u32::from_be_bytes([a, r, g, b])
+    }
+
+    /// Returns the color packed as a 32 bit integer with 8 bits per component, in ABGR order.
+    pub fn to_abgr32(&self) -> u32 {
+        let [r, g, b, a] = self.to_rgba8();
+        u32::from_be_bytes([a, b, g, r])
+    }
+
+    /// Returns the color packed as a 64 bit integer with 16 bits per component, in RGBA order.
+    pub fn to_rgba64(&self) -> u64 {
+        let [r, g, b, a] = self.to_rgba16();
+        pack64([r, g, b, a])
+    }
+
+    /// Returns the color packed as a 64 bit integer with 16 bits per component, in ARGB order.
+    pub fn to_argb64(&self) -> u64 {
+        let [r, g, b, a] = self.to_rgba16();
+        pack64([a, r, g, b])
+    }
+
+    /// Returns the color packed as a 64 bit integer with 16 bits per component, in ABGR order.
+    pub fn to_abgr64(&self) -> u64 {
+        let [r, g, b, a] = self.to_rgba16();
+        pack64([a, b, g, r])
+    }
+
     pub fn h(&self) -> f32 {
         unsafe { (get_api().godot_color_get_h)(self.sys()) }
     }
@@ -33,6 +171,113 @@ impl Color {
         unsafe { (get_api().godot_color_get_v)(self.sys()) }
     }
 
+    /// Returns the average of the red, green and blue components.
+    pub fn gray(&self) -> f32 {
+        (self.r + self.g + self.b) / 3.0
+    }
+
+    /// Linearly interpolates between this color and `b`, including the alpha component.
+    /// `t` is in the range of 0.0 - 1.0.
+    pub fn lerp(&self, b: Color, t: f32) -> Color {
+        Color::rgba(
+            self.r + (b.r - self.r) * t,
+            self.g + (b.g - self.g) * t,
+            self.b + (b.b - self.b) * t,
+            self.a + (b.a - self.a) * t,
+        )
+    }
+
+    /// Returns the result of drawing `over` on top of this color, using alpha compositing.
+    pub fn blend(&self, over: Color) -> Color {
+        let sa = 1.0 - over.a;
+        let a = self.a * sa + over.a;
+        if a == 0.0 {
+            return Color::rgba(0.0, 0.0, 0.0, 0.0);
+        }
+
+        Color::rgba(
+            (self.r * self.a * sa + over.r * over.a) / a,
+            (self.g * self.a * sa + over.g * over.a) / a,
+            (self.b * self.a * sa + over.b * over.a) / a,
+            a,
+        )
+    }
+
+    /// Returns the color darkened by `amount`, in the range of 0.0 - 1.0.
+    pub fn darkened(&self, amount: f32) -> Color {
+        Color::rgba(
+            self.r * (1.0 - amount),
+            self.g * (1.0 - amount),
+            self.b * (1.0 - amount),
+            self.a,
+        )
+    }
+
+    /// Returns the color lightened by `amount`, in the range of 0.0 - 1.0.
+    pub fn lightened(&self, amount: f32) -> Color {
+        Color::rgba(
+            self.r + (1.0 - self.r) * amount,
+            self.g + (1.0 - self.g) * amount,
+            self.b + (1.0 - self.b) * amount,
+            self.a,
+        )
+    }
+
+    /// Returns the inverted color `(1 - r, 1 - g, 1 - b, a)`.
+    pub fn inverted(&self) -> Color {
+        Color::rgba(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a)
+    }
+
+    /// Returns the most contrasting color, by offsetting the hue of the color by half.
+    pub fn contrasted(&self) -> Color {
+        Color::rgba(
+            (self.r + 0.5) % 1.0,
+            (self.g + 0.5) % 1.0,
+            (self.b + 0.5) % 1.0,
+            self.a,
+        )
+    }
+
+    /// Converts the color from the sRGB color space to the linear one.
+    pub fn to_linear(&self) -> Color {
+        let convert = |c: f32| {
+            if c < 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        Color::rgba(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Converts the color from the linear color space to the sRGB one.
+    pub fn to_srgb(&self) -> Color {
+        let convert = |c: f32| {
+            if c < 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+
+        Color::rgba(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Returns the named color constant with the given name, ignoring case, spaces,
+    /// underscores, dashes, apostrophes and periods, like Godot's `ColorN`.
+    pub fn named(name: &str) -> Option<Color> {
+        let name = name
+            .chars()
+            .filter(|c| !" _-'.".contains(*c))
+            .collect::<String>();
+
+        NAMED_COLORS
+            .iter()
+            .find(|(named, _)| named.replace('_', "").eq_ignore_ascii_case(&name))
+            .map(|(_, color)| *color)
+    }
+
     #[doc(hidden)]
     pub fn sys(&self) -> &sys::godot_color {
         unsafe { transmute(self) }
@@ -44,8 +289,306 @@ impl Color {
     }
 }
 
+fn pack64(components: [u16; 4]) -> u64 {
+    components
+        .iter()
+        .fold(0, |packed, &c| (packed << 16) | u64::from(c))
+}
+
+macro_rules! named_colors {
+    ($($name:ident = ($r:expr, $g:expr, $b:expr);)*) => {
+        /// Godot's named colors, which are the X11 colors.
+        impl Color {
+            /// Transparent white.
+            pub const TRANSPARENT: Color = Color::rgba(1.0, 1.0, 1.0, 0.0);
+
+            $(
+                pub const $name: Color = Color::rgb($r, $g, $b);
+            )*
+        }
+
+        const NAMED_COLORS: &[(&str, Color)] = &[
+            ("TRANSPARENT", Color::TRANSPARENT),
+            $((stringify!($name), Color::$name),)*
+        ];
+    };
+}
+
+named_colors! {
+    ALICE_BLUE = (0.94, 0.97, 1.0);
+    ANTIQUE_WHITE = (0.98, 0.92, 0.84);
+    AQUA = (0.0, 1.0, 1.0);
+    AQUAMARINE = (0.5, 1.0, 0.83);
+    AZURE = (0.94, 1.0, 1.0);
+    BEIGE = (0.96, 0.96, 0.86);
+    BISQUE = (1.0, 0.89, 0.77);
+    BLACK = (0.0, 0.0, 0.0);
+    BLANCHED_ALMOND = (1.0, 0.92, 0.8);
+    BLUE = (0.0, 0.0, 1.0);
+    BLUE_VIOLET = (0.54, 0.17, 0.89);
+    BROWN = (0.65, 0.16, 0.16);
+    BURLYWOOD = (0.87, 0.72, 0.53);
+    CADET_BLUE = (0.37, 0.62, 0.63);
+    CHARTREUSE = (0.5, 1.0, 0.0);
+    CHOCOLATE = (0.82, 0.41, 0.12);
+    CORAL = (1.0, 0.5, 0.31);
+    CORNFLOWER = (0.39, 0.58, 0.93);
+    CORNSILK = (1.0, 0.97, 0.86);
+    CRIMSON = (0.86, 0.08, 0.24);
+    CYAN = (0.0, 1.0, 1.0);
+    DARK_BLUE = (0.0, 0.0, 0.55);
+    DARK_CYAN = (0.0, 0.55, 0.55);
+    DARK_GOLDENROD = (0.72, 0.53, 0.04);
+    DARK_GRAY = (0.66, 0.66, 0.66);
+    DARK_GREEN = (0.0, 0.39, 0.0);
+    DARK_KHAKI = (0.74, 0.72, 0.42);
+    DARK_MAGENTA = (0.55, 0.0, 0.55);
+    DARK_OLIVE_GREEN = (0.33, 0.42, 0.18);
+    DARK_ORANGE = (1.0, 0.55, 0.0);
+    DARK_ORCHID = (0.6, 0.2, 0.8);
+    DARK_RED = (0.55, 0.0, 0.0);
+    DARK_SALMON = (0.91, 0.59, 0.48);
+    DARK_SEA_GREEN = (0.56, 0.74, 0.56);
+    DARK_SLATE_BLUE = (0.28, 0.24, 0.55);
+    DARK_SLATE_GRAY = (0.18, 0.31, 0.31);
+    DARK_TURQUOISE = (0.0, 0.81, 0.82);
+    DARK_VIOLET = (0.58, 0.0, 0.83);
+    DEEP_PINK = (1.0, 0.08, 0.58);
+    DEEP_SKY_BLUE = (0.0, 0.75, 1.0);
+    DIM_GRAY = (0.41, 0.41, 0.41);
+    DODGER_BLUE = (0.12, 0.56, 1.0);
+    FIREBRICK = (0.7, 0.13, 0.13);
+    FLORAL_WHITE = (1.0, 0.98, 0.94);
+    FOREST_GREEN = (0.13, 0.55, 0.13);
+    FUCHSIA = (1.0, 0.0, 1.0);
+    GAINSBORO = (0.86, 0.86, 0.86);
+    GHOST_WHITE = (0.97, 0.97, 1.0);
+    GOLD = (1.0, 0.84, 0.0);
+    GOLDENROD = (0.85, 0.65, 0.13);
+    GRAY = (0.75, 0.75, 0.75);
+    GREEN = (0.0, 1.0, 0.0);
+    GREEN_YELLOW = (0.68, 1.0, 0.18);
+    HONEYDEW = (0.94, 1.0, 0.94);
+    HOT_PINK = (1.0, 0.41, 0.71);
+    INDIAN_RED = (0.8, 0.36, 0.36);
+    INDIGO = (0.29, 0.0, 0.51);
+    IVORY = (1.0, 1.0, 0.94);
+    KHAKI = (0.94, 0.9, 0.55);
+    LAVENDER = (0.9, 0.9, 0.98);
+    LAVENDER_BLUSH = (1.0, 0.94, 0.96);
+    LAWN_GREEN = (0.49, 0.99, 0.0);
+    LEMON_CHIFFON = (1.0, 0.98, 0.8);
+    LIGHT_BLUE = (0.68, 0.85, 0.9);
+    LIGHT_CORAL = (0.94, 0.5, 0.5);
+    LIGHT_CYAN = (0.88, 1.0, 1.0);
+    LIGHT_GOLDENROD = (0.98, 0.98, 0.82);
+    LIGHT_GRAY = (0.83, 0.83, 0.83);
+    LIGHT_GREEN = (0.56, 0.93, 0.56);
+    LIGHT_PINK = (1.0, 0.71, 0.76);
+    LIGHT_SALMON = (1.0, 0.63, 0.48);
+    LIGHT_SEA_GREEN = (0.13, 0.7, 0.67);
+    LIGHT_SKY_BLUE = (0.53, 0.81, 0.98);
+    LIGHT_SLATE_GRAY = (0.47, 0.53, 0.6);
+    LIGHT_STEEL_BLUE = (0.69, 0.77, 0.87);
+    LIGHT_YELLOW = (1.0, 1.0, 0.88);
+    LIME = (0.0, 1.0, 0.0);
+    LIME_GREEN = (0.2, 0.8, 0.2);
+    LINEN = (0.98, 0.94, 0.9);
+    MAGENTA = (1.0, 0.0, 1.0);
+    MAROON = (0.69, 0.19, 0.38);
+    MEDIUM_AQUAMARINE = (0.4, 0.8, 0.67);
+    MEDIUM_BLUE = (0.0, 0.0, 0.8);
+    MEDIUM_ORCHID = (0.73, 0.33, 0.83);
+    MEDIUM_PURPLE = (0.58, 0.44, 0.86);
+    MEDIUM_SEA_GREEN = (0.24, 0.7, 0.44);
+    MEDIUM_SLATE_BLUE = (0.48, 0.41, 0.93);
+    MEDIUM_SPRING_GREEN = (0.0, 0.98, 0.6);
+    MEDIUM_TURQUOISE = (0.28, 0.82, 0.8);
+    MEDIUM_VIOLET_RED = (0.78, 0.08, 0.52);
+    MIDNIGHT_BLUE = (0.1, 0.1, 0.44);
+    MINT_CREAM = (0.96, 1.0, 0.98);
+    MISTY_ROSE = (1.0, 0.89, 0.88);
+    MOCCASIN = (1.0, 0.89, 0.71);
+    NAVAJO_WHITE = (1.0, 0.87, 0.68);
+    NAVY_BLUE = (0.0, 0.0, 0.5);
+    OLD_LACE = (0.99, 0.96, 0.9);
+    OLIVE = (0.5, 0.5, 0.0);
+    OLIVE_DRAB = (0.42, 0.56, 0.14);
+    ORANGE = (1.0, 0.65, 0.0);
+    ORANGE_RED = (1.0, 0.27, 0.0);
+    ORCHID = (0.85, 0.44, 0.84);
+    PALE_GOLDENROD = (0.93, 0.91, 0.67);
+    PALE_GREEN = (0.6, 0.98, 0.6);
+    PALE_TURQUOISE = (0.69, 0.93, 0.93);
+    PALE_VIOLET_RED = (0.86, 0.44, 0.58);
+    PAPAYA_WHIP = (1.0, 0.94, 0.84);
+    PEACH_PUFF = (1.0, 0.85, 0.73);
+    PERU = (0.8, 0.52, 0.25);
+    PINK = (1.0, 0.75, 0.8);
+    PLUM = (0.87, 0.63, 0.87);
+    POWDER_BLUE = (0.69, 0.88, 0.9);
+    PURPLE = (0.63, 0.13, 0.94);
+    REBECCA_PURPLE = (0.4, 0.2, 0.6);
+    RED = (1.0, 0.0, 0.0);
+    ROSY_BROWN = (0.74, 0.56, 0.56);
+    ROYAL_BLUE = (0.25, 0.41, 0.88);
+    SADDLE_BROWN = (0.55, 0.27, 0.07);
+    SALMON = (0.98, 0.5, 0.45);
+    SANDY_BROWN = (0.96, 0.64, 0.38);
+    SEA_GREEN = (0.18, 0.55, 0.34);
+    SEASHELL = (1.0, 0.96, 0.93);
+    SIENNA = (0.63, 0.32, 0.18);
+    SILVER = (0.75, 0.75, 0.75);
+    SKY_BLUE = (0.53, 0.81, 0.92);
+    SLATE_BLUE = (0.42, 0.35, 0.8);
+    SLATE_GRAY = (0.44, 0.5, 0.56);
+    SNOW = (1.0, 0.98, 0.98);
+    SPRING_GREEN = (0.0, 1.0, 0.5);
+    STEEL_BLUE = (0.27, 0.51, 0.71);
+    TAN = (0.82, 0.71, 0.55);
+    TEAL = (0.0, 0.5, 0.5);
+    THISTLE = (0.85, 0.75, 0.85);
+    TOMATO = (1.0, 0.39, 0.28);
+    TURQUOISE = (0.25, 0.88, 0.82);
+    VIOLET = (0.93, 0.51, 0.93);
+    WEB_GRAY = (0.5, 0.5, 0.5);
+    WEB_GREEN = (0.0, 0.5, 0.0);
+    WEB_MAROON = (0.5, 0.0, 0.0);
+    WEB_PURPLE = (0.5, 0.0, 0.5);
+    WHEAT = (0.96, 0.87, 0.7);
+    WHITE = (1.0, 1.0, 1.0);
+    WHITE_SMOKE = (0.96, 0.96, 0.96);
+    YELLOW = (1.0, 1.0, 0.0);
+    YELLOW_GREEN = (0.6, 0.8, 0.2);
+}
+
 #[test]
 fn color_repr() {
     use std::mem::size_of;
     assert_eq!(size_of::<Color>(), size_of::<sys::godot_color>());
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Color;
+    use euclid::approxeq::ApproxEq;
+
+    fn approx_eq(a: Color, b: Color) -> bool {
+        a.r.approx_eq(&b.r) && a.g.approx_eq(&b.g) && a.b.approx_eq(&b.b) && a.a.approx_eq(&b.a)
+    }
+
+    #[test]
+    fn from_hsv_is_sane() {
+        assert!(approx_eq(
+            Color::rgb(1.0, 0.0, 0.0),
+            Color::from_hsv(0.0, 1.0, 1.0, 1.0)
+        ));
+        assert!(approx_eq(
+            Color::rgb(0.0, 1.0, 0.0),
+            Color::from_hsv(1.0 / 3.0, 1.0, 1.0, 1.0)
+        ));
+        assert!(approx_eq(
+            Color::rgba(0.5, 0.25, 0.5, 0.5),
+            Color::from_hsv(5.0 / 6.0, 0.5, 0.5, 0.5)
+        ));
+        assert!(approx_eq(
+            Color::rgb(0.3, 0.3, 0.3),
+            Color::from_hsv(0.7, 0.0, 0.3, 1.0)
+        ));
+    }
+
+    #[test]
+    fn html_roundtrip() {
+        let color = Color::from_rgba8(0x12, 0x34, 0xab, 0x80);
+        assert_eq!("801234ab", color.to_html(true));
+        assert_eq!("1234ab", color.to_html(false));
+        assert_eq!(Some(color), Color::from_html("#801234ab"));
+        assert_eq!(Some(color), Color::from_html("801234AB"));
+
+        assert_eq!(
+            Some(Color::from_rgba8(0x11, 0x22, 0x33, 0xff)),
+            Color::from_html("#123")
+        );
+        assert_eq!(
+            Some(Color::from_rgba8(0x22, 0x33, 0x44, 0x11)),
+            Color::from_html("1234")
+        );
+
+        assert_eq!(None, Color::from_html("#12345"));
+        assert_eq!(None, Color::from_html("#12345g"));
+        assert_eq!(None, Color::from_html("#1234é"));
+    }
+
+    #[test]
+    fn packed_integers_are_sane() {
+        let color = Color::from_rgba8(0x12, 0x34, 0x56, 0x78);
+        assert_eq!(0x1234_5678, color.to_rgba32());
+        assert_eq!(0x7812_3456, color.to_argb32());
+        assert_eq!(0x7856_3412, color.to_abgr32());
+
+        let color = Color::rgba(1.0, 0.0, 0.5, 1.0);
+        assert_eq!(0xffff_0000_8000_ffff, color.to_rgba64());
+        assert_eq!(0xffff_ffff_0000_8000, color.to_argb64());
+        assert_eq!(0xffff_8000_0000_ffff, color.to_abgr64());
+
+        // components outside of the range are clamped
+        assert_eq!(0xff00_00ff, Color::rgba(2.0, -1.0, 0.0, 1.0).to_rgba32());
+    }
+
+    #[test]
+    fn lerp_and_blend_are_sane() {
+        let a = Color::rgba(0.0, 0.2, 0.4, 1.0);
+        let b = Color::rgba(1.0, 0.4, 0.0, 0.0);
+        assert!(approx_eq(Color::rgba(0.5, 0.3, 0.2, 0.5), a.lerp(b, 0.5)));
+
+        assert!(approx_eq(a, a.blend(b)));
+        assert!(approx_eq(
+            Color::rgba(0.5, 0.3, 0.2, 1.0),
+            a.blend(Color::rgba(1.0, 0.4, 0.0, 0.5))
+        ));
+        assert_eq!(
+            Color::rgba(0.0, 0.0, 0.0, 0.0),
+            Color::TRANSPARENT.blend(Color::TRANSPARENT)
+        );
+    }
+
+    #[test]
+    fn adjustments_are_sane() {
+        let color = Color::rgba(0.2, 0.4, 0.8, 0.5);
+        assert!(approx_eq(
+            Color::rgba(0.1, 0.2, 0.4, 0.5),
+            color.darkened(0.5)
+        ));
+        assert!(approx_eq(
+            Color::rgba(0.6, 0.7, 0.9, 0.5),
+            color.lightened(0.5)
+        ));
+        assert!(approx_eq(Color::rgba(0.8, 0.6, 0.2, 0.5), color.inverted()));
+        assert!(approx_eq(
+            Color::rgba(0.7, 0.9, 0.3, 0.5),
+            color.contrasted()
+        ));
+        assert!(0.466_666_67.approx_eq(&color.gray()));
+    }
+
+    #[test]
+    fn color_space_roundtrip() {
+        let color = Color::rgba(0.02, 0.5, 1.0, 0.5);
+        let linear = color.to_linear();
+        assert!((0.02 / 12.92).approx_eq(&linear.r));
+        assert!(0.214_041_14.approx_eq(&linear.g));
+        assert!(1.0.approx_eq(&linear.b));
+        assert!(0.5.approx_eq(&linear.a));
+        assert!(approx_eq(color, linear.to_srgb()));
+    }
+
+    #[test]
+    fn named_colors_are_sane() {
+        assert_eq!(Color::rgb(0.94, 0.97, 1.0), Color::ALICE_BLUE);
+        assert_eq!(Some(Color::ALICE_BLUE), Color::named("aliceblue"));
+        assert_eq!(Some(Color::ALICE_BLUE), Color::named("Alice Blue"));
+        assert_eq!(Some(Color::WEB_GRAY), Color::named("web_gray"));
+        assert_eq!(Some(Color::TRANSPARENT), Color::named("transparent"));
+        assert_eq!(None, Color::named("not a color"));
+    }
+}
@@ -1,10 +1,15 @@
 use crate::get_api;
 use crate::sys;
+use crate::FromVariant;
+use crate::FromVariantError;
 use crate::GodotString;
+use crate::ToVariant;
 
 use crate::Variant;
 use crate::VariantArray;
 use std::fmt;
+use std::iter::{Extend, FromIterator};
+use std::ptr;
 
 /// A reference-counted `Dictionary` of `Variant` key-value pairs.
 pub struct Dictionary(pub(crate) sys::godot_dictionary);
@@ -50,6 +55,27 @@ impl Dictionary {
         unsafe { Variant((get_api().godot_dictionary_get)(&self.0, &key.0)) }
     }
 
+    /// Returns the value corresponding to the key, converted to `T`, or `None` if the key
+    /// doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hp: Option<i64> = dict.get_as("hp")?;
+    /// ```
+    pub fn get_as<K, T>(&self, key: &K) -> Result<Option<T>, FromVariantError>
+    where
+        K: ToVariant + ?Sized,
+        T: FromVariant,
+    {
+        let key = key.to_variant();
+        if self.contains(&key) {
+            T::from_variant(self.get_ref(&key)).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Sets a value to the element corresponding to the key.
     pub fn set(&mut self, key: &Variant, val: &Variant) {
         unsafe { (get_api().godot_dictionary_set)(&mut self.0, &key.0, &val.0) }
@@ -93,6 +119,14 @@ impl Dictionary {
         unsafe { Variant::cast_ref((get_api().godot_dictionary_next)(&self.0, &key.0)) }
     }
 
+    /// Returns an iterator over the key-value pairs of the `Dictionary`, in insertion order.
+    pub fn iter(&self) -> DictionaryIter {
+        DictionaryIter {
+            dict: self,
+            last_key: ptr::null(),
+        }
+    }
+
     /// Return a hashed i32 value representing the dictionary's contents.
     pub fn hash(&self) -> i32 {
         unsafe { (get_api().godot_dictionary_hash)(&self.0) }
@@ -128,6 +162,97 @@ impl fmt::Debug for Dictionary {
     }
 }
 
+/// Iterator over the key-value pairs of a `Dictionary`, created by `Dictionary::iter`.
+pub struct DictionaryIter<'a> {
+    dict: &'a Dictionary,
+    last_key: *const sys::godot_variant,
+}
+
+impl<'a> Iterator for DictionaryIter<'a> {
+    type Item = (&'a Variant, &'a Variant);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let key = (get_api().godot_dictionary_next)(&self.dict.0, self.last_key);
+            if key.is_null() {
+                return None;
+            }
+            self.last_key = key;
+            let key = Variant::cast_ref(key);
+            Some((key, self.dict.get_ref(key)))
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Dictionary {
+    type Item = (&'a Variant, &'a Variant);
+    type IntoIter = DictionaryIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Owning iterator over the key-value pairs of a `Dictionary`.
+pub struct DictionaryIntoIter {
+    dict: Dictionary,
+    last_key: Option<Variant>,
+}
+
+impl Iterator for DictionaryIntoIter {
+    type Item = (Variant, Variant);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            let last_key = self.last_key.as_ref().map_or(ptr::null(), |key| key.sys());
+            let key = (get_api().godot_dictionary_next)(&self.dict.0, last_key);
+            if key.is_null() {
+                return None;
+            }
+            let key = Variant::cast_ref(key).clone();
+            let value = self.dict.get(&key);
+            self.last_key = Some(key.clone());
+            Some((key, value))
+        }
+    }
+}
+
+impl IntoIterator for Dictionary {
+    type Item = (Variant, Variant);
+    type IntoIter = DictionaryIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DictionaryIntoIter {
+            dict: self,
+            last_key: None,
+        }
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Dictionary
+where
+    K: ToVariant,
+    V: ToVariant,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = Dictionary::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<K, V> Extend<(K, V)> for Dictionary
+where
+    K: ToVariant,
+    V: ToVariant,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.set(&key.to_variant(), &value.to_variant());
+        }
+    }
+}
+
 godot_test!(test_dictionary {
     use crate::VariantType;
     let foo = Variant::from_str("foo");
@@ -172,6 +297,30 @@ godot_test!(test_dictionary {
     }
 });
 
+godot_test!(test_dictionary_iter {
+    let mut dict: Dictionary = vec![("foo".to_string(), 1), ("bar".to_string(), 2)]
+        .into_iter()
+        .collect();
+    dict.extend(vec![("baz".to_string(), 3)]);
+    assert_eq!(3, dict.len());
+
+    let pairs = dict
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_i64()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![("foo".to_string(), 1), ("bar".to_string(), 2), ("baz".to_string(), 3)],
+        pairs
+    );
+
+    let values = dict.new_ref().into_iter().map(|(_, value)| value.to_i64()).sum::<i64>();
+    assert_eq!(6, values);
+
+    assert_eq!(Ok(Some(2)), dict.get_as::<_, i64>("bar"));
+    assert_eq!(Ok(None), dict.get_as::<_, i64>("nope"));
+    assert!(dict.get_as::<_, bool>("bar").is_err());
+});
+
 // TODO: clear dictionaries without affecting clones
 //godot_test!(test_dictionary_clone_clear {
 //    let foo = Variant::from_str("foo");
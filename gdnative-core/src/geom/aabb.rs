@@ -3,12 +3,227 @@ use crate::Vector3;
 /// Axis-aligned bounding box.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct Aabb {
     pub position: Vector3,
     pub size: Vector3,
 }
 
 impl Aabb {
+    /// Creates a bounding box from its position, i.e. its minimum corner, and its size.
+    #[inline]
+    pub fn new(position: Vector3, size: Vector3) -> Self {
+        Aabb { position, size }
+    }
+
+    /// Returns the maximum corner of the box.
+    #[inline]
+    pub fn end(&self) -> Vector3 {
+        self.position + self.size
+    }
+
+    /// Returns the volume of the box.
+    #[inline]
+    pub fn get_area(&self) -> f32 {
+        self.size.x * self.size.y * self.size.z
+    }
+
+    /// Returns the smallest box containing both `self` and `other`.
+    #[inline]
+    pub fn merge(&self, other: &Aabb) -> Self {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Aabb::new(position, end - position)
+    }
+
+    /// Returns `true` if the box overlaps with `other`. Boxes that only share a face don't
+    /// overlap.
+    #[inline]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+
+        self.position.x < other_end.x
+            && end.x > other.position.x
+            && self.position.y < other_end.y
+            && end.y > other.position.y
+            && self.position.z < other_end.z
+            && end.z > other.position.z
+    }
+
+    /// Returns `true` if the box completely encloses `other`.
+    #[inline]
+    pub fn encloses(&self, other: &Aabb) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+
+        self.position.x <= other.position.x
+            && end.x > other_end.x
+            && self.position.y <= other.position.y
+            && end.y > other_end.y
+            && self.position.z <= other.position.z
+            && end.z > other_end.z
+    }
+
+    /// Returns the box extended by `by` on all sides.
+    #[inline]
+    pub fn grow(&self, by: f32) -> Self {
+        let by = Vector3::new(by, by, by);
+        Aabb::new(self.position - by, self.size + by * 2.0)
+    }
+
+    /// Returns `true` if `point` is inside the box or on its surface.
+    #[inline]
+    pub fn has_point(&self, point: Vector3) -> bool {
+        let end = self.end();
+
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.z >= self.position.z
+            && point.x <= end.x
+            && point.y <= end.y
+            && point.z <= end.z
+    }
+
+    /// Returns the corner of the box that is the furthest in the direction opposite to
+    /// `normal`, like Godot 3's `AABB::get_support`. This is the corner that is the furthest
+    /// behind a plane with that normal, which is useful for collision detection algorithms.
+    #[inline]
+    pub fn get_support(&self, normal: Vector3) -> Vector3 {
+        let end = self.end();
+        let pick = |normal: f32, min: f32, max: f32| if normal > 0.0 { min } else { max };
+
+        Vector3::new(
+            pick(normal.x, self.position.x, end.x),
+            pick(normal.y, self.position.y, end.y),
+            pick(normal.z, self.position.z, end.z),
+        )
+    }
+
+    /// Returns `true` if the ray starting at `from` in the direction `dir` hits the box.
+    pub fn intersects_ray(&self, from: Vector3, dir: Vector3) -> bool {
+        let (position, end) = (self.position.to_array(), self.end().to_array());
+        let (from, dir) = (from.to_array(), dir.to_array());
+
+        let mut near = f32::MIN;
+        let mut far = f32::MAX;
+
+        for i in 0..3 {
+            if dir[i] == 0.0 {
+                // parallel to the faces on this axis
+                if from[i] < position[i] || from[i] > end[i] {
+                    return false;
+                }
+            } else {
+                let c1 = (position[i] - from[i]) / dir[i];
+                let c2 = (end[i] - from[i]) / dir[i];
+                let (c1, c2) = if c1 > c2 { (c2, c1) } else { (c1, c2) };
+
+                near = near.max(c1);
+                far = far.min(c2);
+
+                if near > far || far < 0.0 {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns `true` if the segment from `from` to `to` intersects the box.
+    pub fn intersects_segment(&self, from: Vector3, to: Vector3) -> bool {
+        let (position, end) = (self.position.to_array(), self.end().to_array());
+        let (from, to) = (from.to_array(), to.to_array());
+
+        let mut min = 0.0f32;
+        let mut max = 1.0f32;
+
+        for i in 0..3 {
+            let (seg_from, seg_to) = (from[i], to[i]);
+            let (box_begin, box_end) = (position[i], end[i]);
+            let length = seg_to - seg_from;
+
+            let (cmin, cmax) = if seg_from < seg_to {
+                if seg_from > box_end || seg_to < box_begin {
+                    return false;
+                }
+
+                (
+                    if seg_from < box_begin {
+                        (box_begin - seg_from) / length
+                    } else {
+                        0.0
+                    },
+                    if seg_to > box_end {
+                        (box_end - seg_from) / length
+                    } else {
+                        1.0
+                    },
+                )
+            } else {
+                if seg_to > box_end || seg_from < box_begin {
+                    return false;
+                }
+
+                (
+                    if seg_from > box_end {
+                        (box_end - seg_from) / length
+                    } else {
+                        0.0
+                    },
+                    if seg_to < box_begin {
+                        (box_begin - seg_from) / length
+                    } else {
+                        1.0
+                    },
+                )
+            };
+
+            min = min.max(cmin);
+            max = max.min(cmax);
+
+            if max < min {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the index of the longest axis of the box: 0 for X, 1 for Y and 2 for Z.
+    #[inline]
+    pub fn get_longest_axis_index(&self) -> usize {
+        let mut index = 0;
+        let mut max_size = self.size.x;
+
+        if self.size.y > max_size {
+            index = 1;
+            max_size = self.size.y;
+        }
+        if self.size.z > max_size {
+            index = 2;
+        }
+
+        index
+    }
+
+    /// Returns the normalized longest axis of the box.
+    #[inline]
+    pub fn get_longest_axis(&self) -> Vector3 {
+        let mut axis = [0.0; 3];
+        axis[self.get_longest_axis_index()] = 1.0;
+        axis.into()
+    }
+
+    /// Returns the length of the longest axis of the box.
+    #[inline]
+    pub fn get_longest_axis_size(&self) -> f32 {
+        self.size.x.max(self.size.y).max(self.size.z)
+    }
+
     #[doc(hidden)]
     pub fn sys(&self) -> *const sys::godot_aabb {
         unsafe { std::mem::transmute::<*const Aabb, *const sys::godot_aabb>(self as *const _) }
@@ -19,3 +234,102 @@ impl Aabb {
         unsafe { std::mem::transmute::<sys::godot_aabb, Self>(c) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit() -> Aabb {
+        Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<sys::godot_aabb>(), size_of::<Aabb>());
+    }
+
+    #[test]
+    fn merge_is_sane() {
+        let other = Aabb::new(Vector3::new(-1.0, 0.5, 2.0), Vector3::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            Aabb::new(Vector3::new(-1.0, 0.0, 0.0), Vector3::new(2.0, 1.5, 3.0)),
+            unit().merge(&other)
+        );
+    }
+
+    #[test]
+    fn intersects_is_sane() {
+        let overlapping = Aabb::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(1.0, 1.0, 1.0));
+        let touching = Aabb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+        let apart = Aabb::new(Vector3::new(3.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+
+        assert!(unit().intersects(&overlapping));
+        assert!(!unit().intersects(&touching));
+        assert!(!unit().intersects(&apart));
+    }
+
+    #[test]
+    fn encloses_is_sane() {
+        let big = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(3.0, 3.0, 3.0));
+        assert!(big.encloses(&unit()));
+        assert!(!unit().encloses(&big));
+    }
+
+    #[test]
+    fn grow_is_sane() {
+        assert_eq!(
+            Aabb::new(Vector3::new(-0.5, -0.5, -0.5), Vector3::new(2.0, 2.0, 2.0)),
+            unit().grow(0.5)
+        );
+    }
+
+    #[test]
+    fn has_point_is_sane() {
+        assert!(unit().has_point(Vector3::new(0.5, 0.5, 0.5)));
+        assert!(unit().has_point(Vector3::new(1.0, 0.0, 1.0)));
+        assert!(!unit().has_point(Vector3::new(0.5, 1.5, 0.5)));
+    }
+
+    #[test]
+    fn get_support_is_sane() {
+        let aabb = Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(2.0, 4.0, 6.0));
+        assert_eq!(
+            Vector3::new(-1.0, 2.0, -3.0),
+            aabb.get_support(Vector3::new(1.0, -1.0, 0.5))
+        );
+        assert_eq!(
+            Vector3::new(1.0, 2.0, 3.0),
+            aabb.get_support(Vector3::new(-1.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn intersects_ray_is_sane() {
+        let aabb = unit();
+        assert!(aabb.intersects_ray(Vector3::new(-1.0, 0.5, 0.5), Vector3::new(1.0, 0.0, 0.0)));
+        assert!(aabb.intersects_ray(Vector3::new(0.5, 0.5, 0.5), Vector3::new(0.0, 1.0, 0.0)));
+        assert!(aabb.intersects_ray(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0)));
+        assert!(!aabb.intersects_ray(Vector3::new(-1.0, 0.5, 0.5), Vector3::new(-1.0, 0.0, 0.0)));
+        assert!(!aabb.intersects_ray(Vector3::new(-1.0, 2.0, 0.5), Vector3::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn intersects_segment_is_sane() {
+        let aabb = unit();
+        assert!(aabb.intersects_segment(Vector3::new(-1.0, 0.5, 0.5), Vector3::new(2.0, 0.5, 0.5)));
+        assert!(aabb.intersects_segment(Vector3::new(2.0, 2.0, 2.0), Vector3::new(0.5, 0.5, 0.5)));
+        assert!(
+            !aabb.intersects_segment(Vector3::new(-2.0, 0.5, 0.5), Vector3::new(-1.0, 0.5, 0.5))
+        );
+        assert!(!aabb.intersects_segment(Vector3::new(-1.0, 0.0, 2.0), Vector3::new(2.0, 0.0, 2.0)));
+    }
+
+    #[test]
+    fn longest_axis_is_sane() {
+        let aabb = Aabb::new(Vector3::zero(), Vector3::new(1.0, 3.0, 2.0));
+        assert_eq!(1, aabb.get_longest_axis_index());
+        assert_eq!(Vector3::new(0.0, 1.0, 0.0), aabb.get_longest_axis());
+        assert_eq!(3.0, aabb.get_longest_axis_size());
+    }
+}
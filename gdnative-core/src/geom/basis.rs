@@ -1,13 +1,345 @@
-use crate::Vector3;
+use std::ops::{Mul, MulAssign};
 
-/// A 3x3 matrix.
+use crate::{Quat, QuatGodot, Vector3};
+
+/// A 3x3 matrix, typically used to represent the rotation and scale of a `Transform`.
+///
+/// Like in Godot, `elements` are the rows of the matrix, while the axes of the basis are its
+/// columns. See the official [`Godot documentation`](https://docs.godotengine.org/en/3.1/classes/class_basis.html).
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct Basis {
     pub elements: [Vector3; 3],
 }
 
+impl Default for Basis {
+    #[inline]
+    fn default() -> Self {
+        Basis::identity()
+    }
+}
+
 impl Basis {
+    /// Returns the identity basis.
+    #[inline]
+    pub fn identity() -> Self {
+        Basis::from_diagonal(Vector3::new(1.0, 1.0, 1.0))
+    }
+
+    /// Creates a basis from its rows.
+    #[inline]
+    pub fn from_elements(elements: [Vector3; 3]) -> Self {
+        Basis { elements }
+    }
+
+    /// Creates a basis from its axes, which are the columns of the matrix.
+    #[inline]
+    pub fn from_axes(x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Basis::from_elements([x, y, z]).transposed()
+    }
+
+    /// Creates a diagonal matrix, i.e. a scale without rotation.
+    #[inline]
+    pub fn from_diagonal(diagonal: Vector3) -> Self {
+        Basis::from_elements([
+            Vector3::new(diagonal.x, 0.0, 0.0),
+            Vector3::new(0.0, diagonal.y, 0.0),
+            Vector3::new(0.0, 0.0, diagonal.z),
+        ])
+    }
+
+    /// Creates a rotation basis from Euler angles in radians, applied in the YXZ convention
+    /// used by Godot: Z first, then X, then Y.
+    pub fn from_euler(euler: Vector3) -> Self {
+        let (sx, cx) = euler.x.sin_cos();
+        let x = Basis::from_elements([
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, cx, -sx),
+            Vector3::new(0.0, sx, cx),
+        ]);
+
+        let (sy, cy) = euler.y.sin_cos();
+        let y = Basis::from_elements([
+            Vector3::new(cy, 0.0, sy),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(-sy, 0.0, cy),
+        ]);
+
+        let (sz, cz) = euler.z.sin_cos();
+        let z = Basis::from_elements([
+            Vector3::new(cz, -sz, 0.0),
+            Vector3::new(sz, cz, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ]);
+
+        y * x * z
+    }
+
+    /// Creates a rotation basis from a quaternion, which doesn't need to be normalized.
+    pub fn from_quat(quat: Quat) -> Self {
+        let d = quat.square_norm();
+        let s = 2.0 / d;
+        let (xs, ys, zs) = (quat.i * s, quat.j * s, quat.k * s);
+        let (wx, wy, wz) = (quat.r * xs, quat.r * ys, quat.r * zs);
+        let (xx, xy, xz) = (quat.i * xs, quat.i * ys, quat.i * zs);
+        let (yy, yz, zz) = (quat.j * ys, quat.j * zs, quat.k * zs);
+
+        Basis::from_elements([
+            Vector3::new(1.0 - (yy + zz), xy - wz, xz + wy),
+            Vector3::new(xy + wz, 1.0 - (xx + zz), yz - wx),
+            Vector3::new(xz - wy, yz + wx, 1.0 - (xx + yy)),
+        ])
+    }
+
+    /// Creates a rotation of `phi` radians around `axis`, which must be normalized.
+    pub fn from_axis_angle(axis: Vector3, phi: f32) -> Self {
+        let axis_sq = Vector3::new(axis.x * axis.x, axis.y * axis.y, axis.z * axis.z);
+        let (sine, cosine) = phi.sin_cos();
+        let t = 1.0 - cosine;
+
+        let mut elements = [Vector3::zero(); 3];
+        elements[0].x = axis_sq.x + cosine * (1.0 - axis_sq.x);
+        elements[1].y = axis_sq.y + cosine * (1.0 - axis_sq.y);
+        elements[2].z = axis_sq.z + cosine * (1.0 - axis_sq.z);
+
+        let xyzt = axis.x * axis.y * t;
+        let zyxs = axis.z * sine;
+        elements[0].y = xyzt - zyxs;
+        elements[1].x = xyzt + zyxs;
+
+        let xyzt = axis.x * axis.z * t;
+        let zyxs = axis.y * sine;
+        elements[0].z = xyzt + zyxs;
+        elements[2].x = xyzt - zyxs;
+
+        let xyzt = axis.y * axis.z * t;
+        let zyxs = axis.x * sine;
+        elements[1].z = xyzt - zyxs;
+        elements[2].y = xyzt + zyxs;
+
+        Basis::from_elements(elements)
+    }
+
+    /// Returns the axis at `index` (0 for X, 1 for Y and 2 for Z), which is a column of the
+    /// matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than 2.
+    #[inline]
+    pub fn get_axis(&self, index: usize) -> Vector3 {
+        let [x, y, z] = self.elements;
+        Vector3::new(
+            x.to_array()[index],
+            y.to_array()[index],
+            z.to_array()[index],
+        )
+    }
+
+    /// Sets the axis at `index` (0 for X, 1 for Y and 2 for Z), which is a column of the
+    /// matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is greater than 2.
+    #[inline]
+    pub fn set_axis(&mut self, index: usize, axis: Vector3) {
+        for (row, value) in self.elements.iter_mut().zip(axis.to_array().iter()) {
+            let mut array = row.to_array();
+            array[index] = *value;
+            *row = array.into();
+        }
+    }
+
+    /// Returns the determinant of the matrix.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        let [a, b, c] = self.elements;
+        a.x * (b.y * c.z - c.y * b.z) - b.x * (a.y * c.z - c.y * a.z)
+            + c.x * (a.y * b.z - b.y * a.z)
+    }
+
+    /// Returns the inverse of the matrix, or `None` if its determinant is zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let [a, b, c] = self.elements;
+        let cofac = |r1: Vector3, c1: usize, r2: Vector3, c2: usize| {
+            let (r1, r2) = (r1.to_array(), r2.to_array());
+            r1[c1] * r2[c2] - r1[c2] * r2[c1]
+        };
+
+        let co = [cofac(b, 1, c, 2), cofac(b, 2, c, 0), cofac(b, 0, c, 1)];
+        let det = a.x * co[0] + a.y * co[1] + a.z * co[2];
+        if det == 0.0 {
+            return None;
+        }
+
+        let s = 1.0 / det;
+        Some(Basis::from_elements([
+            Vector3::new(co[0], cofac(a, 2, c, 1), cofac(a, 1, b, 2)) * s,
+            Vector3::new(co[1], cofac(a, 0, c, 2), cofac(a, 2, b, 0)) * s,
+            Vector3::new(co[2], cofac(a, 1, c, 0), cofac(a, 0, b, 1)) * s,
+        ]))
+    }
+
+    /// Returns the transposed matrix.
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Basis::from_elements([self.get_axis(0), self.get_axis(1), self.get_axis(2)])
+    }
+
+    /// Returns the basis with normalized axes that are perpendicular to each other, using the
+    /// Gram-Schmidt process starting from the X axis.
+    pub fn orthonormalized(&self) -> Self {
+        let x = self.get_axis(0);
+        let y = self.get_axis(1);
+        let z = self.get_axis(2);
+
+        let x = x.normalize();
+        let y = (y - x * x.dot(y)).normalize();
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalize();
+
+        Basis::from_axes(x, y, z)
+    }
+
+    /// Returns the basis rotated by `phi` radians around `axis`, which must be normalized.
+    /// The rotation is applied in the parent space, i.e. after the current basis.
+    #[inline]
+    pub fn rotated(&self, axis: Vector3, phi: f32) -> Self {
+        Basis::from_axis_angle(axis, phi) * *self
+    }
+
+    /// Returns the basis scaled by `scale` in the parent space, i.e. after the current basis.
+    #[inline]
+    pub fn scaled(&self, scale: Vector3) -> Self {
+        let [x, y, z] = self.elements;
+        Basis::from_elements([x * scale.x, y * scale.y, z * scale.z])
+    }
+
+    /// Returns the length of each axis. The lengths are negated if the basis has a negative
+    /// determinant, i.e. if it contains a reflection.
+    pub fn get_scale(&self) -> Vector3 {
+        let sign = self.determinant().signum();
+        Vector3::new(
+            self.get_axis(0).length(),
+            self.get_axis(1).length(),
+            self.get_axis(2).length(),
+        ) * sign
+    }
+
+    /// Returns the rotation of the basis as Euler angles in radians, in the YXZ convention
+    /// used by `from_euler`. The basis must be a rotation, which can be obtained with
+    /// `orthonormalized`.
+    pub fn get_euler(&self) -> Vector3 {
+        let [a, b, c] = self.elements;
+        let m12 = b.z;
+
+        if m12 < 1.0 {
+            if m12 > -1.0 {
+                if b.x == 0.0 && a.y == 0.0 && a.z == 0.0 && c.x == 0.0 && a.x == 1.0 {
+                    // pure X rotation, returned in the simplest form
+                    Vector3::new((-m12).atan2(b.y), 0.0, 0.0)
+                } else {
+                    Vector3::new((-m12).asin(), a.z.atan2(c.z), b.x.atan2(b.y))
+                }
+            } else {
+                Vector3::new(std::f32::consts::FRAC_PI_2, a.y.atan2(a.x), 0.0)
+            }
+        } else {
+            Vector3::new(-std::f32::consts::FRAC_PI_2, (-a.y).atan2(a.x), 0.0)
+        }
+    }
+
+    /// Returns the rotation of the basis as a quaternion. The basis must be a rotation, which
+    /// can be obtained with `orthonormalized`.
+    pub fn to_quat(&self) -> Quat {
+        let [a, b, c] = self.elements;
+        let m = [a.to_array(), b.to_array(), c.to_array()];
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let mut temp = [0.0; 4];
+
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt();
+            temp[3] = s * 0.5;
+            let s = 0.5 / s;
+            temp[0] = (m[2][1] - m[1][2]) * s;
+            temp[1] = (m[0][2] - m[2][0]) * s;
+            temp[2] = (m[1][0] - m[0][1]) * s;
+        } else {
+            let i = if m[0][0] < m[1][1] {
+                if m[1][1] < m[2][2] {
+                    2
+                } else {
+                    1
+                }
+            } else if m[0][0] < m[2][2] {
+                2
+            } else {
+                0
+            };
+            let j = (i + 1) % 3;
+            let k = (i + 2) % 3;
+
+            let s = (m[i][i] - m[j][j] - m[k][k] + 1.0).sqrt();
+            temp[i] = s * 0.5;
+            let s = 0.5 / s;
+            temp[3] = (m[k][j] - m[j][k]) * s;
+            temp[j] = (m[j][i] + m[i][j]) * s;
+            temp[k] = (m[k][i] + m[i][k]) * s;
+        }
+
+        Quat::quaternion(temp[0], temp[1], temp[2], temp[3])
+    }
+
+    /// Returns the rotation of the basis as a quaternion, removing its scale. Unlike `to_quat`,
+    /// this can be used on any invertible basis.
+    pub fn get_rotation_quat(&self) -> Quat {
+        let mut m = self.orthonormalized();
+        if m.determinant() < 0.0 {
+            m = m.scaled(Vector3::new(-1.0, -1.0, -1.0));
+        }
+        m.to_quat()
+    }
+
+    /// Transforms `v` by the matrix.
+    #[inline]
+    pub fn xform(&self, v: Vector3) -> Vector3 {
+        let [a, b, c] = self.elements;
+        Vector3::new(a.dot(v), b.dot(v), c.dot(v))
+    }
+
+    /// Transforms `v` by the transposed matrix, which is the inverse transformation if the
+    /// basis is a rotation.
+    #[inline]
+    pub fn xform_inv(&self, v: Vector3) -> Vector3 {
+        Vector3::new(
+            self.get_axis(0).dot(v),
+            self.get_axis(1).dot(v),
+            self.get_axis(2).dot(v),
+        )
+    }
+
+    /// Spherically interpolates between the rotations of this basis and `other`, and linearly
+    /// interpolates between the lengths of their rows. `t` is in the range of 0.0 - 1.0.
+    pub fn slerp(&self, other: &Basis, t: f32) -> Self {
+        let from = self.get_rotation_quat();
+        let to = other.get_rotation_quat();
+
+        let mut b = Basis::from_quat(QuatGodot::slerp(from, to, t));
+        for (row, (a, c)) in b
+            .elements
+            .iter_mut()
+            .zip(self.elements.iter().zip(other.elements.iter()))
+        {
+            *row *= lerp(a.length(), c.length(), t);
+        }
+        b
+    }
+
     #[doc(hidden)]
     pub fn sys(&self) -> *const sys::godot_basis {
         unsafe { std::mem::transmute::<*const Basis, *const sys::godot_basis>(self as *const _) }
@@ -18,3 +350,222 @@ impl Basis {
         unsafe { std::mem::transmute::<sys::godot_basis, Self>(c) }
     }
 }
+
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+impl Mul<Basis> for Basis {
+    type Output = Basis;
+
+    #[inline]
+    fn mul(self, rhs: Basis) -> Basis {
+        let [a, b, c] = self.elements;
+        Basis::from_elements([rhs.xform_inv(a), rhs.xform_inv(b), rhs.xform_inv(c)])
+    }
+}
+
+impl MulAssign<Basis> for Basis {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Basis) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vector3> for Basis {
+    type Output = Vector3;
+
+    #[inline]
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        self.xform(rhs)
+    }
+}
+
+impl From<Quat> for Basis {
+    #[inline]
+    fn from(quat: Quat) -> Self {
+        Basis::from_quat(quat)
+    }
+}
+
+impl From<Basis> for Quat {
+    #[inline]
+    fn from(basis: Basis) -> Self {
+        basis.to_quat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn approx_eq(a: &Basis, b: &Basis) -> bool {
+        a.elements
+            .iter()
+            .zip(b.elements.iter())
+            .all(|(a, b)| a.approx_eq(b))
+    }
+
+    fn sample() -> Basis {
+        Basis::from_elements([
+            Vector3::new(2.0, 1.0, 0.5),
+            Vector3::new(-1.0, 3.0, 0.0),
+            Vector3::new(0.25, -0.5, 1.5),
+        ])
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<sys::godot_basis>(), size_of::<Basis>());
+    }
+
+    #[test]
+    fn axes_are_columns() {
+        let basis = sample();
+        assert_eq!(Vector3::new(2.0, -1.0, 0.25), basis.get_axis(0));
+        assert_eq!(Vector3::new(0.5, 0.0, 1.5), basis.get_axis(2));
+
+        let mut basis = Basis::identity();
+        basis.set_axis(1, Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            Basis::from_axes(
+                Vector3::new(1.0, 0.0, 0.0),
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(0.0, 0.0, 1.0)
+            ),
+            basis
+        );
+    }
+
+    #[test]
+    fn determinant_is_sane() {
+        assert!(1.0.approx_eq(&Basis::identity().determinant()));
+        assert!(24.0.approx_eq(&Basis::from_diagonal(Vector3::new(2.0, 3.0, 4.0)).determinant()));
+        assert!(10.375.approx_eq(&sample().determinant()));
+    }
+
+    #[test]
+    fn inverse_is_sane() {
+        let basis = sample();
+        let inverse = basis.inverse().unwrap();
+        assert!(approx_eq(&Basis::identity(), &(basis * inverse)));
+        assert!(approx_eq(&Basis::identity(), &(inverse * basis)));
+
+        assert_eq!(
+            None,
+            Basis::from_diagonal(Vector3::new(1.0, 0.0, 1.0)).inverse()
+        );
+    }
+
+    #[test]
+    fn transposed_is_sane() {
+        let basis = sample();
+        let transposed = basis.transposed();
+        assert_eq!(Vector3::new(2.0, -1.0, 0.25), transposed.elements[0]);
+        assert_eq!(basis, transposed.transposed());
+    }
+
+    #[test]
+    fn axis_angle_matches_godot() {
+        let basis = Basis::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+        assert!(Vector3::new(0.0, 0.0, -1.0).approx_eq(&basis.xform(Vector3::new(1.0, 0.0, 0.0))));
+
+        let basis = Basis::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        assert!(Vector3::new(0.0, 1.0, 0.0).approx_eq(&(basis * Vector3::new(1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn xform_inv_is_inverse_of_rotation() {
+        let basis = Basis::from_euler(Vector3::new(0.3, -1.2, 2.0));
+        let v = Vector3::new(1.0, -2.0, 3.0);
+        assert!(v.approx_eq(&basis.xform_inv(basis.xform(v))));
+    }
+
+    #[test]
+    fn euler_roundtrip() {
+        let cases = [
+            Vector3::new(0.3, -1.2, 2.0),
+            Vector3::new(-0.5, 0.1, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, -3.0),
+        ];
+
+        for &euler in cases.iter() {
+            assert!(euler.approx_eq(&Basis::from_euler(euler).get_euler()));
+        }
+    }
+
+    #[test]
+    fn euler_is_yxz() {
+        let euler = Vector3::new(0.3, -1.2, 2.0);
+        let expected = Basis::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), euler.y)
+            * Basis::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), euler.x)
+            * Basis::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), euler.z);
+        assert!(approx_eq(&expected, &Basis::from_euler(euler)));
+    }
+
+    #[test]
+    fn quat_roundtrip() {
+        let basis = Basis::from_euler(Vector3::new(0.3, -1.2, 2.0));
+        let quat = Quat::from(basis);
+        assert!(approx_eq(&basis, &Basis::from(quat)));
+
+        let quat = Basis::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), FRAC_PI_2).to_quat();
+        let half = FRAC_PI_4;
+        assert!(half.sin().approx_eq(&quat.i));
+        assert!(half.cos().approx_eq(&quat.r));
+    }
+
+    #[test]
+    fn orthonormalized_is_sane() {
+        let basis = sample().orthonormalized();
+        assert!(1.0.approx_eq(&basis.determinant()));
+        assert!(approx_eq(&Basis::identity(), &(basis * basis.transposed())));
+        assert!(sample()
+            .get_axis(0)
+            .normalize()
+            .approx_eq(&basis.get_axis(0)));
+    }
+
+    #[test]
+    fn rotated_and_scaled_are_sane() {
+        let basis = Basis::identity()
+            .scaled(Vector3::new(2.0, 3.0, 4.0))
+            .rotated(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+
+        assert!(Vector3::new(2.0, 3.0, 4.0).approx_eq(&basis.get_scale()));
+        assert!(Vector3::new(0.0, 2.0, 0.0).approx_eq(&basis.xform(Vector3::new(1.0, 0.0, 0.0))));
+
+        let reflected = Basis::from_diagonal(Vector3::new(-1.0, 1.0, 1.0));
+        assert!(Vector3::new(-1.0, -1.0, -1.0).approx_eq(&reflected.get_scale()));
+    }
+
+    #[test]
+    fn slerp_is_sane() {
+        let from = Basis::identity();
+        let to = Basis::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2)
+            .scaled(Vector3::new(3.0, 3.0, 3.0));
+
+        let halfway = from.slerp(&to, 0.5);
+        let expected = Basis::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_4)
+            .scaled(Vector3::new(2.0, 2.0, 2.0));
+        assert!(approx_eq(&expected, &halfway));
+
+        assert!(approx_eq(&from, &from.slerp(&to, 0.0)));
+        assert!(approx_eq(&to, &from.slerp(&to, 1.0)));
+    }
+
+    #[test]
+    fn mul_assign_is_mul() {
+        let mut basis = sample();
+        basis *= Basis::from_euler(Vector3::new(0.1, 0.2, 0.3));
+        assert_eq!(
+            sample() * Basis::from_euler(Vector3::new(0.1, 0.2, 0.3)),
+            basis
+        );
+    }
+}
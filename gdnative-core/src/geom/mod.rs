@@ -16,6 +16,9 @@ pub type Point2 = euclid::default::Point2D<f32>;
 pub type Rotation2D = euclid::default::Rotation2D<f32>;
 pub type Rotation3D = euclid::default::Rotation3D<f32>;
 
+/// Tolerance used by Godot for approximate comparisons.
+pub(crate) const CMP_EPSILON: f32 = 0.00001;
+
 pub use self::aabb::Aabb;
 pub use self::basis::Basis;
 pub use self::plane::Plane;
@@ -1,14 +1,122 @@
+use super::CMP_EPSILON;
 use crate::Vector3;
 
-/// Plane in hessian form.
+/// Plane in hessian form: the points `p` on the plane satisfy `normal.dot(p) == d`.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct Plane {
     pub normal: Vector3,
     pub d: f32,
 }
 
 impl Plane {
+    /// Creates a plane from its normal and its distance from the origin.
+    #[inline]
+    pub fn new(normal: Vector3, d: f32) -> Self {
+        Plane { normal, d }
+    }
+
+    /// Creates a plane from three points, given in clockwise order.
+    #[inline]
+    pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        let normal = (a - c).cross(a - b).normalize();
+        Plane::new(normal, normal.dot(a))
+    }
+
+    /// Returns the point of the plane that is the closest to the origin.
+    #[inline]
+    pub fn center(&self) -> Vector3 {
+        self.normal * self.d
+    }
+
+    /// Returns the signed distance from the plane to `point`, which is positive if the point
+    /// is above the plane.
+    #[inline]
+    pub fn distance_to(&self, point: Vector3) -> f32 {
+        self.normal.dot(point) - self.d
+    }
+
+    /// Returns `true` if `point` is above the plane.
+    #[inline]
+    pub fn is_point_over(&self, point: Vector3) -> bool {
+        self.normal.dot(point) > self.d
+    }
+
+    /// Returns `true` if the distance from `point` to the plane is at most `epsilon`.
+    #[inline]
+    pub fn has_point(&self, point: Vector3, epsilon: f32) -> bool {
+        self.distance_to(point).abs() <= epsilon
+    }
+
+    /// Returns the orthogonal projection of `point` on the plane.
+    #[inline]
+    pub fn project(&self, point: Vector3) -> Vector3 {
+        point - self.normal * self.distance_to(point)
+    }
+
+    /// Returns the plane scaled so that its normal has a length of 1.
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let length = self.normal.length();
+        if length == 0.0 {
+            Plane::new(Vector3::zero(), 0.0)
+        } else {
+            Plane::new(self.normal / length, self.d / length)
+        }
+    }
+
+    /// Returns the intersection point of the three planes `self`, `b` and `c`, or `None` if
+    /// they don't intersect in a single point.
+    pub fn intersect_3(&self, b: &Plane, c: &Plane) -> Option<Vector3> {
+        let (n0, n1, n2) = (self.normal, b.normal, c.normal);
+        let denom = n0.cross(n1).dot(n2);
+
+        if denom.abs() < CMP_EPSILON {
+            return None;
+        }
+
+        Some((n1.cross(n2) * self.d + n2.cross(n0) * b.d + n0.cross(n1) * c.d) / denom)
+    }
+
+    /// Returns the intersection point of the plane and the ray starting at `from` in the
+    /// direction `dir`, or `None` if the ray doesn't hit the plane.
+    pub fn intersects_ray(&self, from: Vector3, dir: Vector3) -> Option<Vector3> {
+        let den = self.normal.dot(dir);
+        if den.abs() < CMP_EPSILON {
+            return None;
+        }
+
+        let dist = (self.normal.dot(from) - self.d) / den;
+        if dist > CMP_EPSILON {
+            // the plane is behind the start of the ray
+            return None;
+        }
+
+        Some(from - dir * dist)
+    }
+
+    /// Returns the intersection point of the plane and the segment from `begin` to `end`, or
+    /// `None` if the segment doesn't cross the plane.
+    pub fn intersects_segment(&self, begin: Vector3, end: Vector3) -> Option<Vector3> {
+        let segment = begin - end;
+        let den = self.normal.dot(segment);
+        if den.abs() < CMP_EPSILON {
+            return None;
+        }
+
+        let dist = (self.normal.dot(begin) - self.d) / den;
+        if !(-CMP_EPSILON..=1.0 + CMP_EPSILON).contains(&dist) {
+            return None;
+        }
+
+        Some(begin - segment * dist)
+    }
+
     #[doc(hidden)]
     pub fn sys(&self) -> *const sys::godot_plane {
         unsafe { std::mem::transmute::<*const Plane, *const sys::godot_plane>(self as *const _) }
@@ -19,3 +127,98 @@ impl Plane {
         unsafe { std::mem::transmute::<sys::godot_plane, Self>(c) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn floor() -> Plane {
+        Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0)
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<sys::godot_plane>(), size_of::<Plane>());
+    }
+
+    #[test]
+    fn from_points_is_sane() {
+        let plane = Plane::from_points(
+            Vector3::new(0.0, 2.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(0.0, 2.0, 1.0),
+        );
+        assert!(floor().normal.approx_eq(&plane.normal));
+        assert!(2.0.approx_eq(&plane.d));
+        assert!(Vector3::new(0.0, 2.0, 0.0).approx_eq(&plane.center()));
+    }
+
+    #[test]
+    fn distance_to_is_sane() {
+        let plane = floor();
+        assert!(3.0.approx_eq(&plane.distance_to(Vector3::new(4.0, 5.0, -1.0))));
+        assert!((-2.0).approx_eq(&plane.distance_to(Vector3::new(0.0, 0.0, 0.0))));
+        assert!(plane.is_point_over(Vector3::new(0.0, 2.5, 0.0)));
+        assert!(plane.has_point(Vector3::new(7.0, 2.0, 3.0), 0.001));
+    }
+
+    #[test]
+    fn project_is_sane() {
+        let plane = Plane::new(Vector3::new(1.0, 1.0, 0.0).normalize(), 0.0);
+        let projected = plane.project(Vector3::new(2.0, 0.0, 3.0));
+        assert!(Vector3::new(1.0, -1.0, 3.0).approx_eq(&projected));
+        assert!(0.0.approx_eq(&plane.distance_to(projected)));
+    }
+
+    #[test]
+    fn normalized_is_sane() {
+        let plane = Plane::new(Vector3::new(0.0, 2.0, 0.0), 4.0).normalized();
+        assert!(floor().normal.approx_eq(&plane.normal));
+        assert!(2.0.approx_eq(&plane.d));
+    }
+
+    #[test]
+    fn intersect_3_is_sane() {
+        let x = Plane::new(Vector3::new(1.0, 0.0, 0.0), 1.0);
+        let z = Plane::new(Vector3::new(0.0, 0.0, 1.0), 3.0);
+        let point = floor().intersect_3(&x, &z).unwrap();
+        assert!(Vector3::new(1.0, 2.0, 3.0).approx_eq(&point));
+
+        let parallel = Plane::new(Vector3::new(0.0, 1.0, 0.0), -1.0);
+        assert_eq!(None, floor().intersect_3(&parallel, &x));
+    }
+
+    #[test]
+    fn intersects_ray_is_sane() {
+        let plane = floor();
+        let hit = plane.intersects_ray(Vector3::new(1.0, 5.0, 1.0), Vector3::new(0.0, -1.0, 0.0));
+        assert!(Vector3::new(1.0, 2.0, 1.0).approx_eq(&hit.unwrap()));
+
+        // pointing away from the plane
+        assert_eq!(
+            None,
+            plane.intersects_ray(Vector3::new(1.0, 5.0, 1.0), Vector3::new(0.0, 1.0, 0.0))
+        );
+        // parallel to the plane
+        assert_eq!(
+            None,
+            plane.intersects_ray(Vector3::new(1.0, 5.0, 1.0), Vector3::new(1.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn intersects_segment_is_sane() {
+        let plane = floor();
+        let hit =
+            plane.intersects_segment(Vector3::new(0.0, 0.0, 0.0), Vector3::new(4.0, 4.0, 0.0));
+        assert!(Vector3::new(2.0, 2.0, 0.0).approx_eq(&hit.unwrap()));
+
+        // too short to reach the plane
+        assert_eq!(
+            None,
+            plane.intersects_segment(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0))
+        );
+    }
+}
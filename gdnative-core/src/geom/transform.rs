@@ -1,8 +1,15 @@
-use crate::{Basis, Vector3};
+use std::ops::{Mul, MulAssign};
+
+use crate::{Basis, QuatGodot, Vector3};
 
 /// 3D Transformation (3x4 matrix) Using basis + origin representation.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct Transform {
     /// The basis is a matrix containing 3 Vector3 as its columns: X axis, Y axis, and Z axis.
     /// These vectors can be interpreted as the basis vectors of local coordinate system
@@ -12,7 +19,87 @@ pub struct Transform {
     pub origin: Vector3,
 }
 
+impl Default for Transform {
+    #[inline]
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
 impl Transform {
+    /// Creates a transform from a basis and an origin.
+    #[inline]
+    pub fn new(basis: Basis, origin: Vector3) -> Self {
+        Transform { basis, origin }
+    }
+
+    /// Returns the identity transform.
+    #[inline]
+    pub fn identity() -> Self {
+        Transform::new(Basis::identity(), Vector3::zero())
+    }
+
+    /// Returns the inverse of the transform, assuming that the basis is a rotation. Use
+    /// `affine_inverse` for transforms with scale.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let basis = self.basis.transposed();
+        Transform::new(basis, basis.xform(-self.origin))
+    }
+
+    /// Returns the inverse of the transform, which can have any invertible basis, or `None`
+    /// if the basis isn't invertible.
+    #[inline]
+    pub fn affine_inverse(&self) -> Option<Self> {
+        let basis = self.basis.inverse()?;
+        Some(Transform::new(basis, basis.xform(-self.origin)))
+    }
+
+    /// Returns a transform with the same origin, rotated so that its -Z axis points towards
+    /// `target` and its Y axis is as close as possible to `up`.
+    ///
+    /// `target` must not be at the origin of the transform, and `up` must not be parallel to
+    /// the direction of `target`.
+    pub fn looking_at(&self, target: Vector3, up: Vector3) -> Self {
+        let z = (self.origin - target).normalize();
+        let x = up.cross(z);
+        let y = z.cross(x);
+
+        Transform::new(
+            Basis::from_axes(x.normalize(), y.normalize(), z),
+            self.origin,
+        )
+    }
+
+    /// Interpolates between this transform and `other`, spherically for the rotations and
+    /// linearly for the scales and origins. `weight` is in the range of 0.0 - 1.0.
+    pub fn interpolate_with(&self, other: &Transform, weight: f32) -> Self {
+        let rotation = QuatGodot::slerp(
+            self.basis.get_rotation_quat(),
+            other.basis.get_rotation_quat(),
+            weight,
+        )
+        .normalize();
+        let scale = self.basis.get_scale().lerp(other.basis.get_scale(), weight);
+
+        Transform::new(
+            Basis::from_quat(rotation) * Basis::from_diagonal(scale),
+            self.origin.lerp(other.origin, weight),
+        )
+    }
+
+    /// Transforms `v` by the transform.
+    #[inline]
+    pub fn xform(&self, v: Vector3) -> Vector3 {
+        self.basis.xform(v) + self.origin
+    }
+
+    /// Transforms `v` by the inverse of the transform, assuming that the basis is a rotation.
+    #[inline]
+    pub fn xform_inv(&self, v: Vector3) -> Vector3 {
+        self.basis.xform_inv(v - self.origin)
+    }
+
     #[doc(hidden)]
     pub fn sys(&self) -> *const sys::godot_transform {
         unsafe {
@@ -25,3 +112,146 @@ impl Transform {
         unsafe { std::mem::transmute::<sys::godot_transform, Self>(c) }
     }
 }
+
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+
+    #[inline]
+    fn mul(self, rhs: Transform) -> Transform {
+        Transform::new(self.basis * rhs.basis, self.xform(rhs.origin))
+    }
+}
+
+impl MulAssign<Transform> for Transform {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<Vector3> for Transform {
+    type Output = Vector3;
+
+    #[inline]
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        self.xform(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::approxeq::ApproxEq;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn approx_eq(a: &Transform, b: &Transform) -> bool {
+        a.origin.approx_eq(&b.origin)
+            && a.basis
+                .elements
+                .iter()
+                .zip(b.basis.elements.iter())
+                .all(|(a, b)| a.approx_eq(b))
+    }
+
+    fn sample() -> Transform {
+        Transform::new(
+            Basis::from_euler(Vector3::new(0.3, -1.2, 2.0)).scaled(Vector3::new(2.0, 0.5, 1.0)),
+            Vector3::new(1.0, 2.0, 3.0),
+        )
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<sys::godot_transform>(), size_of::<Transform>());
+    }
+
+    #[test]
+    fn xform_is_sane() {
+        let transform = Transform::new(
+            Basis::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2),
+            Vector3::new(1.0, 0.0, 0.0),
+        );
+        let v = Vector3::new(1.0, 0.0, 0.0);
+
+        assert!(Vector3::new(1.0, 1.0, 0.0).approx_eq(&transform.xform(v)));
+        assert!(Vector3::new(1.0, 1.0, 0.0).approx_eq(&(transform * v)));
+        assert!(v.approx_eq(&transform.xform_inv(transform.xform(v))));
+    }
+
+    #[test]
+    fn inverse_is_sane() {
+        let transform = Transform::new(
+            Basis::from_euler(Vector3::new(0.3, -1.2, 2.0)),
+            Vector3::new(1.0, 2.0, 3.0),
+        );
+        assert!(approx_eq(
+            &Transform::identity(),
+            &(transform * transform.inverse())
+        ));
+    }
+
+    #[test]
+    fn affine_inverse_is_sane() {
+        let transform = sample();
+        let inverse = transform.affine_inverse().unwrap();
+        assert!(approx_eq(&Transform::identity(), &(transform * inverse)));
+        assert!(approx_eq(&Transform::identity(), &(inverse * transform)));
+
+        let v = Vector3::new(-4.0, 0.5, 2.0);
+        assert!(v.approx_eq(&inverse.xform(transform.xform(v))));
+
+        let flat = Transform::new(
+            Basis::from_diagonal(Vector3::new(1.0, 0.0, 1.0)),
+            Vector3::zero(),
+        );
+        assert_eq!(None, flat.affine_inverse());
+    }
+
+    #[test]
+    fn mul_composes_transforms() {
+        let a = sample();
+        let b = Transform::new(
+            Basis::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.7),
+            Vector3::new(-2.0, 0.0, 5.0),
+        );
+        let v = Vector3::new(0.5, -1.0, 2.0);
+        assert!(a.xform(b.xform(v)).approx_eq(&(a * b).xform(v)));
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(a * b, c);
+    }
+
+    #[test]
+    fn looking_at_is_sane() {
+        let transform = Transform::new(Basis::identity(), Vector3::new(0.0, 0.0, 5.0))
+            .looking_at(Vector3::new(5.0, 0.0, 5.0), Vector3::new(0.0, 1.0, 0.0));
+
+        // -Z points towards the target
+        assert!(Vector3::new(1.0, 0.0, 0.0).approx_eq(&-transform.basis.get_axis(2)));
+        assert!(Vector3::new(0.0, 1.0, 0.0).approx_eq(&transform.basis.get_axis(1)));
+        assert!(Vector3::new(0.0, 0.0, 5.0).approx_eq(&transform.origin));
+    }
+
+    #[test]
+    fn interpolate_with_is_sane() {
+        let from = Transform::identity();
+        let to = Transform::new(
+            Basis::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2)
+                .scaled(Vector3::new(3.0, 3.0, 3.0)),
+            Vector3::new(2.0, 4.0, 6.0),
+        );
+
+        let halfway = from.interpolate_with(&to, 0.5);
+        let expected = Transform::new(
+            Basis::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_4)
+                .scaled(Vector3::new(2.0, 2.0, 2.0)),
+            Vector3::new(1.0, 2.0, 3.0),
+        );
+        assert!(approx_eq(&expected, &halfway));
+
+        assert!(approx_eq(&from, &from.interpolate_with(&to, 0.0)));
+        assert!(approx_eq(&to, &from.interpolate_with(&to, 1.0)));
+    }
+}
@@ -34,9 +34,13 @@ use crate::NativeClass;
 
 use crate::Variant;
 
+mod method;
 pub mod property;
 
-pub use self::property::{Export, ExportInfo, PropertyBuilder, Usage as PropertyUsage};
+pub use self::method::{ArgumentError, FromVarArgs, VarArgs};
+pub use self::property::{
+    DynamicProperties, Export, ExportInfo, PropertyBuilder, PropertyInfo, Usage as PropertyUsage,
+};
 
 /// A handle that can register new classes to the engine during initialization.
 ///
@@ -216,12 +220,48 @@ pub type ScriptConstructorFn =
 pub type ScriptDestructorFn =
     unsafe extern "C" fn(*mut sys::godot_object, *mut libc::c_void, *mut libc::c_void) -> ();
 
+/// RPC mode of an exported method, controlling how it may be called over the network.
+///
+/// These correspond to the `remote`, `remotesync`, `master`, `puppet`, `mastersync` and
+/// `puppetsync` keywords in GDScript.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RpcMode {
+    /// The method can't be called remotely.
     Disabled,
+    /// The method can be called remotely, but is never executed locally.
     Remote,
-    Sync,
-    Mater,
-    Slave,
+    /// The method can be called remotely, and is also executed locally.
+    RemoteSync,
+    /// The method is only executed on the network master of the node.
+    Master,
+    /// The method is only executed on puppets (nodes that are not the network master).
+    Puppet,
+    /// Same as `Master`, but the method is also executed locally.
+    MasterSync,
+    /// Same as `Puppet`, but the method is also executed locally.
+    PuppetSync,
+}
+
+impl RpcMode {
+    #[inline]
+    fn sys(self) -> sys::godot_method_rpc_mode {
+        match self {
+            RpcMode::Disabled => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_DISABLED,
+            RpcMode::Remote => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_REMOTE,
+            RpcMode::RemoteSync => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_REMOTESYNC,
+            RpcMode::Master => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_MASTER,
+            RpcMode::Puppet => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_PUPPET,
+            RpcMode::MasterSync => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_MASTERSYNC,
+            RpcMode::PuppetSync => sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_PUPPETSYNC,
+        }
+    }
+}
+
+impl Default for RpcMode {
+    #[inline]
+    fn default() -> Self {
+        RpcMode::Disabled
+    }
 }
 
 pub struct ScriptMethodAttributes {
@@ -256,7 +296,7 @@ impl<C: NativeClass> ClassBuilder<C> {
     pub fn add_method_advanced(&self, method: ScriptMethod) {
         let method_name = CString::new(method.name).unwrap();
         let attr = sys::godot_method_attributes {
-            rpc_type: sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_DISABLED,
+            rpc_type: method.attributes.rpc_mode.sys(),
         };
 
         let method_desc = sys::godot_instance_method {
@@ -276,18 +316,20 @@ impl<C: NativeClass> ClassBuilder<C> {
         }
     }
 
-    pub fn add_method(&self, name: &str, method: ScriptMethodFn) {
+    pub fn add_method_with_rpc_mode(&self, name: &str, method: ScriptMethodFn, rpc_mode: RpcMode) {
         self.add_method_advanced(ScriptMethod {
-            name: name,
+            name,
             method_ptr: Some(method),
-            attributes: ScriptMethodAttributes {
-                rpc_mode: RpcMode::Disabled,
-            },
+            attributes: ScriptMethodAttributes { rpc_mode },
             method_data: ptr::null_mut(),
             free_func: None,
         });
     }
 
+    pub fn add_method(&self, name: &str, method: ScriptMethodFn) {
+        self.add_method_with_rpc_mode(name, method, RpcMode::Disabled);
+    }
+
     /// Returns a `PropertyBuilder` which can be used to add a property to the class being
     /// registered.
     ///
@@ -311,7 +353,121 @@ impl<C: NativeClass> ClassBuilder<C> {
         PropertyBuilder::new(self, name)
     }
 
+    /// Registers the `_get`, `_set` and `_get_property_list` methods of the class being
+    /// registered, which forward to its `DynamicProperties` implementation.
+    ///
+    /// The user data of the class must implement both `Map` and `MapMut`.
+    pub fn add_dynamic_properties(&self)
+    where
+        C: DynamicProperties,
+        C::UserData: Map + MapMut,
+    {
+        property::dynamic::register(self);
+    }
+
+    /// Adds a property group to the class being registered. In the editor inspector, the
+    /// properties registered after the group are shown under a collapsible section named `name`.
+    ///
+    /// If `prefix` is not empty, only the following properties whose names start with `prefix`
+    /// are included in the group, and the prefix is hidden from their names in the inspector.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// # use gdnative_core::init::ClassBuilder;
+    /// # use gdnative_core::NativeClass;
+    /// # fn register<C: NativeClass>(builder: &ClassBuilder<C>) {
+    /// builder.add_property_group("Movement", "movement/");
+    ///
+    /// builder
+    ///     .add_property("movement/speed")
+    ///     .with_default(10.0)
+    ///     .done();
+    /// # }
+    /// ```
+    pub fn add_property_group(&self, name: &str, prefix: &str) {
+        self.add_property_marker(name, prefix, property::Usage::GROUP);
+    }
+
+    /// Adds a property category to the class being registered. In the editor inspector, the
+    /// properties registered after the category are shown under a header named `name`, like
+    /// the ones separating the properties of different base classes.
+    pub fn add_property_category(&self, name: &str) {
+        self.add_property_marker(name, "", property::Usage::CATEGORY);
+    }
+
+    /// Registers a marker property that is only used to organize the properties in the editor.
+    fn add_property_marker(&self, name: &str, hint_string: &str, usage: property::Usage) {
+        let hint_string = GodotString::from_str(hint_string);
+        let default = Variant::new();
+
+        let mut attr = sys::godot_property_attributes {
+            rset_type: sys::godot_method_rpc_mode_GODOT_METHOD_RPC_MODE_DISABLED,
+            type_: VariantType::Nil as sys::godot_int,
+            hint: sys::godot_property_hint_GODOT_PROPERTY_HINT_NONE,
+            hint_string: hint_string.to_sys(),
+            usage: usage.to_sys(),
+            default_value: default.to_sys(),
+        };
+
+        let path = CString::new(name).unwrap();
+
+        let set = sys::godot_property_set_func {
+            set_func: Some(marker_setter),
+            ..Default::default()
+        };
+
+        let get = sys::godot_property_get_func {
+            get_func: Some(marker_getter),
+            ..Default::default()
+        };
+
+        unsafe {
+            (get_api().godot_nativescript_register_property)(
+                self.init_handle,
+                self.class_name.as_ptr(),
+                path.as_ptr() as *const _,
+                &mut attr,
+                set,
+                get,
+            );
+        }
+    }
+
+    /// Registers the signals described by a type implementing `GodotSignal`, usually
+    /// derived with `#[derive(GodotSignal)]`.
+    pub fn add_signals<S>(&self)
+    where
+        S: GodotSignal,
+    {
+        S::register_signals(self);
+    }
+
+    /// Registers a signal with the engine.
+    ///
+    /// Arguments with a non-nil `default` at the end of the argument list are registered as
+    /// default arguments. Defaults of arguments followed by one without a default are ignored.
     pub fn add_signal(&self, signal: Signal) {
+        let num_default_args = signal
+            .args
+            .iter()
+            .rev()
+            .take_while(|arg| !arg.default.is_nil())
+            .count();
+        let first_default_arg = signal.args.len() - num_default_args;
+
+        if signal.args[..first_default_arg]
+            .iter()
+            .any(|arg| !arg.default.is_nil())
+        {
+            godot_warn!(
+                "signal {}: only trailing arguments can have defaults, other defaults are ignored",
+                signal.name,
+            );
+        }
+
         unsafe {
             let name = GodotString::from_str(signal.name);
             let owned = signal
@@ -327,13 +483,17 @@ impl<C: NativeClass> ClassBuilder<C> {
                 .iter()
                 .map(|(arg, arg_name, hint_string)| sys::godot_signal_argument {
                     name: arg_name.to_sys(),
-                    type_: arg.default.get_type() as i32,
+                    type_: arg.export_info.variant_type as i32,
                     hint: arg.export_info.hint_kind,
                     hint_string: hint_string.to_sys(),
                     usage: arg.usage.to_sys(),
                     default_value: arg.default.to_sys(),
                 })
                 .collect::<Vec<_>>();
+            let mut default_args = signal.args[first_default_arg..]
+                .iter()
+                .map(|arg| arg.default.to_sys())
+                .collect::<Vec<_>>();
             (get_api().godot_nativescript_register_signal)(
                 self.init_handle,
                 self.class_name.as_ptr(),
@@ -341,22 +501,159 @@ impl<C: NativeClass> ClassBuilder<C> {
                     name: name.to_sys(),
                     num_args: args.len() as i32,
                     args: args.as_mut_ptr(),
-                    num_default_args: 0,
-                    default_args: ptr::null_mut(),
+                    num_default_args: num_default_args as i32,
+                    default_args: default_args.as_mut_ptr(),
                 },
             );
         }
     }
 }
 
+extern "C" fn marker_setter(
+    _this: *mut sys::godot_object,
+    _method_data: *mut libc::c_void,
+    _class: *mut libc::c_void,
+    _val: *mut sys::godot_variant,
+) {
+}
+
+extern "C" fn marker_getter(
+    _this: *mut sys::godot_object,
+    _method_data: *mut libc::c_void,
+    _class: *mut libc::c_void,
+) -> sys::godot_variant {
+    Variant::new().forget()
+}
+
 pub struct Signal<'l> {
     pub name: &'l str,
     pub args: &'l [SignalArgument<'l>],
 }
 
+/// An argument of a signal.
+///
+/// A nil `default` means that the argument has no default value.
 pub struct SignalArgument<'l> {
     pub name: &'l str,
     pub default: Variant,
     pub export_info: ExportInfo,
     pub usage: PropertyUsage,
 }
+
+impl<'l> SignalArgument<'l> {
+    /// Returns a builder for an argument of type `T`, with the `ExportInfo` taken from the
+    /// `Export` implementation of `T`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```ignore
+    /// builder.add_signal(Signal {
+    ///     name: "hit",
+    ///     args: &[
+    ///         SignalArgument::builder::<i64>("damage").done(),
+    ///         SignalArgument::builder::<f64>("knockback")
+    ///             .with_default(1.0)
+    ///             .with_hint((0.0..=10.0).into())
+    ///             .done(),
+    ///     ],
+    /// });
+    /// ```
+    pub fn builder<T>(name: &'l str) -> SignalArgumentBuilder<'l, T>
+    where
+        T: Export,
+    {
+        SignalArgumentBuilder {
+            name,
+            default: None,
+            hint: None,
+            usage: PropertyUsage::DEFAULT,
+        }
+    }
+}
+
+/// Builder type used to create a `SignalArgument` of type `T`.
+#[must_use]
+pub struct SignalArgumentBuilder<'l, T: Export> {
+    name: &'l str,
+    default: Option<T>,
+    hint: Option<T::Hint>,
+    usage: PropertyUsage,
+}
+
+impl<'l, T> SignalArgumentBuilder<'l, T>
+where
+    T: Export,
+{
+    /// Sets the default value of the argument. Only trailing arguments can have defaults.
+    pub fn with_default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the export hint of the argument.
+    pub fn with_hint(mut self, hint: T::Hint) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Sets the usage flags of the argument.
+    pub fn with_usage(mut self, usage: PropertyUsage) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Creates the `SignalArgument`.
+    pub fn done(self) -> SignalArgument<'l> {
+        SignalArgument {
+            name: self.name,
+            default: self.default.to_variant(),
+            export_info: T::export_info(self.hint),
+            usage: self.usage,
+        }
+    }
+}
+
+/// Trait for types describing one or more signals, with argument types checked at compile
+/// time.
+///
+/// This is usually derived with `#[derive(GodotSignal)]`. For structs, a single signal is
+/// declared, with the fields as arguments. For enums, each variant declares a signal. Signal
+/// names are the type or variant names in snake case, unless overridden with
+/// `#[signal(name = "...")]`. Argument types must implement `Export`.
+///
+/// The derive also generates typed helpers to emit the signals: `emit` for structs, and
+/// `emit_<variant>` for enums.
+///
+/// ```ignore
+/// #[derive(GodotSignal)]
+/// struct Hit {
+///     damage: i64,
+///     source: GodotString,
+/// }
+///
+/// // In `register_properties`:
+/// builder.add_signals::<Hit>();
+///
+/// // In a method:
+/// unsafe { Hit::emit(&owner, 10, "trap".into()) };
+/// ```
+pub trait GodotSignal {
+    /// Registers the signals described by this type.
+    fn register_signals<C: NativeClass>(builder: &ClassBuilder<C>);
+
+    /// Emits the signal described by `self` on `owner`, with the fields as arguments.
+    ///
+    /// # Safety
+    ///
+    /// `owner` must point to a valid Godot object.
+    unsafe fn emit_to<O: GodotObject>(&self, owner: &O);
+}
+
+/// Emits `signal` on `owner`. Used by the code generated by `#[derive(GodotSignal)]`.
+#[doc(hidden)]
+pub unsafe fn emit_signal<O: GodotObject>(owner: &O, signal: &str, args: &[Variant]) {
+    let mut object = Object::from_sys(owner.to_sys());
+    object.emit_signal(GodotString::from_str(signal), args);
+}
@@ -0,0 +1,153 @@
+//! Variadic method arguments.
+
+use std::fmt;
+
+use crate::FromVariant;
+use crate::FromVariantError;
+use crate::Variant;
+
+/// The remaining arguments of a variadic method call.
+///
+/// A method exported in a `#[methods]` impl block can receive all the arguments following its
+/// fixed parameters with a trailing `VarArgs` or `&[Variant]` parameter. `VarArgs` converts the
+/// arguments one at a time, reporting the position of the argument on errors:
+///
+/// ```ignore
+/// #[export]
+/// fn emit(&self, _owner: Node, event: GodotString, mut args: VarArgs) {
+///     let count = match args.next::<i64>() {
+///         Ok(count) => count,
+///         Err(err) => {
+///             godot_error!("{}", err);
+///             return;
+///         }
+///     };
+///
+///     for arg in args.as_slice() {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct VarArgs<'a> {
+    args: &'a [Variant],
+    index: usize,
+}
+
+impl<'a> VarArgs<'a> {
+    /// Creates a `VarArgs` from `args`, the first of which is the argument at `index` of the
+    /// call, counting from 0.
+    pub fn new(args: &'a [Variant], index: usize) -> Self {
+        VarArgs { args, index }
+    }
+
+    /// Returns the number of remaining arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if there are no remaining arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns the remaining arguments.
+    pub fn as_slice(&self) -> &'a [Variant] {
+        self.args
+    }
+
+    /// Returns the next argument without converting it, or `None` if there are no arguments
+    /// left.
+    pub fn next_variant(&mut self) -> Option<&'a Variant> {
+        let (first, rest) = self.args.split_first()?;
+        self.args = rest;
+        self.index += 1;
+        Some(first)
+    }
+
+    /// Converts the next argument to `T`.
+    ///
+    /// The argument is consumed even if it can't be converted.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: FromVariant>(&mut self) -> Result<T, ArgumentError> {
+        let index = self.index;
+        let variant = self
+            .next_variant()
+            .ok_or(ArgumentError::Missing { index })?;
+
+        T::from_variant(variant).map_err(|error| ArgumentError::InvalidType {
+            index,
+            ty: std::any::type_name::<T>(),
+            error,
+        })
+    }
+
+    /// Returns an error if there are arguments left, which can be used to reject extra
+    /// arguments after all the expected ones were converted.
+    pub fn done(&self) -> Result<(), ArgumentError> {
+        if self.args.is_empty() {
+            Ok(())
+        } else {
+            Err(ArgumentError::TooMany {
+                expected: self.index,
+                got: self.index + self.args.len(),
+            })
+        }
+    }
+}
+
+/// Error returned when the arguments of a variadic method call are invalid.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ArgumentError {
+    /// The argument at `index`, counting from 0, was not passed.
+    Missing { index: usize },
+    /// The argument at `index`, counting from 0, can't be converted to `ty`.
+    InvalidType {
+        index: usize,
+        ty: &'static str,
+        error: FromVariantError,
+    },
+    /// More arguments were passed than expected.
+    TooMany { expected: usize, got: usize },
+}
+
+impl fmt::Display for ArgumentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgumentError::Missing { index } => write!(f, "missing argument #{}", index + 1),
+            ArgumentError::InvalidType { index, ty, error } => write!(
+                f,
+                "cannot convert argument #{} to {}: {}",
+                index + 1,
+                ty,
+                error
+            ),
+            ArgumentError::TooMany { expected, got } => write!(
+                f,
+                "too many arguments: expected {} but got {}",
+                expected, got
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArgumentError {}
+
+/// Types that can receive the remaining arguments of a variadic method.
+///
+/// This is implemented for `VarArgs` and `&[Variant]`.
+pub trait FromVarArgs<'a> {
+    fn from_var_args(args: VarArgs<'a>) -> Self;
+}
+
+impl<'a> FromVarArgs<'a> for VarArgs<'a> {
+    fn from_var_args(args: VarArgs<'a>) -> Self {
+        args
+    }
+}
+
+impl<'a> FromVarArgs<'a> for &'a [Variant] {
+    fn from_var_args(args: VarArgs<'a>) -> Self {
+        args.as_slice()
+    }
+}
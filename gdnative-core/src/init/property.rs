@@ -12,8 +12,10 @@ use crate::VariantType;
 use super::ClassBuilder;
 
 mod accessor;
+pub(crate) mod dynamic;
 pub mod hint;
 
+pub use dynamic::{DynamicProperties, PropertyInfo};
 pub use hint::*;
 
 use accessor::{Getter, InvalidGetter, InvalidSetter, RawGetter, RawSetter, Setter};
@@ -56,6 +58,23 @@ impl ExportInfo {
             hint_string: T::class_name().into(),
         }
     }
+
+    /// Create an `ExportInfo` for an array with elements described by `element`, which
+    /// lets the editor inspector show the type and hint of the elements.
+    pub fn array_of(element: ExportInfo) -> Self {
+        let hint_string = format!(
+            "{}/{}:{}",
+            element.variant_type as u32,
+            element.hint_kind,
+            element.hint_string.to_string(),
+        );
+
+        ExportInfo {
+            variant_type: VariantType::VariantArray,
+            hint_kind: sys::godot_property_hint_GODOT_PROPERTY_HINT_TYPE_STRING,
+            hint_string: hint_string.into(),
+        }
+    }
 }
 
 /// Builder type used to register a property on a `NativeClass`.
@@ -401,6 +420,27 @@ mod impl_export {
     impl_export_for_core_type_without_hint!(Vector3Array);
     impl_export_for_core_type_without_hint!(ColorArray);
 
+    impl<K, V> Export for TypedDictionary<K, V>
+    where
+        K: ToVariant + FromVariant,
+        V: ToVariant + FromVariant,
+    {
+        type Hint = ();
+        fn export_info(_hint: Option<Self::Hint>) -> ExportInfo {
+            ExportInfo::new(VariantType::Dictionary)
+        }
+    }
+
+    impl<T> Export for TypedArray<T>
+    where
+        T: Export + FromVariant,
+    {
+        type Hint = T::Hint;
+        fn export_info(hint: Option<Self::Hint>) -> ExportInfo {
+            ExportInfo::array_of(T::export_info(hint))
+        }
+    }
+
     impl Export for Color {
         type Hint = hint::ColorHint;
         fn export_info(hint: Option<Self::Hint>) -> ExportInfo {
@@ -411,6 +451,13 @@ mod impl_export {
         }
     }
 
+    impl Export for Variant {
+        type Hint = ();
+        fn export_info(_hint: Option<Self::Hint>) -> ExportInfo {
+            ExportInfo::new(VariantType::Nil)
+        }
+    }
+
     impl<T> Export for T
     where
         T: GodotObject + ToVariant,
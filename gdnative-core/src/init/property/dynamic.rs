@@ -0,0 +1,262 @@
+//! Properties that are only known at runtime.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use libc;
+
+use crate::init::{ClassBuilder, RpcMode, ScriptMethod, ScriptMethodAttributes, ScriptMethodFn};
+use crate::object::GodotObject;
+use crate::user_data::{Map, MapMut, UserData};
+use crate::Dictionary;
+use crate::GodotString;
+use crate::NativeClass;
+use crate::ToVariant;
+use crate::Variant;
+use crate::VariantArray;
+
+use super::{Export, ExportInfo, Usage};
+
+/// Trait for classes with properties that aren't known at registration time, for example
+/// data-driven classes with a set of properties defined by designers.
+///
+/// The methods are called by the engine through the `_get`, `_set` and `_get_property_list`
+/// virtual methods, after `ClassBuilder::add_dynamic_properties` is called in
+/// `register_properties`. Properties registered with `ClassBuilder::add_property` take
+/// precedence over dynamic ones.
+///
+/// # Examples
+///
+/// ```ignore
+/// impl DynamicProperties for Stats {
+///     fn get_property(&self, _owner: Reference, name: &str) -> Option<Variant> {
+///         self.values.get(name).map(|value| value.to_variant())
+///     }
+///
+///     fn set_property(&mut self, _owner: Reference, name: &str, value: Variant) -> bool {
+///         match (self.values.get_mut(name), value.try_to_i64()) {
+///             (Some(stat), Some(value)) => {
+///                 *stat = value;
+///                 true
+///             }
+///             _ => false,
+///         }
+///     }
+///
+///     fn property_list(&self, _owner: Reference) -> Vec<PropertyInfo> {
+///         self.values
+///             .keys()
+///             .map(|name| PropertyInfo::of::<i64>(format!("stats/{}", name), None))
+///             .collect()
+///     }
+/// }
+/// ```
+pub trait DynamicProperties: NativeClass {
+    /// Returns the value of the property `name`, or `None` if the class doesn't have such
+    /// a property.
+    fn get_property(&self, owner: Self::Base, name: &str) -> Option<Variant>;
+
+    /// Sets the property `name` to `value`. Returns `false` if the class doesn't have such
+    /// a property.
+    fn set_property(&mut self, owner: Self::Base, name: &str, value: Variant) -> bool;
+
+    /// Returns the descriptions of the dynamic properties, which are shown in the editor
+    /// inspector and used for serialization.
+    fn property_list(&self, owner: Self::Base) -> Vec<PropertyInfo>;
+}
+
+/// Description of a property returned by `DynamicProperties::property_list`.
+#[derive(Debug)]
+pub struct PropertyInfo {
+    pub name: GodotString,
+    pub export_info: ExportInfo,
+    pub usage: Usage,
+}
+
+impl PropertyInfo {
+    /// Creates a `PropertyInfo` with the default usage flags.
+    pub fn new<S: Into<GodotString>>(name: S, export_info: ExportInfo) -> Self {
+        PropertyInfo {
+            name: name.into(),
+            export_info,
+            usage: Usage::DEFAULT,
+        }
+    }
+
+    /// Creates a `PropertyInfo` for a property of type `T`, with an optional typed hint.
+    pub fn of<T: Export, S: Into<GodotString>>(name: S, hint: Option<T::Hint>) -> Self {
+        Self::new(name, T::export_info(hint))
+    }
+
+    /// Sets the usage flags of the property.
+    pub fn with_usage(mut self, usage: Usage) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Returns the property as a `Dictionary` in the format expected by the engine.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set(&"name".into(), &self.name.to_variant());
+        dict.set(
+            &"type".into(),
+            &(self.export_info.variant_type as i64).to_variant(),
+        );
+        dict.set(
+            &"hint".into(),
+            &(self.export_info.hint_kind as i64).to_variant(),
+        );
+        dict.set(
+            &"hint_string".into(),
+            &self.export_info.hint_string.to_variant(),
+        );
+        dict.set(&"usage".into(), &(self.usage.bits() as i64).to_variant());
+        dict
+    }
+}
+
+impl ToVariant for PropertyInfo {
+    fn to_variant(&self) -> Variant {
+        self.to_dictionary().to_variant()
+    }
+}
+
+pub(crate) fn register<C>(builder: &ClassBuilder<C>)
+where
+    C: DynamicProperties,
+    C::UserData: Map + MapMut,
+{
+    let methods: [(&str, ScriptMethodFn); 3] = [
+        ("_get", get::<C>),
+        ("_set", set::<C>),
+        ("_get_property_list", get_property_list::<C>),
+    ];
+
+    for (name, method) in methods.iter() {
+        builder.add_method_advanced(ScriptMethod {
+            name,
+            method_ptr: Some(*method),
+            attributes: ScriptMethodAttributes {
+                rpc_mode: RpcMode::Disabled,
+            },
+            method_data: ptr::null_mut(),
+            free_func: None,
+        });
+    }
+}
+
+/// Returns the argument at `index` as a `Variant`, or `None` if it wasn't passed.
+unsafe fn argument<'a>(
+    num_args: libc::c_int,
+    args: *mut *mut sys::godot_variant,
+    index: usize,
+) -> Option<&'a Variant> {
+    if index < num_args as usize {
+        Some(Variant::cast_ref(*args.add(index)))
+    } else {
+        None
+    }
+}
+
+/// Calls `op` with the user data and the owner of an instance, catching panics and reporting
+/// user data errors.
+unsafe fn call<C, F, R>(
+    this: *mut sys::godot_object,
+    user_data: *mut libc::c_void,
+    method: &str,
+    op: F,
+) -> Option<R>
+where
+    C: NativeClass,
+    F: FnOnce(&C::UserData, C::Base) -> Result<R, String>,
+{
+    let user_data = C::UserData::clone_from_user_data_unchecked(user_data as *const _);
+    let owner = C::Base::from_sys(this);
+
+    match panic::catch_unwind(AssertUnwindSafe(|| op(&user_data, owner))) {
+        Ok(Ok(ret)) => Some(ret),
+        Ok(Err(err)) => {
+            godot_error!("gdnative-core: cannot call {}: {}", method, err);
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+unsafe extern "C" fn get<C>(
+    this: *mut sys::godot_object,
+    _method_data: *mut libc::c_void,
+    user_data: *mut libc::c_void,
+    num_args: libc::c_int,
+    args: *mut *mut sys::godot_variant,
+) -> sys::godot_variant
+where
+    C: DynamicProperties,
+    C::UserData: Map,
+{
+    let name = match argument(num_args, args, 0) {
+        Some(name) => name.to_string(),
+        None => return Variant::new().forget(),
+    };
+
+    call::<C, _, _>(this, user_data, "_get", |user_data, owner| {
+        user_data
+            .map(|rust_ty| rust_ty.get_property(owner, &name))
+            .map_err(|err| format!("{:?}", err))
+    })
+    .and_then(|value| value)
+    .unwrap_or_else(Variant::new)
+    .forget()
+}
+
+unsafe extern "C" fn set<C>(
+    this: *mut sys::godot_object,
+    _method_data: *mut libc::c_void,
+    user_data: *mut libc::c_void,
+    num_args: libc::c_int,
+    args: *mut *mut sys::godot_variant,
+) -> sys::godot_variant
+where
+    C: DynamicProperties,
+    C::UserData: MapMut,
+{
+    let (name, value) = match (argument(num_args, args, 0), argument(num_args, args, 1)) {
+        (Some(name), Some(value)) => (name.to_string(), value.clone()),
+        _ => return Variant::from_bool(false).forget(),
+    };
+
+    let handled = call::<C, _, _>(this, user_data, "_set", |user_data, owner| {
+        user_data
+            .map_mut(|rust_ty| rust_ty.set_property(owner, &name, value))
+            .map_err(|err| format!("{:?}", err))
+    })
+    .unwrap_or(false);
+
+    Variant::from_bool(handled).forget()
+}
+
+unsafe extern "C" fn get_property_list<C>(
+    this: *mut sys::godot_object,
+    _method_data: *mut libc::c_void,
+    user_data: *mut libc::c_void,
+    _num_args: libc::c_int,
+    _args: *mut *mut sys::godot_variant,
+) -> sys::godot_variant
+where
+    C: DynamicProperties,
+    C::UserData: Map,
+{
+    let properties = call::<C, _, _>(this, user_data, "_get_property_list", |user_data, owner| {
+        user_data
+            .map(|rust_ty| rust_ty.property_list(owner))
+            .map_err(|err| format!("{:?}", err))
+    })
+    .unwrap_or_default();
+
+    let mut array = VariantArray::new();
+    for property in properties.iter() {
+        array.push(&property.to_variant());
+    }
+
+    array.to_variant().forget()
+}
@@ -367,6 +367,12 @@ impl StringHint {
     }
 }
 
+impl From<EnumHint> for StringHint {
+    fn from(hint: EnumHint) -> Self {
+        Self::Enum(hint)
+    }
+}
+
 /// Possible hints for `Color`.
 #[derive(Clone, Debug)]
 pub enum ColorHint {
@@ -38,57 +38,63 @@ mod macros;
 #[macro_use]
 mod class;
 pub mod access;
-mod byte_array;
 mod color;
-mod color_array;
 mod dictionary;
-mod float32_array;
 mod free_on_drop;
 mod generated;
 pub mod init;
-mod int32_array;
+pub mod marshal;
+#[cfg(feature = "mock_api")]
+pub mod mock;
 mod node_path;
 #[doc(hidden)]
 pub mod object;
 mod point2;
+mod pool_array;
+mod quat;
+mod rect2;
 mod rid;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod string;
-mod string_array;
+mod transform2d;
+#[cfg(feature = "async")]
+pub mod tasks;
 mod type_tag;
+mod typed_array;
+mod typed_dictionary;
 pub mod user_data;
 mod variant;
 mod variant_array;
 mod vector2;
-mod vector2_array;
 mod vector3;
-mod vector3_array;
 
-pub use crate::byte_array::*;
 pub use crate::class::*;
 pub use crate::color::*;
-pub use crate::color_array::*;
 pub use crate::dictionary::*;
-pub use crate::float32_array::*;
 pub use crate::free_on_drop::*;
 pub use crate::generated::*;
 pub use crate::geom::*;
-pub use crate::int32_array::*;
+pub use crate::init::GodotSignal;
 pub use crate::node_path::*;
 pub use crate::object::GodotObject;
 pub use crate::object::Instanciable;
 pub use crate::point2::*;
+pub use crate::pool_array::*;
+pub use crate::quat::*;
+pub use crate::rect2::*;
 pub use crate::rid::*;
 pub use crate::string::*;
-pub use crate::string_array::*;
+pub use crate::transform2d::*;
+pub use crate::typed_array::*;
+pub use crate::typed_dictionary::*;
 pub use crate::user_data::Map;
 pub use crate::user_data::MapMut;
 pub use crate::user_data::UserData;
 pub use crate::variant::*;
 pub use crate::variant_array::*;
 pub use crate::vector2::*;
-pub use crate::vector2_array::*;
 pub use crate::vector3::*;
-pub use crate::vector3_array::*;
 
 pub use sys::GodotApi;
 
@@ -112,6 +118,8 @@ pub fn get_gdnative_library_sys() -> *mut sys::godot_object {
 #[doc(hidden)]
 pub unsafe fn cleanup_internal_state() {
     type_tag::cleanup();
+    #[cfg(feature = "async")]
+    tasks::cleanup();
     GODOT_API = None;
 }
 
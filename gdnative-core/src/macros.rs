@@ -332,102 +332,6 @@ macro_rules! impl_common_methods {
     )
 }
 
-macro_rules! impl_guard_common_trait {
-    (
-        Drop for $Type:ident { $access:ident, $len:ident, } : $gd_method:ident
-    ) => {
-        impl<'a> Drop for $Type<'a> {
-            fn drop(&mut self) {
-                unsafe {
-                    ($crate::get_api().$gd_method)(self.$access);
-                }
-            }
-        }
-    };
-
-    (
-        Clone for $Type:ident { $access:ident, $len:ident, } : $gd_method:ident
-    ) => {
-        impl<'a> Clone for $Type<'a> {
-            fn clone(&self) -> Self {
-                let $access = unsafe { ($crate::get_api().$gd_method)(self.$access) };
-
-                $Type {
-                    $access,
-                    $len: self.$len,
-                    _marker: std::marker::PhantomData,
-                }
-            }
-        }
-    };
-}
-
-macro_rules! define_access_guard {
-    (
-        pub struct $Type:ident<'a>: $AccessPtr:ty {
-            $access:ident = $access_gd_method:ident($BasePtr:ty),
-            $len:ident = $len_gd_method:ident,
-        }
-        Guard<Target=$Target:ident> => $guard_gd_method:ident -> $OrigPtr:ty;
-        $( $Trait:ident => $trait_gd_method:ident; )*
-    ) => {
-        pub struct $Type<'a> {
-            $access: *mut $AccessPtr,
-            $len: usize,
-            _marker: std::marker::PhantomData<&'a $Target>,
-        }
-
-        impl<'a> $Type<'a> {
-            unsafe fn new(arr: $BasePtr) -> Self {
-                let $len = ($crate::get_api().$len_gd_method)(arr) as usize;
-                let $access = ($crate::get_api().$access_gd_method)(arr);
-                $Type {
-                    $access,
-                    $len,
-                    _marker: std::marker::PhantomData,
-                }
-            }
-        }
-
-        unsafe impl<'a> $crate::access::Guard for $Type<'a> {
-            type Target = $Target;
-            fn len(&self) -> usize { self.$len }
-            fn read_ptr(&self) -> *const Self::Target {
-                unsafe {
-                    let orig_ptr: $OrigPtr = ($crate::get_api().$guard_gd_method)(self.$access);
-                    orig_ptr as *const Self::Target
-                }
-            }
-        }
-
-        $(
-            impl_guard_common_trait!(
-                $Trait for $Type { $access, $len, } : $trait_gd_method
-            );
-        )*
-    };
-
-    (
-        pub struct $Type:ident<'a>: $AccessPtr:ty {
-            $access:ident = $access_gd_method:ident($BasePtr:ty),
-            $len:ident = $len_gd_method:ident,
-        }
-        Guard<Target=$Target:ident> + WritePtr => $guard_gd_method:ident -> $OrigPtr:ty;
-        $( $Trait:ident => $trait_gd_method:ident; )*
-    ) => {
-        define_access_guard!(
-            pub struct $Type<'a>: $AccessPtr {
-                $access = $access_gd_method($BasePtr),
-                $len = $len_gd_method,
-            }
-            Guard<Target=$Target> => $guard_gd_method -> $OrigPtr;
-            $( $Trait => $trait_gd_method; )*
-        );
-
-        unsafe impl<'a> $crate::access::WritePtr for $Type<'a> { }
-    };
-}
-
 macro_rules! godot_test {
     ($($test_name:ident $body:block)*) => {
         $(
@@ -447,6 +351,19 @@ macro_rules! godot_test {
 
                 ok
             }
+
+            // Also run the test with `cargo test` against the mock API.
+            #[cfg(all(test, feature = "mock_api"))]
+            mod $test_name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[test]
+                fn mock_api() {
+                    crate::mock::install();
+                    $body
+                }
+            }
         )*
     }
 }
@@ -502,6 +419,118 @@ macro_rules! godot_wrap_method_parameter_count {
     }
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! godot_wrap_method_parameter_is_optional {
+    () => {
+        false
+    };
+    ($default:expr) => {
+        true
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! godot_wrap_method_parameter_default {
+    () => {
+        unreachable!("required parameters are checked before conversion")
+    };
+    ($default:expr) => {
+        $default
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! godot_wrap_method_has_rest {
+    () => {
+        false
+    };
+    ($rest:ident) => {
+        true
+    };
+}
+
+/// Checks the number of arguments passed to a wrapped method, and converts them to the
+/// parameter types. Parameters followed by `= default` are optional: if the caller omits them,
+/// which is only possible for trailing parameters, they are set to the default expression.
+///
+/// A final `..rest: T` parameter, where `T` implements `FromVarArgs`, receives the arguments
+/// following the other parameters.
+///
+/// Returns nil from the calling function if the arguments are invalid.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! godot_wrap_method_args {
+    (
+        $num_args:ident,
+        $args:ident,
+        $($pname:ident : $pty:ty $(= $default:expr)?),*
+        $(, ..$rest:ident : $rest_ty:ty)?
+    ) => {
+        let num_params = godot_wrap_method_parameter_count!($($pname,)*);
+
+        // Parameters up to the last one without a default are required.
+        let mut num_required = 0;
+        let mut idx = 0;
+        $(
+            idx += 1;
+            if !godot_wrap_method_parameter_is_optional!($($default)?) {
+                num_required = idx;
+            }
+        )*
+
+        let has_rest = godot_wrap_method_has_rest!($($rest)?);
+        if $num_args < num_required || ($num_args > num_params && !has_rest) {
+            if has_rest {
+                godot_error!("Incorrect number of parameters: expected at least {} but got {}", num_required, $num_args);
+            } else if num_required == num_params {
+                godot_error!("Incorrect number of parameters: expected {} but got {}", num_params, $num_args);
+            } else {
+                godot_error!("Incorrect number of parameters: expected {} to {} but got {}", num_required, num_params, $num_args);
+            }
+            return $crate::Variant::new().to_sys();
+        }
+
+        let mut offset = 0;
+        $(
+            let $pname: $pty = if offset < $num_args {
+                let _variant: &$crate::Variant = ::std::mem::transmute(&mut **($args.offset(offset as isize)));
+                match <$pty as $crate::FromVariant>::from_variant(_variant) {
+                    Ok(val) => val,
+                    Err(err) => {
+                        godot_error!(
+                            "Cannot convert argument #{idx} ({name}) to {ty}: {err} (non-primitive types may impose structural checks)",
+                            idx = offset + 1,
+                            name = stringify!($pname),
+                            ty = stringify!($pty),
+                            err = err,
+                        );
+                        return $crate::Variant::new().to_sys();
+                    },
+                }
+            } else {
+                godot_wrap_method_parameter_default!($($default)?)
+            };
+
+            offset += 1;
+        )*
+
+        $(
+            let __rest_args = (num_params..$num_args.max(num_params))
+                .map(|offset| {
+                    let _variant: &$crate::Variant = ::std::mem::transmute(&mut **($args.offset(offset as isize)));
+                    _variant.clone()
+                })
+                .collect::<Vec<_>>();
+            let $rest: $rest_ty = $crate::init::FromVarArgs::from_var_args(
+                $crate::init::VarArgs::new(&__rest_args, num_params as usize),
+            );
+        )?
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! godot_wrap_method_inner {
@@ -511,7 +540,8 @@ macro_rules! godot_wrap_method_inner {
         fn $method_name:ident(
             $self:ident,
             $owner:ident : $owner_ty:ty
-            $(,$pname:ident : $pty:ty)*
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(, ..$rest:ident : $rest_ty:ty)?
         ) -> $retty:ty
     ) => {
         {
@@ -529,35 +559,16 @@ macro_rules! godot_wrap_method_inner {
 
                 let __instance: Instance<$type_name> = Instance::from_raw(this, user_data);
 
-                let num_params = godot_wrap_method_parameter_count!($($pname,)*);
-                if num_args != num_params {
-                    godot_error!("Incorrect number of parameters: expected {} but got {}", num_params, num_args);
-                    return $crate::Variant::new().to_sys();
-                }
-
-                let mut offset = 0;
-                $(
-                    let _variant: &$crate::Variant = ::std::mem::transmute(&mut **(args.offset(offset)));
-                    let $pname = match <$pty as $crate::FromVariant>::from_variant(_variant) {
-                        Ok(val) => val,
-                        Err(err) => {
-                            godot_error!(
-                                "Cannot convert argument #{idx} ({name}) to {ty}: {err} (non-primitive types may impose structural checks)",
-                                idx = offset + 1,
-                                name = stringify!($pname),
-                                ty = stringify!($pty),
-                                err = err,
-                            );
-                            return $crate::Variant::new().to_sys();
-                        },
-                    };
-
-                    offset += 1;
-                )*
+                godot_wrap_method_args!(
+                    num_args,
+                    args,
+                    $($pname : $pty $(= $default)?),*
+                    $(, ..$rest : $rest_ty)?
+                );
 
                 let rust_ret = match panic::catch_unwind(AssertUnwindSafe(move || {
                     let ret = __instance.$map_method(|__rust_val, $owner| {
-                        let ret = __rust_val.$method_name($owner, $($pname,)*);
+                        let ret = __rust_val.$method_name($owner, $($pname,)* $($rest)?);
                         <$retty as $crate::ToVariant>::to_variant(&ret)
                     });
                     std::mem::drop(__instance);
@@ -586,6 +597,12 @@ macro_rules! godot_wrap_method_inner {
 
 /// Convenience macro to wrap an object's method into a function pointer
 /// that can be passed to the engine when registering a class.
+///
+/// Trailing parameters can be made optional by following their type with
+/// `= default`, e.g. `fn foo(&self, owner: Node, a: i64, b: i64 = 1)`.
+///
+/// A final parameter prefixed with `..` receives the remaining arguments. Its type must
+/// implement `FromVarArgs`, e.g. `fn foo(&self, owner: Node, a: i64, ..args: &[Variant])`.
 #[macro_export]
 macro_rules! godot_wrap_method {
     // mutable
@@ -594,7 +611,8 @@ macro_rules! godot_wrap_method {
         fn $method_name:ident(
             &mut $self:ident,
             $owner:ident : $owner_ty:ty
-            $(,$pname:ident : $pty:ty)*
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(, ..$rest:ident : $rest_ty:ty)?
             $(,)?
         ) -> $retty:ty
     ) => {
@@ -604,7 +622,8 @@ macro_rules! godot_wrap_method {
             fn $method_name(
                 $self,
                 $owner: $owner_ty
-                $(,$pname : $pty)*
+                $(,$pname : $pty $(= $default)?)*
+                $(, ..$rest : $rest_ty)?
             ) -> $retty
         )
     };
@@ -614,7 +633,8 @@ macro_rules! godot_wrap_method {
         fn $method_name:ident(
             & $self:ident,
             $owner:ident : $owner_ty:ty
-            $(,$pname:ident : $pty:ty)*
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(, ..$rest:ident : $rest_ty:ty)?
             $(,)?
         ) -> $retty:ty
     ) => {
@@ -624,7 +644,8 @@ macro_rules! godot_wrap_method {
             fn $method_name(
                 $self,
                 $owner: $owner_ty
-                $(,$pname : $pty)*
+                $(,$pname : $pty $(= $default)?)*
+                $(, ..$rest : $rest_ty)?
             ) -> $retty
         )
     };
@@ -634,7 +655,8 @@ macro_rules! godot_wrap_method {
         fn $method_name:ident(
             &mut $self:ident,
             $owner:ident : $owner_ty:ty
-            $(,$pname:ident : $pty:ty)*
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(, ..$rest:ident : $rest_ty:ty)?
             $(,)?
         )
     ) => {
@@ -643,7 +665,8 @@ macro_rules! godot_wrap_method {
             fn $method_name(
                 &mut $self,
                 $owner: $owner_ty
-                $(,$pname : $pty)*
+                $(,$pname : $pty $(= $default)?)*
+                $(, ..$rest : $rest_ty)?
             ) -> ()
         )
     };
@@ -653,7 +676,8 @@ macro_rules! godot_wrap_method {
         fn $method_name:ident(
             & $self:ident,
             $owner:ident : $owner_ty:ty
-            $(,$pname:ident : $pty:ty)*
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(, ..$rest:ident : $rest_ty:ty)?
             $(,)?
         )
     ) => {
@@ -662,7 +686,66 @@ macro_rules! godot_wrap_method {
             fn $method_name(
                 & $self,
                 $owner: $owner_ty
-                $(,$pname : $pty)*
+                $(,$pname : $pty $(= $default)?)*
+                $(, ..$rest : $rest_ty)?
+            ) -> ()
+        )
+    };
+}
+
+/// Convenience macro to wrap an `async` associated function into a function pointer
+/// that can be passed to the engine when registering a class.
+///
+/// The first parameter of the function receives the `Instance` the method is called on.
+/// The returned future is spawned on the executor of the current thread, and a
+/// `FunctionState` is returned to the caller instead of the result.
+#[cfg(feature = "async")]
+#[macro_export]
+macro_rules! godot_wrap_async_method {
+    (
+        $type_name:ty,
+        async fn $method_name:ident(
+            $this:ident : $this_ty:ty
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(,)?
+        ) -> $retty:ty
+    ) => {
+        {
+            #[allow(unused_unsafe, unused_variables, unused_assignments, unused_mut)]
+            unsafe extern "C" fn method(
+                this: *mut $crate::sys::godot_object,
+                method_data: *mut $crate::libc::c_void,
+                user_data: *mut $crate::libc::c_void,
+                num_args: $crate::libc::c_int,
+                args: *mut *mut $crate::sys::godot_variant
+            ) -> $crate::sys::godot_variant {
+
+                use $crate::Instance;
+
+                let __instance: Instance<$type_name> = Instance::from_raw(this, user_data);
+
+                godot_wrap_method_args!(num_args, args, $($pname : $pty $(= $default)?),*);
+
+                let future = <$type_name>::$method_name(__instance, $($pname,)*);
+                $crate::tasks::spawn_method(future).forget()
+            }
+
+            method
+        }
+    };
+    (
+        $type_name:ty,
+        async fn $method_name:ident(
+            $this:ident : $this_ty:ty
+            $(,$pname:ident : $pty:ty $(= $default:expr)?)*
+            $(,)?
+        )
+    ) => {
+        godot_wrap_async_method!(
+            $type_name,
+            async fn $method_name(
+                $this: $this_ty
+                $(,$pname : $pty $(= $default)?)*
             ) -> ()
         )
     };
@@ -0,0 +1,1063 @@
+//! Godot's binary serialization format for variants.
+//!
+//! This is the format used by `var2bytes` and `bytes2var` in GDScript, as well as by
+//! `PacketPeer.put_var`, `File.store_var` and the high-level multiplayer API. The encoder
+//! and decoder in this module are implemented in pure Rust, and work on
+//! [`VariantValue`](enum.VariantValue.html) trees, which don't require the engine to be
+//! loaded. This makes it possible to exchange data with Godot from dedicated servers and
+//! tools.
+//!
+//! When the engine is present, [`var2bytes`](fn.var2bytes.html) and
+//! [`bytes2var`](fn.bytes2var.html) can be used to convert `Variant`s directly.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use gdnative::marshal::VariantValue;
+//!
+//! let bytes = VariantValue::I64(42).to_bytes();
+//! assert_eq!(&[2, 0, 0, 0, 42, 0, 0, 0], &bytes[..]);
+//! assert_eq!(Ok(VariantValue::I64(42)), VariantValue::from_bytes(&bytes));
+//! ```
+
+use std::fmt;
+
+use crate::*;
+
+/// Mask of the type in a variant header.
+const HEADER_TYPE_MASK: u32 = 0xFF;
+/// Header flag set on integers and floats encoded with 64 bits.
+const HEADER_FLAG_64: u32 = 1 << 16;
+/// Header flag set on objects encoded as their instance ID.
+const HEADER_FLAG_OBJECT_AS_ID: u32 = 1 << 16;
+
+/// Flag set on the name count of node paths in the current format.
+const NODE_PATH_NEW_FORMAT: u32 = 0x8000_0000;
+/// Flag set on node paths that are absolute.
+const NODE_PATH_ABSOLUTE: u32 = 1;
+/// Flag set on node paths in the old property format, which stored the property separately.
+const NODE_PATH_PROPERTY: u32 = 2;
+
+/// Flag set on the length of shared arrays and dictionaries, which is ignored.
+const SHARED_FLAG: u32 = 0x8000_0000;
+
+/// Maximum nesting depth of arrays, dictionaries and objects accepted by the decoder. Deeper
+/// data is rejected instead of overflowing the stack, since it may come from the network.
+const MAX_DEPTH: usize = 512;
+
+/// A pure-Rust representation of a variant value, which can be encoded in Godot's binary
+/// format without the engine.
+///
+/// The variants correspond to the ones of [`VariantType`](../enum.VariantType.html).
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantValue {
+    Nil,
+    Bool(bool),
+    I64(i64),
+    F64(f64),
+    GodotString(String),
+    Vector2(Vector2),
+    Rect2(Rect2),
+    Vector3(Vector3),
+    Transform2D(Transform2D),
+    Plane(Plane),
+    Quat(Quat),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform(Transform),
+    Color(Color),
+    NodePath(NodePathValue),
+    /// RIDs are only meaningful within a running engine, so their content is never encoded.
+    Rid,
+    Object(ObjectValue),
+    /// Dictionary entries, in insertion order.
+    Dictionary(Vec<(VariantValue, VariantValue)>),
+    VariantArray(Vec<VariantValue>),
+    ByteArray(Vec<u8>),
+    Int32Array(Vec<i32>),
+    Float32Array(Vec<f32>),
+    StringArray(Vec<String>),
+    Vector2Array(Vec<Vector2>),
+    Vector3Array(Vec<Vector3>),
+    ColorArray(Vec<Color>),
+}
+
+/// A pure-Rust representation of a node path.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NodePathValue {
+    /// Node names, e.g. `["Path2D", "Sprite"]` in `"Path2D/Sprite:texture:size"`.
+    pub names: Vec<String>,
+    /// Resource and property names, e.g. `["texture", "size"]` in `"Path2D/Sprite:texture:size"`.
+    pub subnames: Vec<String>,
+    /// Whether the path starts with a slash.
+    pub absolute: bool,
+}
+
+impl NodePathValue {
+    /// Parses a node path from a string, e.g. `"Path2D/PathFollow2D/Sprite:texture:size"`.
+    pub fn parse(path: &str) -> Self {
+        let absolute = path.starts_with('/');
+        let mut parts = path.split(':');
+        let names = parts
+            .next()
+            .unwrap_or("")
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+        let subnames = parts
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+
+        NodePathValue {
+            names,
+            subnames,
+            absolute,
+        }
+    }
+}
+
+impl fmt::Display for NodePathValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.absolute {
+            f.write_str("/")?;
+        }
+        f.write_str(&self.names.join("/"))?;
+        for subname in &self.subnames {
+            write!(f, ":{}", subname)?;
+        }
+        Ok(())
+    }
+}
+
+/// A pure-Rust representation of an encoded object.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectValue {
+    /// A null object.
+    Null,
+    /// An object encoded as its instance ID. This is what `var2bytes` produces by default.
+    InstanceId(u64),
+    /// An object encoded with its class name and stored properties, as produced by
+    /// `var2bytes` with `full_objects` enabled.
+    Full {
+        class: String,
+        properties: Vec<(String, VariantValue)>,
+    },
+}
+
+/// Error returned when decoding malformed data.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DecodeError {
+    /// The data ended before the value was complete.
+    UnexpectedEof,
+    /// The type in a variant header is not a valid `VariantType`.
+    InvalidType(u32),
+    /// A string is not valid UTF-8.
+    InvalidUtf8,
+    /// Arrays, dictionaries or objects are nested deeper than the decoder allows.
+    TooDeep,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEof => write!(f, "unexpected end of data"),
+            DecodeError::InvalidType(ty) => write!(f, "invalid variant type: {}", ty),
+            DecodeError::InvalidUtf8 => write!(f, "string is not valid UTF-8"),
+            DecodeError::TooDeep => write!(f, "data is nested too deeply"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl VariantValue {
+    /// Returns the type of this value.
+    pub fn get_type(&self) -> VariantType {
+        match self {
+            VariantValue::Nil => VariantType::Nil,
+            VariantValue::Bool(_) => VariantType::Bool,
+            VariantValue::I64(_) => VariantType::I64,
+            VariantValue::F64(_) => VariantType::F64,
+            VariantValue::GodotString(_) => VariantType::GodotString,
+            VariantValue::Vector2(_) => VariantType::Vector2,
+            VariantValue::Rect2(_) => VariantType::Rect2,
+            VariantValue::Vector3(_) => VariantType::Vector3,
+            VariantValue::Transform2D(_) => VariantType::Transform2D,
+            VariantValue::Plane(_) => VariantType::Plane,
+            VariantValue::Quat(_) => VariantType::Quat,
+            VariantValue::Aabb(_) => VariantType::Aabb,
+            VariantValue::Basis(_) => VariantType::Basis,
+            VariantValue::Transform(_) => VariantType::Transform,
+            VariantValue::Color(_) => VariantType::Color,
+            VariantValue::NodePath(_) => VariantType::NodePath,
+            VariantValue::Rid => VariantType::Rid,
+            VariantValue::Object(_) => VariantType::Object,
+            VariantValue::Dictionary(_) => VariantType::Dictionary,
+            VariantValue::VariantArray(_) => VariantType::VariantArray,
+            VariantValue::ByteArray(_) => VariantType::ByteArray,
+            VariantValue::Int32Array(_) => VariantType::Int32Array,
+            VariantValue::Float32Array(_) => VariantType::Float32Array,
+            VariantValue::StringArray(_) => VariantType::StringArray,
+            VariantValue::Vector2Array(_) => VariantType::Vector2Array,
+            VariantValue::Vector3Array(_) => VariantType::Vector3Array,
+            VariantValue::ColorArray(_) => VariantType::ColorArray,
+        }
+    }
+
+    /// Encodes this value in Godot's binary format, like `var2bytes`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.encode(&mut buf);
+        buf
+    }
+
+    /// Encodes this value in Godot's binary format, appending the result to `buf`.
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        let mut header = self.get_type() as u32;
+        match self {
+            VariantValue::I64(v) if *v > i64::from(i32::MAX) || *v < i64::from(i32::MIN) => {
+                header |= HEADER_FLAG_64
+            }
+            VariantValue::F64(v) if f64::from(*v as f32) != *v => header |= HEADER_FLAG_64,
+            VariantValue::Object(ObjectValue::InstanceId(_)) => header |= HEADER_FLAG_OBJECT_AS_ID,
+            _ => {}
+        }
+        put_u32(buf, header);
+
+        match self {
+            VariantValue::Nil | VariantValue::Rid => {}
+            VariantValue::Bool(v) => put_u32(buf, *v as u32),
+            VariantValue::I64(v) => {
+                if header & HEADER_FLAG_64 != 0 {
+                    buf.extend_from_slice(&v.to_le_bytes());
+                } else {
+                    buf.extend_from_slice(&(*v as i32).to_le_bytes());
+                }
+            }
+            VariantValue::F64(v) => {
+                if header & HEADER_FLAG_64 != 0 {
+                    buf.extend_from_slice(&v.to_le_bytes());
+                } else {
+                    put_f32(buf, *v as f32);
+                }
+            }
+            VariantValue::GodotString(s) => put_string(buf, s),
+            VariantValue::Vector2(v) => put_vector2(buf, v),
+            VariantValue::Rect2(r) => {
+                put_f32s(buf, &[r.origin.x, r.origin.y, r.size.width, r.size.height])
+            }
+            VariantValue::Vector3(v) => put_vector3(buf, v),
+            VariantValue::Transform2D(t) => {
+                put_f32s(buf, &[t.m11, t.m12, t.m21, t.m22, t.m31, t.m32])
+            }
+            VariantValue::Plane(p) => {
+                put_vector3(buf, &p.normal);
+                put_f32(buf, p.d);
+            }
+            VariantValue::Quat(q) => put_f32s(buf, &[q.i, q.j, q.k, q.r]),
+            VariantValue::Aabb(aabb) => {
+                put_vector3(buf, &aabb.position);
+                put_vector3(buf, &aabb.size);
+            }
+            VariantValue::Basis(basis) => put_basis(buf, basis),
+            VariantValue::Transform(t) => {
+                put_basis(buf, &t.basis);
+                put_vector3(buf, &t.origin);
+            }
+            VariantValue::Color(c) => put_color(buf, c),
+            VariantValue::NodePath(path) => {
+                put_u32(buf, path.names.len() as u32 | NODE_PATH_NEW_FORMAT);
+                put_u32(buf, path.subnames.len() as u32);
+                put_u32(buf, if path.absolute { NODE_PATH_ABSOLUTE } else { 0 });
+                for name in path.names.iter().chain(&path.subnames) {
+                    put_string(buf, name);
+                }
+            }
+            VariantValue::Object(ObjectValue::Null) => put_u32(buf, 0),
+            VariantValue::Object(ObjectValue::InstanceId(id)) => {
+                buf.extend_from_slice(&id.to_le_bytes())
+            }
+            VariantValue::Object(ObjectValue::Full { class, properties }) => {
+                put_string(buf, class);
+                put_u32(buf, properties.len() as u32);
+                for (name, value) in properties {
+                    put_string(buf, name);
+                    value.encode(buf);
+                }
+            }
+            VariantValue::Dictionary(entries) => {
+                put_u32(buf, entries.len() as u32);
+                for (key, value) in entries {
+                    key.encode(buf);
+                    value.encode(buf);
+                }
+            }
+            VariantValue::VariantArray(values) => {
+                put_u32(buf, values.len() as u32);
+                for value in values {
+                    value.encode(buf);
+                }
+            }
+            VariantValue::ByteArray(bytes) => {
+                put_u32(buf, bytes.len() as u32);
+                buf.extend_from_slice(bytes);
+                pad(buf);
+            }
+            VariantValue::Int32Array(values) => {
+                put_u32(buf, values.len() as u32);
+                for v in values {
+                    buf.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            VariantValue::Float32Array(values) => {
+                put_u32(buf, values.len() as u32);
+                put_f32s(buf, values);
+            }
+            VariantValue::StringArray(strings) => {
+                put_u32(buf, strings.len() as u32);
+                for s in strings {
+                    // Strings in pool arrays include the null terminator.
+                    put_u32(buf, s.len() as u32 + 1);
+                    buf.extend_from_slice(s.as_bytes());
+                    buf.push(0);
+                    pad(buf);
+                }
+            }
+            VariantValue::Vector2Array(vectors) => {
+                put_u32(buf, vectors.len() as u32);
+                for v in vectors {
+                    put_vector2(buf, v);
+                }
+            }
+            VariantValue::Vector3Array(vectors) => {
+                put_u32(buf, vectors.len() as u32);
+                for v in vectors {
+                    put_vector3(buf, v);
+                }
+            }
+            VariantValue::ColorArray(colors) => {
+                put_u32(buf, colors.len() as u32);
+                for c in colors {
+                    put_color(buf, c);
+                }
+            }
+        }
+    }
+
+    /// Decodes a value in Godot's binary format, like `bytes2var`. Trailing data is ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode(bytes).map(|(value, _)| value)
+    }
+
+    /// Decodes a value in Godot's binary format, returning it along with the number of bytes
+    /// read.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let value = reader.variant(0)?;
+        Ok((value, reader.pos))
+    }
+}
+
+fn pad(buf: &mut Vec<u8>) {
+    while buf.len() % 4 != 0 {
+        buf.push(0);
+    }
+}
+
+fn put_u32(buf: &mut Vec<u8>, v: u32) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_f32(buf: &mut Vec<u8>, v: f32) {
+    buf.extend_from_slice(&v.to_bits().to_le_bytes());
+}
+
+fn put_f32s(buf: &mut Vec<u8>, values: &[f32]) {
+    for &v in values {
+        put_f32(buf, v);
+    }
+}
+
+fn put_string(buf: &mut Vec<u8>, s: &str) {
+    put_u32(buf, s.len() as u32);
+    buf.extend_from_slice(s.as_bytes());
+    pad(buf);
+}
+
+fn put_vector2(buf: &mut Vec<u8>, v: &Vector2) {
+    put_f32s(buf, &[v.x, v.y]);
+}
+
+fn put_vector3(buf: &mut Vec<u8>, v: &Vector3) {
+    put_f32s(buf, &[v.x, v.y, v.z]);
+}
+
+fn put_basis(buf: &mut Vec<u8>, basis: &Basis) {
+    for row in &basis.elements {
+        put_vector3(buf, row);
+    }
+}
+
+fn put_color(buf: &mut Vec<u8>, c: &Color) {
+    put_f32s(buf, &[c.r, c.g, c.b, c.a]);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() - self.pos < len {
+            return Err(DecodeError::UnexpectedEof);
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn skip_padding(&mut self, len: usize) -> Result<(), DecodeError> {
+        if len % 4 != 0 {
+            self.take(4 - len % 4)?;
+        }
+        Ok(())
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        let mut le = [0; 4];
+        le.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(le))
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        let mut le = [0; 8];
+        le.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(le))
+    }
+
+    fn f32(&mut self) -> Result<f32, DecodeError> {
+        self.u32().map(f32::from_bits)
+    }
+
+    fn len(&mut self) -> Result<usize, DecodeError> {
+        self.u32().map(|len| len as usize)
+    }
+
+    fn string(&mut self) -> Result<String, DecodeError> {
+        let len = self.len()?;
+        let s = self.utf8(len)?;
+        self.skip_padding(len)?;
+        Ok(s)
+    }
+
+    fn utf8(&mut self, len: usize) -> Result<String, DecodeError> {
+        let bytes = self.take(len)?;
+        // Godot stops parsing at the first null character.
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(len);
+        std::str::from_utf8(&bytes[..end])
+            .map(String::from)
+            .map_err(|_| DecodeError::InvalidUtf8)
+    }
+
+    fn vector2(&mut self) -> Result<Vector2, DecodeError> {
+        Ok(Vector2::new(self.f32()?, self.f32()?))
+    }
+
+    fn vector3(&mut self) -> Result<Vector3, DecodeError> {
+        Ok(Vector3::new(self.f32()?, self.f32()?, self.f32()?))
+    }
+
+    fn basis(&mut self) -> Result<Basis, DecodeError> {
+        Ok(Basis {
+            elements: [self.vector3()?, self.vector3()?, self.vector3()?],
+        })
+    }
+
+    fn color(&mut self) -> Result<Color, DecodeError> {
+        Ok(Color::rgba(
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+        ))
+    }
+
+    /// Reads `len` elements with `f`. The capacity isn't reserved upfront, so malformed
+    /// lengths can't cause huge allocations.
+    fn list<T, F>(&mut self, mut f: F) -> Result<Vec<T>, DecodeError>
+    where
+        F: FnMut(&mut Self) -> Result<T, DecodeError>,
+    {
+        let len = self.len()?;
+        let mut values = Vec::new();
+        for _ in 0..len {
+            values.push(f(self)?);
+        }
+        Ok(values)
+    }
+
+    fn variant(&mut self, depth: usize) -> Result<VariantValue, DecodeError> {
+        if depth > MAX_DEPTH {
+            return Err(DecodeError::TooDeep);
+        }
+
+        let header = self.u32()?;
+        let ty = header & HEADER_TYPE_MASK;
+        if ty > VariantType::ColorArray as u32 {
+            return Err(DecodeError::InvalidType(ty));
+        }
+
+        // Only collections are decoded here, keeping the frames of nested values small.
+        match VariantType::from_sys(ty as sys::godot_variant_type) {
+            VariantType::Dictionary => {
+                let len = self.u32()? & !SHARED_FLAG;
+                let mut entries = Vec::new();
+                for _ in 0..len {
+                    entries.push((self.variant(depth + 1)?, self.variant(depth + 1)?));
+                }
+                Ok(VariantValue::Dictionary(entries))
+            }
+            VariantType::VariantArray => {
+                let len = self.u32()? & !SHARED_FLAG;
+                let mut values = Vec::new();
+                for _ in 0..len {
+                    values.push(self.variant(depth + 1)?);
+                }
+                Ok(VariantValue::VariantArray(values))
+            }
+            VariantType::Object if header & HEADER_FLAG_OBJECT_AS_ID == 0 => {
+                Ok(VariantValue::Object(self.full_object(depth)?))
+            }
+            ty => self.value(ty, header),
+        }
+    }
+
+    /// Decodes a value that doesn't contain other variants.
+    fn value(&mut self, ty: VariantType, header: u32) -> Result<VariantValue, DecodeError> {
+        let flag_64 = header & HEADER_FLAG_64 != 0;
+
+        let value = match ty {
+            VariantType::Nil => VariantValue::Nil,
+            VariantType::Bool => VariantValue::Bool(self.u32()? != 0),
+            VariantType::I64 => {
+                if flag_64 {
+                    VariantValue::I64(self.u64()? as i64)
+                } else {
+                    VariantValue::I64(i64::from(self.u32()? as i32))
+                }
+            }
+            VariantType::F64 => {
+                if flag_64 {
+                    VariantValue::F64(f64::from_bits(self.u64()?))
+                } else {
+                    VariantValue::F64(f64::from(self.f32()?))
+                }
+            }
+            VariantType::GodotString => VariantValue::GodotString(self.string()?),
+            VariantType::Vector2 => VariantValue::Vector2(self.vector2()?),
+            VariantType::Rect2 => VariantValue::Rect2(euclid::rect(
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+            )),
+            VariantType::Vector3 => VariantValue::Vector3(self.vector3()?),
+            VariantType::Transform2D => VariantValue::Transform2D(Transform2D::row_major(
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+            )),
+            VariantType::Plane => VariantValue::Plane(Plane {
+                normal: self.vector3()?,
+                d: self.f32()?,
+            }),
+            VariantType::Quat => VariantValue::Quat(Quat::quaternion(
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+                self.f32()?,
+            )),
+            VariantType::Aabb => VariantValue::Aabb(Aabb {
+                position: self.vector3()?,
+                size: self.vector3()?,
+            }),
+            VariantType::Basis => VariantValue::Basis(self.basis()?),
+            VariantType::Transform => VariantValue::Transform(Transform {
+                basis: self.basis()?,
+                origin: self.vector3()?,
+            }),
+            VariantType::Color => VariantValue::Color(self.color()?),
+            VariantType::NodePath => VariantValue::NodePath(self.node_path()?),
+            VariantType::Rid => VariantValue::Rid,
+            VariantType::Object => VariantValue::Object(ObjectValue::InstanceId(self.u64()?)),
+            VariantType::Dictionary | VariantType::VariantArray => {
+                unreachable!("collections are decoded by `Reader::variant`")
+            }
+            VariantType::ByteArray => {
+                let len = self.len()?;
+                let bytes = self.take(len)?.to_vec();
+                self.skip_padding(len)?;
+                VariantValue::ByteArray(bytes)
+            }
+            VariantType::Int32Array => {
+                VariantValue::Int32Array(self.list(|r| r.u32().map(|v| v as i32))?)
+            }
+            VariantType::Float32Array => VariantValue::Float32Array(self.list(Self::f32)?),
+            VariantType::StringArray => VariantValue::StringArray(self.list(|r| {
+                let len = r.len()?;
+                let s = r.utf8(len)?;
+                r.skip_padding(len)?;
+                Ok(s)
+            })?),
+            VariantType::Vector2Array => VariantValue::Vector2Array(self.list(Self::vector2)?),
+            VariantType::Vector3Array => VariantValue::Vector3Array(self.list(Self::vector3)?),
+            VariantType::ColorArray => VariantValue::ColorArray(self.list(Self::color)?),
+        };
+
+        Ok(value)
+    }
+
+    fn node_path(&mut self) -> Result<NodePathValue, DecodeError> {
+        let name_count = self.u32()?;
+        if name_count & NODE_PATH_NEW_FORMAT == 0 {
+            // Old format: the path is stored as a string, and `name_count` is its length.
+            let len = name_count as usize;
+            let path = self.utf8(len)?;
+            self.skip_padding(len)?;
+            return Ok(NodePathValue::parse(&path));
+        }
+
+        let name_count = name_count & !NODE_PATH_NEW_FORMAT;
+        let mut subname_count = self.u32()?;
+        let flags = self.u32()?;
+        if flags & NODE_PATH_PROPERTY != 0 {
+            subname_count += 1;
+        }
+
+        let mut path = NodePathValue {
+            absolute: flags & NODE_PATH_ABSOLUTE != 0,
+            ..NodePathValue::default()
+        };
+        for _ in 0..name_count {
+            path.names.push(self.string()?);
+        }
+        for _ in 0..subname_count {
+            path.subnames.push(self.string()?);
+        }
+        Ok(path)
+    }
+
+    fn full_object(&mut self, depth: usize) -> Result<ObjectValue, DecodeError> {
+        let class = self.string()?;
+        if class.is_empty() {
+            return Ok(ObjectValue::Null);
+        }
+
+        let properties = self.list(|r| Ok((r.string()?, r.variant(depth + 1)?)))?;
+        Ok(ObjectValue::Full { class, properties })
+    }
+}
+
+/// Converts a `Variant` to Godot's binary format, like `var2bytes`.
+///
+/// Objects are encoded as their instance IDs.
+pub fn var2bytes(variant: &Variant) -> Vec<u8> {
+    VariantValue::from_variant_ref(variant).to_bytes()
+}
+
+/// Converts data in Godot's binary format to a `Variant`, like `bytes2var`.
+///
+/// Objects can't be decoded, and are converted to nil.
+pub fn bytes2var(bytes: &[u8]) -> Result<Variant, DecodeError> {
+    VariantValue::from_bytes(bytes).map(|value| value.to_variant())
+}
+
+impl VariantValue {
+    fn from_variant_ref(variant: &Variant) -> Self {
+        match variant.get_type() {
+            VariantType::Nil => VariantValue::Nil,
+            VariantType::Bool => VariantValue::Bool(variant.to_bool()),
+            VariantType::I64 => VariantValue::I64(variant.to_i64()),
+            VariantType::F64 => VariantValue::F64(variant.to_f64()),
+            VariantType::GodotString => VariantValue::GodotString(variant.to_string()),
+            VariantType::Vector2 => VariantValue::Vector2(variant.to_vector2()),
+            VariantType::Rect2 => VariantValue::Rect2(variant.to_rect2()),
+            VariantType::Vector3 => VariantValue::Vector3(variant.to_vector3()),
+            VariantType::Transform2D => VariantValue::Transform2D(variant.to_transform2d()),
+            VariantType::Plane => VariantValue::Plane(variant.to_plane()),
+            VariantType::Quat => VariantValue::Quat(variant.to_quat()),
+            VariantType::Aabb => VariantValue::Aabb(variant.to_aabb()),
+            VariantType::Basis => VariantValue::Basis(variant.to_basis()),
+            VariantType::Transform => VariantValue::Transform(variant.to_transform()),
+            VariantType::Color => VariantValue::Color(variant.to_color()),
+            VariantType::NodePath => {
+                let mut path = variant.to_node_path();
+                let names = (0..path.name_count())
+                    .map(|i| path.get_name(i).to_string())
+                    .collect();
+                let subnames = (0..path.get_subname_count())
+                    .map(|i| path.get_subname(i).to_string())
+                    .collect();
+                VariantValue::NodePath(NodePathValue {
+                    names,
+                    subnames,
+                    absolute: path.is_absolute(),
+                })
+            }
+            VariantType::Rid => VariantValue::Rid,
+            VariantType::Object => match variant.try_to_object::<Object>() {
+                Some(object) => VariantValue::Object(ObjectValue::InstanceId(unsafe {
+                    object.get_instance_id() as u64
+                })),
+                None => VariantValue::Object(ObjectValue::Null),
+            },
+            VariantType::Dictionary => {
+                let dict = variant.to_dictionary();
+                let entries = dict
+                    .keys()
+                    .iter()
+                    .map(|key| {
+                        (
+                            Self::from_variant_ref(key),
+                            Self::from_variant_ref(dict.get_ref(key)),
+                        )
+                    })
+                    .collect();
+                VariantValue::Dictionary(entries)
+            }
+            VariantType::VariantArray => VariantValue::VariantArray(
+                variant
+                    .to_array()
+                    .iter()
+                    .map(Self::from_variant_ref)
+                    .collect(),
+            ),
+            VariantType::ByteArray => VariantValue::ByteArray(variant.to_byte_array().to_vec()),
+            VariantType::Int32Array => VariantValue::Int32Array(variant.to_int32_array().to_vec()),
+            VariantType::Float32Array => {
+                VariantValue::Float32Array(variant.to_float32_array().to_vec())
+            }
+            VariantType::StringArray => VariantValue::StringArray(
+                variant
+                    .to_string_array()
+                    .read()
+                    .iter()
+                    .map(GodotString::to_string)
+                    .collect(),
+            ),
+            VariantType::Vector2Array => {
+                VariantValue::Vector2Array(variant.to_vector2_array().to_vec())
+            }
+            VariantType::Vector3Array => {
+                VariantValue::Vector3Array(variant.to_vector3_array().to_vec())
+            }
+            VariantType::ColorArray => VariantValue::ColorArray(variant.to_color_array().to_vec()),
+        }
+    }
+}
+
+impl FromVariant for VariantValue {
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        Ok(Self::from_variant_ref(variant))
+    }
+}
+
+impl ToVariant for VariantValue {
+    /// Converts this value to a `Variant`. Objects and RIDs can't be restored, and are
+    /// converted to nil and an invalid RID respectively.
+    fn to_variant(&self) -> Variant {
+        match self {
+            VariantValue::Nil => Variant::new(),
+            VariantValue::Bool(v) => Variant::from_bool(*v),
+            VariantValue::I64(v) => Variant::from_i64(*v),
+            VariantValue::F64(v) => Variant::from_f64(*v),
+            VariantValue::GodotString(s) => Variant::from_str(s),
+            VariantValue::Vector2(v) => v.to_variant(),
+            VariantValue::Rect2(v) => v.to_variant(),
+            VariantValue::Vector3(v) => v.to_variant(),
+            VariantValue::Transform2D(v) => v.to_variant(),
+            VariantValue::Plane(v) => v.to_variant(),
+            VariantValue::Quat(v) => v.to_variant(),
+            VariantValue::Aabb(v) => v.to_variant(),
+            VariantValue::Basis(v) => v.to_variant(),
+            VariantValue::Transform(v) => v.to_variant(),
+            VariantValue::Color(v) => v.to_variant(),
+            VariantValue::NodePath(path) => NodePath::from_str(&path.to_string()).to_variant(),
+            VariantValue::Rid => Rid::new().to_variant(),
+            VariantValue::Object(_) => Variant::new(),
+            VariantValue::Dictionary(entries) => {
+                let mut dict = Dictionary::new();
+                for (key, value) in entries {
+                    dict.set(&key.to_variant(), &value.to_variant());
+                }
+                dict.to_variant()
+            }
+            VariantValue::VariantArray(values) => {
+                let mut array = VariantArray::new();
+                for value in values {
+                    array.push(&value.to_variant());
+                }
+                array.to_variant()
+            }
+            VariantValue::ByteArray(bytes) => ByteArray::from_slice(bytes).to_variant(),
+            VariantValue::Int32Array(values) => Int32Array::from_slice(values).to_variant(),
+            VariantValue::Float32Array(values) => Float32Array::from_slice(values).to_variant(),
+            VariantValue::StringArray(strings) => strings
+                .iter()
+                .map(GodotString::from_str)
+                .collect::<StringArray>()
+                .to_variant(),
+            VariantValue::Vector2Array(vectors) => Vector2Array::from_slice(vectors).to_variant(),
+            VariantValue::Vector3Array(vectors) => Vector3Array::from_slice(vectors).to_variant(),
+            VariantValue::ColorArray(colors) => ColorArray::from_slice(colors).to_variant(),
+        }
+    }
+}
+
+godot_test!(
+    test_marshal_variant_round_trip {
+        let mut dict = Dictionary::new();
+        dict.set(&"name".into(), &"player".into());
+        dict.set(&"position".into(), &Vector3::new(1.0, 2.0, 3.0).to_variant());
+        dict.set(&"path".into(), &NodePath::from_str("/root/Main:position:x").to_variant());
+
+        let mut scores = Int32Array::new();
+        scores.push(3);
+        scores.push(-7);
+        dict.set(&"scores".into(), &scores.to_variant());
+
+        let variant = dict.to_variant();
+        let bytes = crate::marshal::var2bytes(&variant);
+        assert_eq!(&[18, 0, 0, 0, 4, 0, 0, 0], &bytes[..8]);
+
+        let back = crate::marshal::bytes2var(&bytes).expect("should decode");
+        let back = back.try_to_dictionary().expect("should be dictionary");
+        assert_eq!(Some("player".into()), back.get(&"name".into()).try_to_string());
+        assert_eq!(
+            Some(Vector3::new(1.0, 2.0, 3.0)),
+            back.get(&"position".into()).try_to_vector3()
+        );
+        assert_eq!(
+            Some("/root/Main:position:x".into()),
+            back.get(&"path".into()).try_to_node_path().map(|path| path.to_string())
+        );
+        let scores = back.get(&"scores".into()).try_to_int32_array().expect("should be Int32Array");
+        assert_eq!(&[3, -7], &*scores.read());
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: VariantValue, bytes: &[u8]) {
+        assert_eq!(bytes, &value.to_bytes()[..], "encoding {:?}", value);
+        assert_eq!(Ok((value, bytes.len())), VariantValue::decode(bytes));
+    }
+
+    #[test]
+    fn scalars() {
+        round_trip(VariantValue::Nil, &[0, 0, 0, 0]);
+        round_trip(VariantValue::Bool(true), &[1, 0, 0, 0, 1, 0, 0, 0]);
+        round_trip(VariantValue::I64(-2), &[2, 0, 0, 0, 254, 255, 255, 255]);
+        round_trip(
+            VariantValue::I64(1 << 40),
+            &[2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+        );
+        round_trip(VariantValue::F64(1.5), &[3, 0, 0, 0, 0, 0, 192, 63]);
+        round_trip(
+            VariantValue::F64(0.1),
+            &[3, 0, 1, 0, 154, 153, 153, 153, 153, 153, 185, 63],
+        );
+    }
+
+    #[test]
+    fn strings() {
+        round_trip(
+            VariantValue::GodotString("hello".into()),
+            &[4, 0, 0, 0, 5, 0, 0, 0, 104, 101, 108, 108, 111, 0, 0, 0],
+        );
+        round_trip(
+            VariantValue::GodotString("".into()),
+            &[4, 0, 0, 0, 0, 0, 0, 0],
+        );
+        round_trip(
+            VariantValue::StringArray(vec!["ab".into(), "".into()]),
+            &[
+                23, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 97, 98, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+            ],
+        );
+    }
+
+    #[test]
+    fn math() {
+        round_trip(
+            VariantValue::Vector2(Vector2::new(1.0, 2.0)),
+            &[5, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64],
+        );
+        round_trip(
+            VariantValue::Rect2(euclid::rect(0.0, 1.0, 2.0, -2.0)),
+            &[
+                6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 0, 192,
+            ],
+        );
+        round_trip(
+            VariantValue::Plane(Plane {
+                normal: Vector3::new(0.0, 1.0, 0.0),
+                d: 2.0,
+            }),
+            &[
+                9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 0, 0, 0, 0, 64,
+            ],
+        );
+        round_trip(
+            VariantValue::Color(Color::rgba(1.0, 0.0, 0.0, 1.0)),
+            &[
+                14, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 63,
+            ],
+        );
+
+        let transform = VariantValue::Transform(Transform {
+            basis: Basis {
+                elements: [
+                    Vector3::new(1.0, 0.0, 0.0),
+                    Vector3::new(0.0, 1.0, 0.0),
+                    Vector3::new(0.0, 0.0, 1.0),
+                ],
+            },
+            origin: Vector3::new(0.0, 0.0, 2.0),
+        });
+        let bytes = transform.to_bytes();
+        assert_eq!(4 + 48, bytes.len());
+        assert_eq!(&[13, 0, 0, 0, 0, 0, 128, 63], &bytes[..8]);
+        assert_eq!(&[0, 0, 0, 64], &bytes[48..]);
+        assert_eq!(Ok(transform), VariantValue::from_bytes(&bytes));
+    }
+
+    #[test]
+    fn node_paths() {
+        let path = NodePathValue::parse("/root/Main:position:x");
+        assert_eq!(vec!["root", "Main"], path.names);
+        assert_eq!(vec!["position", "x"], path.subnames);
+        assert!(path.absolute);
+        assert_eq!("/root/Main:position:x", path.to_string());
+
+        round_trip(
+            VariantValue::NodePath(NodePathValue::parse("a:b")),
+            &[
+                15, 0, 0, 0, 1, 0, 0, 128, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 97, 0, 0, 0, 1, 0,
+                0, 0, 98, 0, 0, 0,
+            ],
+        );
+
+        // Old format, storing the path as a string
+        assert_eq!(
+            Ok(VariantValue::NodePath(NodePathValue::parse("/a"))),
+            VariantValue::from_bytes(&[15, 0, 0, 0, 2, 0, 0, 0, 47, 97, 0, 0]),
+        );
+    }
+
+    #[test]
+    fn collections() {
+        round_trip(
+            VariantValue::Dictionary(vec![(
+                VariantValue::GodotString("a".into()),
+                VariantValue::Bool(true),
+            )]),
+            &[
+                18, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 97, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
+                0,
+            ],
+        );
+        round_trip(
+            VariantValue::VariantArray(vec![VariantValue::Nil, VariantValue::I64(7)]),
+            &[19, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 7, 0, 0, 0],
+        );
+        round_trip(
+            VariantValue::ByteArray(vec![1, 2, 3]),
+            &[20, 0, 0, 0, 3, 0, 0, 0, 1, 2, 3, 0],
+        );
+        round_trip(
+            VariantValue::Int32Array(vec![-1]),
+            &[21, 0, 0, 0, 1, 0, 0, 0, 255, 255, 255, 255],
+        );
+        round_trip(
+            VariantValue::Vector2Array(vec![Vector2::new(0.0, 1.0)]),
+            &[24, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 63],
+        );
+
+        // Shared arrays have the high bit of their length set
+        assert_eq!(
+            Ok(VariantValue::VariantArray(vec![])),
+            VariantValue::from_bytes(&[19, 0, 0, 0, 0, 0, 0, 128]),
+        );
+    }
+
+    #[test]
+    fn objects() {
+        round_trip(
+            VariantValue::Object(ObjectValue::InstanceId(1234)),
+            &[17, 0, 1, 0, 210, 4, 0, 0, 0, 0, 0, 0],
+        );
+        round_trip(
+            VariantValue::Object(ObjectValue::Null),
+            &[17, 0, 0, 0, 0, 0, 0, 0],
+        );
+        round_trip(
+            VariantValue::Object(ObjectValue::Full {
+                class: "Node".into(),
+                properties: vec![("name".into(), VariantValue::GodotString("x".into()))],
+            }),
+            &[
+                17, 0, 0, 0, 4, 0, 0, 0, 78, 111, 100, 101, 1, 0, 0, 0, 4, 0, 0, 0, 110, 97, 109,
+                101, 4, 0, 0, 0, 1, 0, 0, 0, 120, 0, 0, 0,
+            ],
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            Err(DecodeError::UnexpectedEof),
+            VariantValue::from_bytes(&[2, 0, 0])
+        );
+        assert_eq!(
+            Err(DecodeError::UnexpectedEof),
+            VariantValue::from_bytes(&[19, 0, 0, 0, 255, 255, 255, 127])
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidType(27)),
+            VariantValue::from_bytes(&[27, 0, 0, 0])
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidUtf8),
+            VariantValue::from_bytes(&[4, 0, 0, 0, 1, 0, 0, 0, 255, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn too_deep() {
+        // Arrays containing a single array each
+        let nested = |depth: usize| {
+            let mut bytes = [19, 0, 0, 0, 1, 0, 0, 0].repeat(depth);
+            bytes.extend_from_slice(&[19, 0, 0, 0, 0, 0, 0, 0]);
+            bytes
+        };
+
+        assert!(VariantValue::from_bytes(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(
+            Err(DecodeError::TooDeep),
+            VariantValue::from_bytes(&nested(MAX_DEPTH + 1))
+        );
+        assert_eq!(
+            Err(DecodeError::TooDeep),
+            VariantValue::from_bytes(&nested(1 << 17))
+        );
+    }
+}
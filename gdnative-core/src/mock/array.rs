@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use sys::{godot_array, godot_bool, godot_int, godot_variant};
+
+use super::dictionary::Dictionary;
+use super::variant::{new_variant, value, Value, Var};
+use super::{get, put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_array_new = array_new;
+    api.godot_array_new_copy = array_new_copy;
+    api.godot_array_destroy = array_destroy;
+    api.godot_array_size = array_size;
+    api.godot_array_empty = array_empty;
+    api.godot_array_get = array_get;
+    api.godot_array_set = array_set;
+    api.godot_array_operator_index = array_operator_index;
+    api.godot_array_operator_index_const = array_operator_index_const;
+    api.godot_array_push_back = array_push_back;
+    api.godot_array_push_front = array_push_front;
+    api.godot_array_pop_back = array_pop_back;
+    api.godot_array_pop_front = array_pop_front;
+    api.godot_array_insert = array_insert;
+    api.godot_array_remove = array_remove;
+    api.godot_array_erase = array_erase;
+    api.godot_array_resize = array_resize;
+    api.godot_array_clear = array_clear;
+    api.godot_array_find = array_find;
+    api.godot_array_find_last = array_find_last;
+    api.godot_array_rfind = array_rfind;
+    api.godot_array_has = array_has;
+    api.godot_array_count = array_count;
+    api.godot_array_hash = array_hash;
+    api.godot_array_invert = array_invert;
+    api.godot_array_sort = array_sort;
+    api.godot_array_bsearch = array_bsearch;
+    api.godot_array_duplicate = array_duplicate;
+}
+
+/// Arrays are reference counted and shared by their copies, like in the engine.
+pub(super) type Array = Arc<Mutex<Vec<Var>>>;
+
+/// Returns the array referenced by an initialized `godot_array`.
+pub(super) unsafe fn array<'a>(arr: *const godot_array) -> &'a Array {
+    get(arr)
+}
+
+/// Returns a new `godot_array` referencing `arr`.
+pub(super) unsafe fn new_array(arr: Array) -> godot_array {
+    super::new(arr)
+}
+
+pub(super) fn shared(elements: Vec<Var>) -> Array {
+    Arc::new(Mutex::new(elements))
+}
+
+/// Orders values like the `<` operator of the engine, falling back to the order of the types.
+pub(super) fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Int(_), Value::Real(_))
+        | (Value::Real(_), Value::Int(_))
+        | (Value::Real(_), Value::Real(_)) => a
+            .to_real()
+            .partial_cmp(&b.to_real())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        _ => a.get_type().cmp(&b.get_type()),
+    }
+}
+
+unsafe extern "C" fn array_new(dest: *mut godot_array) {
+    put(dest, shared(Vec::new()));
+}
+
+unsafe extern "C" fn array_new_copy(dest: *mut godot_array, src: *const godot_array) {
+    put(dest, array(src).clone());
+}
+
+unsafe extern "C" fn array_destroy(arr: *mut godot_array) {
+    take::<_, Array>(arr);
+}
+
+unsafe extern "C" fn array_size(arr: *const godot_array) -> godot_int {
+    array(arr).lock().len() as godot_int
+}
+
+unsafe extern "C" fn array_empty(arr: *const godot_array) -> godot_bool {
+    array(arr).lock().is_empty()
+}
+
+unsafe extern "C" fn array_get(arr: *const godot_array, idx: godot_int) -> godot_variant {
+    let element = array(arr)
+        .lock()
+        .get(idx as usize)
+        .map(|v| v.value().clone());
+
+    match element {
+        Some(element) => new_variant(element),
+        None => {
+            godot_error!("mock API: array index {} is out of bounds", idx);
+            new_variant(Value::Nil)
+        }
+    }
+}
+
+unsafe extern "C" fn array_set(arr: *mut godot_array, idx: godot_int, v: *const godot_variant) {
+    let v = Var::new(value(v).clone());
+    match array(arr).lock().get_mut(idx as usize) {
+        Some(element) => *element = v,
+        None => godot_error!("mock API: array index {} is out of bounds", idx),
+    }
+}
+
+/// Returns a pointer to an element. The pointer is valid until the array is resized.
+unsafe fn element_ptr(arr: *const godot_array, idx: godot_int) -> *mut godot_variant {
+    let mut elements = array(arr).lock();
+    let len = elements.len();
+    match elements.get_mut(idx as usize) {
+        Some(element) => &mut element.0,
+        None => panic!("mock API: array index {} is out of bounds ({})", idx, len),
+    }
+}
+
+unsafe extern "C" fn array_operator_index(
+    arr: *mut godot_array,
+    idx: godot_int,
+) -> *mut godot_variant {
+    element_ptr(arr, idx)
+}
+
+unsafe extern "C" fn array_operator_index_const(
+    arr: *const godot_array,
+    idx: godot_int,
+) -> *const godot_variant {
+    element_ptr(arr, idx)
+}
+
+unsafe extern "C" fn array_push_back(arr: *mut godot_array, v: *const godot_variant) {
+    let v = Var::new(value(v).clone());
+    array(arr).lock().push(v);
+}
+
+unsafe extern "C" fn array_push_front(arr: *mut godot_array, v: *const godot_variant) {
+    let v = Var::new(value(v).clone());
+    array(arr).lock().insert(0, v);
+}
+
+unsafe extern "C" fn array_pop_back(arr: *mut godot_array) -> godot_variant {
+    match array(arr).lock().pop() {
+        Some(v) => new_variant(v.value().clone()),
+        None => new_variant(Value::Nil),
+    }
+}
+
+unsafe extern "C" fn array_pop_front(arr: *mut godot_array) -> godot_variant {
+    let mut elements = array(arr).lock();
+    if elements.is_empty() {
+        new_variant(Value::Nil)
+    } else {
+        new_variant(elements.remove(0).value().clone())
+    }
+}
+
+unsafe extern "C" fn array_insert(arr: *mut godot_array, pos: godot_int, v: *const godot_variant) {
+    let v = Var::new(value(v).clone());
+    let mut elements = array(arr).lock();
+    if pos >= 0 && pos as usize <= elements.len() {
+        elements.insert(pos as usize, v);
+    } else {
+        godot_error!("mock API: array index {} is out of bounds", pos);
+    }
+}
+
+unsafe extern "C" fn array_remove(arr: *mut godot_array, idx: godot_int) {
+    let mut elements = array(arr).lock();
+    if idx >= 0 && (idx as usize) < elements.len() {
+        elements.remove(idx as usize);
+    } else {
+        godot_error!("mock API: array index {} is out of bounds", idx);
+    }
+}
+
+unsafe extern "C" fn array_erase(arr: *mut godot_array, v: *const godot_variant) {
+    let v = value(v).clone();
+    let mut elements = array(arr).lock();
+    if let Some(idx) = elements.iter().position(|element| *element.value() == v) {
+        elements.remove(idx);
+    }
+}
+
+unsafe extern "C" fn array_resize(arr: *mut godot_array, size: godot_int) {
+    array(arr)
+        .lock()
+        .resize_with(size.max(0) as usize, || Var::new(Value::Nil));
+}
+
+unsafe extern "C" fn array_clear(arr: *mut godot_array) {
+    array(arr).lock().clear();
+}
+
+unsafe extern "C" fn array_find(
+    arr: *const godot_array,
+    what: *const godot_variant,
+    from: godot_int,
+) -> godot_int {
+    let what = value(what).clone();
+    let elements = array(arr).lock();
+    (from.max(0) as usize..elements.len())
+        .find(|&i| *elements[i].value() == what)
+        .map_or(-1, |i| i as godot_int)
+}
+
+unsafe extern "C" fn array_rfind(
+    arr: *const godot_array,
+    what: *const godot_variant,
+    from: godot_int,
+) -> godot_int {
+    let what = value(what).clone();
+    let elements = array(arr).lock();
+    let len = elements.len() as godot_int;
+    let from = if from < 0 {
+        len + from
+    } else {
+        from.min(len - 1)
+    };
+    (0..=from)
+        .rev()
+        .find(|&i| *elements[i as usize].value() == what)
+        .unwrap_or(-1)
+}
+
+unsafe extern "C" fn array_find_last(
+    arr: *const godot_array,
+    what: *const godot_variant,
+) -> godot_int {
+    array_rfind(arr, what, -1)
+}
+
+unsafe extern "C" fn array_has(arr: *const godot_array, v: *const godot_variant) -> godot_bool {
+    array_find(arr, v, 0) != -1
+}
+
+unsafe extern "C" fn array_count(arr: *const godot_array, v: *const godot_variant) -> godot_int {
+    let v = value(v).clone();
+    let elements = array(arr).lock();
+    elements
+        .iter()
+        .filter(|element| *element.value() == v)
+        .count() as godot_int
+}
+
+unsafe extern "C" fn array_hash(arr: *const godot_array) -> godot_int {
+    let string = Value::Array(array(arr).clone()).to_string();
+    super::string::hash(&string) as godot_int
+}
+
+unsafe extern "C" fn array_invert(arr: *mut godot_array) {
+    array(arr).lock().reverse();
+}
+
+unsafe extern "C" fn array_sort(arr: *mut godot_array) {
+    array(arr)
+        .lock()
+        .sort_by(|a, b| compare(a.value(), b.value()));
+}
+
+unsafe extern "C" fn array_bsearch(
+    arr: *mut godot_array,
+    v: *const godot_variant,
+    before: godot_bool,
+) -> godot_int {
+    let v = value(v).clone();
+    let elements = array(arr).lock();
+    let idx = if before {
+        elements.partition_point(|element| compare(element.value(), &v) == Ordering::Less)
+    } else {
+        elements.partition_point(|element| compare(element.value(), &v) != Ordering::Greater)
+    };
+    idx as godot_int
+}
+
+unsafe extern "C" fn array_duplicate(arr: *const godot_array, deep: godot_bool) -> godot_array {
+    new_array(duplicate(array(arr), deep))
+}
+
+/// Copies the elements of `arr`, and the nested arrays and dictionaries if `deep` is `true`.
+fn duplicate(arr: &Array, deep: bool) -> Array {
+    let elements = arr.lock();
+    if deep {
+        shared(elements.iter().map(deep_copy).collect())
+    } else {
+        shared(elements.clone())
+    }
+}
+
+fn deep_copy(v: &Var) -> Var {
+    match v.value() {
+        Value::Array(arr) => Var::new(Value::Array(duplicate(arr, true))),
+        Value::Dictionary(dict) => {
+            let entries = dict
+                .lock()
+                .iter()
+                .map(|(key, value)| (key.clone(), deep_copy(value)))
+                .collect();
+            Var::new(Value::Dictionary(Dictionary::new(Mutex::new(entries))))
+        }
+        _ => v.clone(),
+    }
+}
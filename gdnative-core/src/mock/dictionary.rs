@@ -0,0 +1,278 @@
+use std::ptr;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use sys::{godot_array, godot_bool, godot_dictionary, godot_int, godot_string, godot_variant};
+
+use super::array::{array, new_array, shared};
+use super::string::new_string;
+use super::variant::{new_variant, value, Value, Var};
+use super::{get, put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_dictionary_new = dictionary_new;
+    api.godot_dictionary_new_copy = dictionary_new_copy;
+    api.godot_dictionary_destroy = dictionary_destroy;
+    api.godot_dictionary_size = dictionary_size;
+    api.godot_dictionary_empty = dictionary_empty;
+    api.godot_dictionary_clear = dictionary_clear;
+    api.godot_dictionary_has = dictionary_has;
+    api.godot_dictionary_has_all = dictionary_has_all;
+    api.godot_dictionary_erase = dictionary_erase;
+    api.godot_dictionary_get = dictionary_get;
+    api.godot_dictionary_set = dictionary_set;
+    api.godot_dictionary_operator_index = dictionary_operator_index;
+    api.godot_dictionary_operator_index_const = dictionary_operator_index_const;
+    api.godot_dictionary_keys = dictionary_keys;
+    api.godot_dictionary_values = dictionary_values;
+    api.godot_dictionary_next = dictionary_next;
+    api.godot_dictionary_operator_equal = dictionary_operator_equal;
+    api.godot_dictionary_hash = dictionary_hash;
+    api.godot_dictionary_to_json = dictionary_to_json;
+}
+
+/// Dictionaries are reference counted and shared by their copies, like in the engine. The
+/// entries are kept in insertion order and looked up linearly.
+pub(super) type Dictionary = Arc<Mutex<Vec<(Var, Var)>>>;
+
+/// Returns the dictionary referenced by an initialized `godot_dictionary`.
+pub(super) unsafe fn dictionary<'a>(dict: *const godot_dictionary) -> &'a Dictionary {
+    get(dict)
+}
+
+/// Returns a new `godot_dictionary` referencing `dict`.
+pub(super) unsafe fn new_dictionary(dict: Dictionary) -> godot_dictionary {
+    super::new(dict)
+}
+
+fn position(entries: &[(Var, Var)], key: &Value) -> Option<usize> {
+    entries.iter().position(|(k, _)| k.value() == key)
+}
+
+/// Returns a pointer to the value of `key`, inserting nil if it doesn't exist yet if `insert`
+/// is true. The pointer is valid until the dictionary is modified.
+unsafe fn value_ptr(
+    dict: *const godot_dictionary,
+    key: *const godot_variant,
+    insert: bool,
+) -> *mut godot_variant {
+    let key = value(key).clone();
+    let mut entries = dictionary(dict).lock();
+
+    let idx = match position(&entries, &key) {
+        Some(idx) => idx,
+        None if insert => {
+            entries.push((Var::new(key), Var::new(Value::Nil)));
+            entries.len() - 1
+        }
+        None => return ptr::null_mut(),
+    };
+
+    &mut entries[idx].1 .0
+}
+
+unsafe extern "C" fn dictionary_new(dest: *mut godot_dictionary) {
+    put(dest, Dictionary::default());
+}
+
+unsafe extern "C" fn dictionary_new_copy(
+    dest: *mut godot_dictionary,
+    src: *const godot_dictionary,
+) {
+    put(dest, dictionary(src).clone());
+}
+
+unsafe extern "C" fn dictionary_destroy(dict: *mut godot_dictionary) {
+    take::<_, Dictionary>(dict);
+}
+
+unsafe extern "C" fn dictionary_size(dict: *const godot_dictionary) -> godot_int {
+    dictionary(dict).lock().len() as godot_int
+}
+
+unsafe extern "C" fn dictionary_empty(dict: *const godot_dictionary) -> godot_bool {
+    dictionary(dict).lock().is_empty()
+}
+
+unsafe extern "C" fn dictionary_clear(dict: *mut godot_dictionary) {
+    dictionary(dict).lock().clear();
+}
+
+unsafe extern "C" fn dictionary_has(
+    dict: *const godot_dictionary,
+    key: *const godot_variant,
+) -> godot_bool {
+    let key = value(key).clone();
+    position(&dictionary(dict).lock(), &key).is_some()
+}
+
+unsafe extern "C" fn dictionary_has_all(
+    dict: *const godot_dictionary,
+    keys: *const godot_array,
+) -> godot_bool {
+    let keys = array(keys).lock().clone();
+    let entries = dictionary(dict).lock();
+    keys.iter()
+        .all(|key| position(&entries, key.value()).is_some())
+}
+
+unsafe extern "C" fn dictionary_erase(dict: *mut godot_dictionary, key: *const godot_variant) {
+    let key = value(key).clone();
+    let mut entries = dictionary(dict).lock();
+    if let Some(idx) = position(&entries, &key) {
+        entries.remove(idx);
+    }
+}
+
+unsafe extern "C" fn dictionary_get(
+    dict: *const godot_dictionary,
+    key: *const godot_variant,
+) -> godot_variant {
+    match value_ptr(dict, key, false).as_ref() {
+        Some(v) => new_variant(value(v).clone()),
+        None => {
+            godot_error!("mock API: dictionary key {} doesn't exist", value(key));
+            new_variant(Value::Nil)
+        }
+    }
+}
+
+unsafe extern "C" fn dictionary_set(
+    dict: *mut godot_dictionary,
+    key: *const godot_variant,
+    v: *const godot_variant,
+) {
+    let v = Var::new(value(v).clone());
+    *(value_ptr(dict, key, true) as *mut Var) = v;
+}
+
+unsafe extern "C" fn dictionary_operator_index(
+    dict: *mut godot_dictionary,
+    key: *const godot_variant,
+) -> *mut godot_variant {
+    value_ptr(dict, key, true)
+}
+
+/// Returns a null pointer if the key doesn't exist.
+unsafe extern "C" fn dictionary_operator_index_const(
+    dict: *const godot_dictionary,
+    key: *const godot_variant,
+) -> *const godot_variant {
+    value_ptr(dict, key, false)
+}
+
+unsafe extern "C" fn dictionary_keys(dict: *const godot_dictionary) -> godot_array {
+    let keys = dictionary(dict)
+        .lock()
+        .iter()
+        .map(|(key, _)| key.clone())
+        .collect();
+    new_array(shared(keys))
+}
+
+unsafe extern "C" fn dictionary_values(dict: *const godot_dictionary) -> godot_array {
+    let values = dictionary(dict)
+        .lock()
+        .iter()
+        .map(|(_, v)| v.clone())
+        .collect();
+    new_array(shared(values))
+}
+
+/// Returns a pointer to the key after `key`, or to the first key if `key` is null.
+unsafe extern "C" fn dictionary_next(
+    dict: *const godot_dictionary,
+    key: *const godot_variant,
+) -> *mut godot_variant {
+    let key = key.as_ref().map(|key| value(key).clone());
+    let mut entries = dictionary(dict).lock();
+
+    let next = match key {
+        None => 0,
+        Some(key) => match position(&entries, &key) {
+            Some(idx) => idx + 1,
+            None => return ptr::null_mut(),
+        },
+    };
+
+    match entries.get_mut(next) {
+        Some((key, _)) => &mut key.0,
+        None => ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn dictionary_operator_equal(
+    dict: *const godot_dictionary,
+    other: *const godot_dictionary,
+) -> godot_bool {
+    Arc::ptr_eq(dictionary(dict), dictionary(other))
+}
+
+unsafe extern "C" fn dictionary_hash(dict: *const godot_dictionary) -> godot_int {
+    let string = Value::Dictionary(dictionary(dict).clone()).to_string();
+    super::string::hash(&string) as godot_int
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\u{8}' => json.push_str("\\b"),
+            '\u{c}' => json.push_str("\\f"),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn to_json(v: &Value) -> String {
+    match v {
+        Value::Nil => "null".to_owned(),
+        Value::Bool(b) => b.to_string(),
+        Value::Int(i) => i.to_string(),
+        Value::Real(r) => r.to_string(),
+        Value::String(s) => json_string(s),
+        Value::Dictionary(dict) => {
+            let entries = dict
+                .lock()
+                .iter()
+                .map(|(key, v)| {
+                    format!(
+                        "{}:{}",
+                        json_string(&key.value().to_string()),
+                        to_json(v.value())
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!("{{{}}}", entries.join(","))
+        }
+        Value::Array(_)
+        | Value::PoolByteArray(_)
+        | Value::PoolIntArray(_)
+        | Value::PoolRealArray(_)
+        | Value::PoolStringArray(_)
+        | Value::PoolVector2Array(_)
+        | Value::PoolVector3Array(_)
+        | Value::PoolColorArray(_) => {
+            let elements = v
+                .to_elements()
+                .iter()
+                .map(|element| to_json(element.value()))
+                .collect::<Vec<_>>();
+            format!("[{}]", elements.join(","))
+        }
+        other => json_string(&other.to_string()),
+    }
+}
+
+unsafe extern "C" fn dictionary_to_json(dict: *const godot_dictionary) -> godot_string {
+    new_string(to_json(&Value::Dictionary(dictionary(dict).clone())))
+}
@@ -0,0 +1,330 @@
+use std::ptr;
+
+use sys::*;
+
+use super::array::shared;
+use super::variant::{reals, value, Value};
+use super::{put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_variant_evaluate = variant_evaluate;
+}
+
+unsafe extern "C" fn variant_evaluate(
+    op: godot_variant_operator,
+    a: *const godot_variant,
+    b: *const godot_variant,
+    r_ret: *mut godot_variant,
+    r_valid: *mut godot_bool,
+) {
+    let ret = evaluate(op, value(a), value(b));
+    *r_valid = ret.is_some();
+    take::<_, Value>(r_ret);
+    put(r_ret, ret.unwrap_or(Value::Nil));
+}
+
+/// Applies `op` to the operands, following the type promotion rules of the engine. Returns
+/// `None` if the operator is not defined for the operands.
+#[allow(non_upper_case_globals)]
+fn evaluate(op: godot_variant_operator, a: &Value, b: &Value) -> Option<Value> {
+    match op {
+        godot_variant_operator_GODOT_VARIANT_OP_EQUAL => equal(a, b).map(Value::Bool),
+        godot_variant_operator_GODOT_VARIANT_OP_NOT_EQUAL => equal(a, b).map(|eq| Value::Bool(!eq)),
+        godot_variant_operator_GODOT_VARIANT_OP_LESS => less(a, b).map(Value::Bool),
+        godot_variant_operator_GODOT_VARIANT_OP_LESS_EQUAL => less_equal(a, b).map(Value::Bool),
+        godot_variant_operator_GODOT_VARIANT_OP_GREATER => less(b, a).map(Value::Bool),
+        godot_variant_operator_GODOT_VARIANT_OP_GREATER_EQUAL => less_equal(b, a).map(Value::Bool),
+        godot_variant_operator_GODOT_VARIANT_OP_ADD => add(a, b),
+        godot_variant_operator_GODOT_VARIANT_OP_SUBTRACT => subtract(a, b),
+        godot_variant_operator_GODOT_VARIANT_OP_MULTIPLY => multiply(a, b),
+        godot_variant_operator_GODOT_VARIANT_OP_DIVIDE => divide(a, b),
+        godot_variant_operator_GODOT_VARIANT_OP_NEGATE => negate(a),
+        godot_variant_operator_GODOT_VARIANT_OP_POSITIVE => positive(a),
+        godot_variant_operator_GODOT_VARIANT_OP_MODULE => match (a, b) {
+            (Value::Int(_), Value::Int(0)) => None,
+            (Value::Int(a), Value::Int(b)) => Some(Value::Int(a.wrapping_rem(*b))),
+            _ => None,
+        },
+        godot_variant_operator_GODOT_VARIANT_OP_STRING_CONCAT => {
+            Some(Value::String(format!("{}{}", a, b)))
+        }
+        godot_variant_operator_GODOT_VARIANT_OP_SHIFT_LEFT => {
+            int_op(a, b, |a, b| a.checked_shl(b as u32))
+        }
+        godot_variant_operator_GODOT_VARIANT_OP_SHIFT_RIGHT => {
+            int_op(a, b, |a, b| a.checked_shr(b as u32))
+        }
+        godot_variant_operator_GODOT_VARIANT_OP_BIT_AND => int_op(a, b, |a, b| Some(a & b)),
+        godot_variant_operator_GODOT_VARIANT_OP_BIT_OR => int_op(a, b, |a, b| Some(a | b)),
+        godot_variant_operator_GODOT_VARIANT_OP_BIT_XOR => int_op(a, b, |a, b| Some(a ^ b)),
+        godot_variant_operator_GODOT_VARIANT_OP_BIT_NEGATE => match a {
+            Value::Int(a) => Some(Value::Int(!a)),
+            _ => None,
+        },
+        godot_variant_operator_GODOT_VARIANT_OP_AND => {
+            Some(Value::Bool(a.to_bool() && b.to_bool()))
+        }
+        godot_variant_operator_GODOT_VARIANT_OP_OR => Some(Value::Bool(a.to_bool() || b.to_bool())),
+        godot_variant_operator_GODOT_VARIANT_OP_XOR => {
+            Some(Value::Bool(a.to_bool() != b.to_bool()))
+        }
+        godot_variant_operator_GODOT_VARIANT_OP_NOT => Some(Value::Bool(!a.to_bool())),
+        godot_variant_operator_GODOT_VARIANT_OP_IN => contains(b, a).map(Value::Bool),
+        _ => None,
+    }
+}
+
+fn is_number(v: &Value) -> bool {
+    matches!(v, Value::Int(_) | Value::Real(_))
+}
+
+fn equal(a: &Value, b: &Value) -> Option<bool> {
+    match (a, b) {
+        // Nil can be compared with everything, and is equal to null objects.
+        (Value::Object(o), Value::Nil) | (Value::Nil, Value::Object(o)) => Some(o.is_null()),
+        (Value::Nil, _) | (_, Value::Nil) => Some(a == b),
+        _ if a.get_type() == b.get_type() || (is_number(a) && is_number(b)) => Some(a == b),
+        _ => None,
+    }
+}
+
+fn less(a: &Value, b: &Value) -> Option<bool> {
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => Some(a < b),
+        (Value::Int(a), Value::Int(b)) => Some(a < b),
+        _ if is_number(a) && is_number(b) => Some(a.to_real() < b.to_real()),
+        (Value::String(a), Value::String(b)) => Some(a < b),
+        (Value::Vector2(a), Value::Vector2(b)) => Some(less_reals(&reals(a), &reals(b))),
+        (Value::Vector3(a), Value::Vector3(b)) => Some(less_reals(&reals(a), &reals(b))),
+        (Value::Object(a), Value::Object(b)) => Some(a < b),
+        _ => None,
+    }
+}
+
+fn less_equal(a: &Value, b: &Value) -> Option<bool> {
+    Some(less(a, b)? || equal(a, b)?)
+}
+
+/// Compares the components in order, like the `<` operator of vectors.
+fn less_reals(a: &[godot_real], b: &[godot_real]) -> bool {
+    a.iter()
+        .zip(b)
+        .find(|(a, b)| a != b)
+        .is_some_and(|(a, b)| a < b)
+}
+
+fn add(a: &Value, b: &Value) -> Option<Value> {
+    let sum = match (a, b) {
+        (Value::Int(a), Value::Int(b)) => Value::Int(a.wrapping_add(*b)),
+        _ if is_number(a) && is_number(b) => Value::Real(a.to_real() + b.to_real()),
+        (Value::String(a), Value::String(b)) => Value::String(format!("{}{}", a, b)),
+        (Value::Vector2(a), Value::Vector2(b)) => Value::Vector2(zip(a, b, |a, b| a + b)),
+        (Value::Vector3(a), Value::Vector3(b)) => Value::Vector3(zip(a, b, |a, b| a + b)),
+        (Value::Quat(a), Value::Quat(b)) => Value::Quat(zip(a, b, |a, b| a + b)),
+        (Value::Color(a), Value::Color(b)) => Value::Color(zip(a, b, |a, b| a + b)),
+        (Value::Array(a), Value::Array(b)) => {
+            // `a` and `b` may be the same array
+            let mut elements = a.lock().clone();
+            let other = b.lock().clone();
+            elements.extend(other);
+            Value::Array(shared(elements))
+        }
+        (Value::PoolByteArray(a), Value::PoolByteArray(b)) => Value::PoolByteArray(concat(a, b)),
+        (Value::PoolIntArray(a), Value::PoolIntArray(b)) => Value::PoolIntArray(concat(a, b)),
+        (Value::PoolRealArray(a), Value::PoolRealArray(b)) => Value::PoolRealArray(concat(a, b)),
+        (Value::PoolStringArray(a), Value::PoolStringArray(b)) => {
+            Value::PoolStringArray(concat(a, b))
+        }
+        (Value::PoolVector2Array(a), Value::PoolVector2Array(b)) => {
+            Value::PoolVector2Array(concat(a, b))
+        }
+        (Value::PoolVector3Array(a), Value::PoolVector3Array(b)) => {
+            Value::PoolVector3Array(concat(a, b))
+        }
+        (Value::PoolColorArray(a), Value::PoolColorArray(b)) => Value::PoolColorArray(concat(a, b)),
+        _ => return None,
+    };
+
+    Some(sum)
+}
+
+fn subtract(a: &Value, b: &Value) -> Option<Value> {
+    let difference = match (a, b) {
+        (Value::Int(a), Value::Int(b)) => Value::Int(a.wrapping_sub(*b)),
+        _ if is_number(a) && is_number(b) => Value::Real(a.to_real() - b.to_real()),
+        (Value::Vector2(a), Value::Vector2(b)) => Value::Vector2(zip(a, b, |a, b| a - b)),
+        (Value::Vector3(a), Value::Vector3(b)) => Value::Vector3(zip(a, b, |a, b| a - b)),
+        (Value::Quat(a), Value::Quat(b)) => Value::Quat(zip(a, b, |a, b| a - b)),
+        (Value::Color(a), Value::Color(b)) => Value::Color(zip(a, b, |a, b| a - b)),
+        _ => return None,
+    };
+
+    Some(difference)
+}
+
+fn multiply(a: &Value, b: &Value) -> Option<Value> {
+    let product = match (a, b) {
+        (Value::Int(a), Value::Int(b)) => Value::Int(a.wrapping_mul(*b)),
+        _ if is_number(a) && is_number(b) => Value::Real(a.to_real() * b.to_real()),
+        (Value::Vector2(a), Value::Vector2(b)) => Value::Vector2(zip(a, b, |a, b| a * b)),
+        (Value::Vector2(v), s) | (s, Value::Vector2(v)) if is_number(s) => {
+            Value::Vector2(map(v, |a| a * s.to_real() as godot_real))
+        }
+        (Value::Vector3(a), Value::Vector3(b)) => Value::Vector3(zip(a, b, |a, b| a * b)),
+        (Value::Vector3(v), s) | (s, Value::Vector3(v)) if is_number(s) => {
+            Value::Vector3(map(v, |a| a * s.to_real() as godot_real))
+        }
+        (Value::Quat(a), Value::Quat(b)) => Value::Quat(quat_multiply(a, b)),
+        (Value::Quat(q), s) if is_number(s) => {
+            Value::Quat(map(q, |a| a * s.to_real() as godot_real))
+        }
+        (Value::Color(a), Value::Color(b)) => Value::Color(zip(a, b, |a, b| a * b)),
+        (Value::Color(c), s) if is_number(s) => {
+            Value::Color(map(c, |a| a * s.to_real() as godot_real))
+        }
+        _ => return None,
+    };
+
+    Some(product)
+}
+
+fn divide(a: &Value, b: &Value) -> Option<Value> {
+    // Division by a zero scalar is an error in the engine, even for reals.
+    if is_number(b) && b.to_real() == 0.0 {
+        return None;
+    }
+
+    let quotient = match (a, b) {
+        (Value::Int(a), Value::Int(b)) => Value::Int(a.wrapping_div(*b)),
+        _ if is_number(a) && is_number(b) => Value::Real(a.to_real() / b.to_real()),
+        (Value::Vector2(a), Value::Vector2(b)) => Value::Vector2(zip(a, b, |a, b| a / b)),
+        (Value::Vector2(v), s) if is_number(s) => {
+            Value::Vector2(map(v, |a| a / s.to_real() as godot_real))
+        }
+        (Value::Vector3(a), Value::Vector3(b)) => Value::Vector3(zip(a, b, |a, b| a / b)),
+        (Value::Vector3(v), s) if is_number(s) => {
+            Value::Vector3(map(v, |a| a / s.to_real() as godot_real))
+        }
+        (Value::Quat(q), s) if is_number(s) => {
+            Value::Quat(map(q, |a| a / s.to_real() as godot_real))
+        }
+        (Value::Color(a), Value::Color(b)) => Value::Color(zip(a, b, |a, b| a / b)),
+        (Value::Color(c), s) if is_number(s) => {
+            Value::Color(map(c, |a| a / s.to_real() as godot_real))
+        }
+        _ => return None,
+    };
+
+    Some(quotient)
+}
+
+fn negate(a: &Value) -> Option<Value> {
+    let negated = match a {
+        Value::Int(a) => Value::Int(a.wrapping_neg()),
+        Value::Real(a) => Value::Real(-a),
+        Value::Vector2(v) => Value::Vector2(map(v, |a| -a)),
+        Value::Vector3(v) => Value::Vector3(map(v, |a| -a)),
+        Value::Quat(q) => Value::Quat(map(q, |a| -a)),
+        // Negating a color inverts all of its components, including alpha.
+        Value::Color(c) => Value::Color(map(c, |a| 1.0 - a)),
+        _ => return None,
+    };
+
+    Some(negated)
+}
+
+fn positive(a: &Value) -> Option<Value> {
+    match a {
+        Value::Int(_) | Value::Real(_) | Value::Vector2(_) | Value::Vector3(_) | Value::Quat(_) => {
+            Some(a.clone())
+        }
+        _ => None,
+    }
+}
+
+fn int_op<F>(a: &Value, b: &Value, op: F) -> Option<Value>
+where
+    F: FnOnce(i64, i64) -> Option<i64>,
+{
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => op(*a, *b).map(Value::Int),
+        _ => None,
+    }
+}
+
+/// Implements `item in container`.
+fn contains(container: &Value, item: &Value) -> Option<bool> {
+    match (container, item) {
+        (Value::String(s), Value::String(sub)) => Some(s.contains(sub.as_str())),
+        (Value::Array(arr), _) => {
+            // `item` may be or contain the array itself
+            let elements = arr.lock().clone();
+            Some(
+                elements
+                    .iter()
+                    .any(|element| equal(element.value(), item) == Some(true)),
+            )
+        }
+        (Value::Dictionary(dict), _) => {
+            let entries = dict.lock().clone();
+            Some(entries.iter().any(|(key, _)| key.value() == item))
+        }
+        _ => None,
+    }
+}
+
+fn concat<T: Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    a.iter().chain(b).cloned().collect()
+}
+
+/// Builds a math type from its components, which are all `godot_real`.
+fn from_reals<T: Default>(reals: Vec<godot_real>) -> T {
+    debug_assert_eq!(
+        std::mem::size_of::<T>(),
+        reals.len() * std::mem::size_of::<godot_real>()
+    );
+
+    let mut v = T::default();
+    for (idx, real) in reals.into_iter().enumerate() {
+        unsafe {
+            ptr::write_unaligned((&mut v as *mut T as *mut godot_real).add(idx), real);
+        }
+    }
+    v
+}
+
+fn map<T, F>(v: &T, f: F) -> T
+where
+    T: Default,
+    F: Fn(godot_real) -> godot_real,
+{
+    from_reals(reals(v).into_iter().map(f).collect())
+}
+
+fn zip<T, F>(a: &T, b: &T, f: F) -> T
+where
+    T: Default,
+    F: Fn(godot_real, godot_real) -> godot_real,
+{
+    from_reals(
+        reals(a)
+            .into_iter()
+            .zip(reals(b))
+            .map(|(a, b)| f(a, b))
+            .collect(),
+    )
+}
+
+fn quat_multiply(a: &godot_quat, b: &godot_quat) -> godot_quat {
+    let (a, b) = (reals(a), reals(b));
+    let (x, y, z, w) = (a[0], a[1], a[2], a[3]);
+    let (qx, qy, qz, qw) = (b[0], b[1], b[2], b[3]);
+
+    from_reals(vec![
+        w * qx + x * qw + y * qz - z * qy,
+        w * qy + y * qw + z * qx - x * qz,
+        w * qz + z * qw + x * qy - y * qx,
+        w * qw - x * qx - y * qy - z * qz,
+    ])
+}
@@ -0,0 +1,138 @@
+use std::ptr;
+
+use sys::*;
+
+use super::variant::reals;
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_vector2_get_x = vector2_get_x;
+    api.godot_vector2_get_y = vector2_get_y;
+    api.godot_vector2_set_x = vector2_set_x;
+    api.godot_vector2_set_y = vector2_set_y;
+    api.godot_vector3_get_axis = vector3_get_axis;
+    api.godot_vector3_set_axis = vector3_set_axis;
+
+    api.godot_color_get_h = color_get_h;
+    api.godot_color_get_s = color_get_s;
+    api.godot_color_get_v = color_get_v;
+
+    api.godot_rid_new = rid_new;
+    api.godot_rid_get_id = rid_get_id;
+    api.godot_rid_operator_equal = rid_operator_equal;
+    api.godot_rid_operator_less = rid_operator_less;
+}
+
+// Math types are plain structs of `godot_real`, and are accessed in place.
+
+unsafe fn component<T>(v: *const T, idx: usize) -> godot_real {
+    ptr::read_unaligned((v as *const godot_real).add(idx))
+}
+
+unsafe fn set_component<T>(v: *mut T, idx: usize, value: godot_real) {
+    ptr::write_unaligned((v as *mut godot_real).add(idx), value);
+}
+
+unsafe extern "C" fn vector2_get_x(v: *const godot_vector2) -> godot_real {
+    component(v, 0)
+}
+
+unsafe extern "C" fn vector2_get_y(v: *const godot_vector2) -> godot_real {
+    component(v, 1)
+}
+
+unsafe extern "C" fn vector2_set_x(v: *mut godot_vector2, x: godot_real) {
+    set_component(v, 0, x);
+}
+
+unsafe extern "C" fn vector2_set_y(v: *mut godot_vector2, y: godot_real) {
+    set_component(v, 1, y);
+}
+
+unsafe extern "C" fn vector3_get_axis(
+    v: *const godot_vector3,
+    axis: godot_vector3_axis,
+) -> godot_real {
+    component(v, axis as usize)
+}
+
+unsafe extern "C" fn vector3_set_axis(
+    v: *mut godot_vector3,
+    axis: godot_vector3_axis,
+    value: godot_real,
+) {
+    set_component(v, axis as usize, value);
+}
+
+/// Returns the maximum and minimum of the RGB components.
+fn max_min(rgb: &[godot_real]) -> (godot_real, godot_real) {
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    let min = rgb[0].min(rgb[1]).min(rgb[2]);
+    (max, min)
+}
+
+unsafe extern "C" fn color_get_h(c: *const godot_color) -> godot_real {
+    let rgb = reals(&*c);
+    let (r, g, b) = (rgb[0], rgb[1], rgb[2]);
+    let (max, min) = max_min(&rgb);
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return 0.0;
+    }
+
+    let h = if r == max {
+        (g - b) / delta
+    } else if g == max {
+        2.0 + (b - r) / delta
+    } else {
+        4.0 + (r - g) / delta
+    } / 6.0;
+
+    if h < 0.0 {
+        h + 1.0
+    } else {
+        h
+    }
+}
+
+unsafe extern "C" fn color_get_s(c: *const godot_color) -> godot_real {
+    let (max, min) = max_min(&reals(&*c));
+    if max == 0.0 {
+        0.0
+    } else {
+        (max - min) / max
+    }
+}
+
+unsafe extern "C" fn color_get_v(c: *const godot_color) -> godot_real {
+    max_min(&reals(&*c)).0
+}
+
+// RIDs only store their ID. The mock has no servers, so all RIDs are empty.
+
+unsafe fn rid_id(rid: *const godot_rid) -> u64 {
+    ptr::read_unaligned(rid as *const u64)
+}
+
+unsafe extern "C" fn rid_new(dest: *mut godot_rid) {
+    ptr::write_unaligned(dest as *mut u64, 0);
+}
+
+unsafe extern "C" fn rid_get_id(rid: *const godot_rid) -> godot_int {
+    rid_id(rid) as godot_int
+}
+
+unsafe extern "C" fn rid_operator_equal(
+    rid: *const godot_rid,
+    other: *const godot_rid,
+) -> godot_bool {
+    rid_id(rid) == rid_id(other)
+}
+
+unsafe extern "C" fn rid_operator_less(
+    rid: *const godot_rid,
+    other: *const godot_rid,
+) -> godot_bool {
+    rid_id(rid) < rid_id(other)
+}
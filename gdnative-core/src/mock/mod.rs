@@ -0,0 +1,296 @@
+//! In-process implementation of the core API, for running tests without the engine.
+//!
+//! This module is only available with the `mock_api` feature enabled. [`install`](fn.install.html)
+//! binds a pure-Rust implementation of the core API functions to the global API table, so that
+//! strings, variants, arrays, dictionaries, pool arrays and the math types can be used in
+//! ordinary `cargo test` binaries.
+//!
+//! Everything that needs a running engine, such as method binds, objects and class
+//! registration, is not available. Calling one of these functions prints its name and aborts
+//! the process.
+//!
+//! ## Example
+//!
+//! Enable the feature for tests only:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! gdnative = { version = "0.7", features = ["mock_api"] }
+//! ```
+//!
+//! And install the API at the start of each test:
+//!
+//! ```ignore
+//! #[test]
+//! fn inventory_round_trip() {
+//!     gdnative::mock::install();
+//!
+//!     let inventory = Inventory::default();
+//!     let variant = inventory.to_variant();
+//!     assert_eq!(Some(inventory), Inventory::from_variant(&variant));
+//! }
+//! ```
+//!
+//! The implementation aims to behave like the engine for the common cases, but it is not a
+//! full replacement: dictionaries are not hashed, hashes don't match the ones of the engine,
+//! and some string functions, such as `md5_text`, are not implemented.
+
+mod array;
+mod dictionary;
+mod evaluate;
+mod math;
+mod node_path;
+mod pool_array;
+mod string;
+mod variant;
+
+use std::ptr;
+use std::sync::Once;
+
+use libc::{c_char, c_int};
+
+use crate::GodotApi;
+
+/// Binds the mock API, unless it was already bound by an earlier call.
+///
+/// This is cheap, and can be called at the start of every test.
+pub fn install() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| unsafe {
+        crate::GODOT_API = Some(api());
+    });
+}
+
+fn api() -> GodotApi {
+    let mut api = GodotApi::stub();
+
+    string::bind(&mut api);
+    node_path::bind(&mut api);
+    variant::bind(&mut api);
+    array::bind(&mut api);
+    dictionary::bind(&mut api);
+    evaluate::bind(&mut api);
+    pool_array::bind(&mut api);
+    math::bind(&mut api);
+
+    api.godot_print = print;
+    api.godot_print_error = print_error;
+    api.godot_print_warning = print_warning;
+
+    api
+}
+
+unsafe extern "C" fn print(message: *const sys::godot_string) {
+    println!("{}", string::string(message));
+}
+
+unsafe extern "C" fn print_error(
+    description: *const c_char,
+    function: *const c_char,
+    file: *const c_char,
+    line: c_int,
+) {
+    eprintln!(
+        "ERROR: {}: {}\n   At: {}:{}",
+        string::c_str(function),
+        string::c_str(description),
+        string::c_str(file),
+        line
+    );
+}
+
+unsafe extern "C" fn print_warning(
+    description: *const c_char,
+    function: *const c_char,
+    file: *const c_char,
+    line: c_int,
+) {
+    eprintln!(
+        "WARNING: {}: {}\n   At: {}:{}",
+        string::c_str(function),
+        string::c_str(description),
+        string::c_str(file),
+        line
+    );
+}
+
+// The opaque sys types are at least pointer-sized, and the mock stores a pointer to a boxed
+// Rust value in them. A null pointer is used for values that were never initialized.
+
+/// Moves `value` to the heap and stores the pointer in the opaque `slot`.
+unsafe fn put<S, T>(slot: *mut S, value: T) {
+    debug_assert!(std::mem::size_of::<S>() >= std::mem::size_of::<*mut T>());
+    ptr::write_unaligned(slot as *mut *mut T, Box::into_raw(Box::new(value)));
+}
+
+/// Returns the value stored in `slot` by `put`.
+unsafe fn get<'a, S, T>(slot: *const S) -> &'a mut T {
+    let value = ptr::read_unaligned(slot as *const *mut T);
+    assert!(!value.is_null(), "mock API: use of an uninitialized value");
+    &mut *value
+}
+
+/// Takes the value stored in `slot` by `put`, if any, leaving the slot empty.
+unsafe fn take<S, T>(slot: *mut S) -> Option<T> {
+    let value = ptr::read_unaligned(slot as *const *mut T);
+    ptr::write_unaligned(slot as *mut *mut T, ptr::null_mut());
+    if value.is_null() {
+        None
+    } else {
+        Some(*Box::from_raw(value))
+    }
+}
+
+/// Returns a new sys value holding `value`.
+unsafe fn new<S: Default, T>(value: T) -> S {
+    let mut slot = S::default();
+    put(&mut slot, value);
+    slot
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dictionary, GodotString, ToVariant, Variant, VariantArray};
+
+    fn s(s: &str) -> GodotString {
+        GodotString::from_str(s)
+    }
+
+    fn utf8(s: GodotString) -> String {
+        s.to_utf8().as_str().to_owned()
+    }
+
+    #[test]
+    fn strings() {
+        super::install();
+
+        assert_eq!(4, s("héllo").find(&s("o")));
+        assert_eq!(-1, s("hello").find_from(&s("h"), 1));
+        assert_eq!(3, s("hello").find_last(&s("l")));
+        assert_eq!("ll", utf8(s("hello").sub_string(2..4)));
+        assert_eq!("Camel Case Http", utf8(s("camelCaseHTTP").capitalize()));
+        assert_eq!(
+            "HTTP_Server",
+            utf8(s("HTTPServer").camelcase_to_underscore())
+        );
+        assert_eq!(-42, s("-4x2.5").to_i32());
+        assert_eq!(255, s("0xff").hex_to_int());
+        assert!(s("-1.5e3").is_valid_float());
+        assert!(!s("1a").is_valid_integer());
+        assert_eq!("a%20b", utf8(s("a b").percent_encode()));
+        assert_eq!("a b", utf8(s("a%20b").http_unescape()));
+        assert_eq!("\\\"a\\n\\\"", utf8(s("\"a\n\"").c_escape()));
+        assert_eq!("\"a\n\"", utf8(s("\\\"a\\n\\\"").c_unescape()));
+        assert_eq!("res://a/c", utf8(s("res://a/./b/../c").simplify_path()));
+        assert_eq!("res://a", utf8(s("res://a/b.tscn").get_base_dir()));
+        assert_eq!("b.tscn", utf8(s("res://a/b.tscn").get_file()));
+    }
+
+    #[test]
+    fn variants() {
+        super::install();
+
+        assert_eq!(1.to_variant(), 1.0.to_variant());
+        assert_eq!("Null", utf8(Variant::new().to_godot_string()));
+        assert_eq!(
+            "(1, 2.5)",
+            utf8(crate::Vector2::new(1.0, 2.5).to_variant().to_godot_string())
+        );
+
+        let array = || {
+            let mut array = VariantArray::new();
+            array.push(&"a".to_variant());
+            array.push(&1.to_variant());
+            array
+        };
+        assert_eq!("[a, 1]", utf8(array().to_variant().to_godot_string()));
+
+        // Arrays are compared by value, dictionaries by reference.
+        assert_eq!(array().to_variant(), array().to_variant());
+        let dict = Dictionary::new();
+        assert_ne!(dict.to_variant(), Dictionary::new().to_variant());
+        assert_eq!(dict.to_variant(), dict.new_ref().to_variant());
+    }
+
+    #[test]
+    fn booleanize() {
+        super::install();
+
+        use crate::{
+            Aabb, Basis, Color, Plane, Quat, Rect2, Rid, Transform, Transform2D, Vector2, Vector3,
+        };
+
+        let zero = Vector3::new(0.0, 0.0, 0.0);
+        let one = Vector3::new(1.0, 1.0, 1.0);
+        let zero_basis = Basis::from_elements([zero; 3]);
+
+        // Math types are true unless all of their components are zero.
+        let cases = [
+            (
+                Variant::from_vector2(&Vector2::new(0.0, 0.0)),
+                Variant::from_vector2(&Vector2::new(1.0, 0.0)),
+            ),
+            (
+                Variant::from_rect2(&Rect2::new(
+                    euclid::point2(0.0, 0.0),
+                    euclid::size2(0.0, 0.0),
+                )),
+                Variant::from_rect2(&Rect2::new(
+                    euclid::point2(0.0, 0.0),
+                    euclid::size2(1.0, 1.0),
+                )),
+            ),
+            (Variant::from_vector3(&zero), Variant::from_vector3(&one)),
+            (
+                Variant::from_transform2d(&Transform2D::row_major(0.0, 0.0, 0.0, 0.0, 0.0, 0.0)),
+                Variant::from_transform2d(&Transform2D::identity()),
+            ),
+            (
+                Variant::from_plane(&Plane::new(zero, 0.0)),
+                Variant::from_plane(&Plane::new(one, 0.0)),
+            ),
+            (
+                Variant::from_quat(&Quat::quaternion(0.0, 0.0, 0.0, 0.0)),
+                Variant::from_quat(&Quat::identity()),
+            ),
+            (
+                Variant::from_aabb(&Aabb::new(zero, zero)),
+                Variant::from_aabb(&Aabb::new(zero, one)),
+            ),
+            (
+                Variant::from_basis(&zero_basis),
+                Variant::from_basis(&Basis::identity()),
+            ),
+            (
+                Variant::from_transform(&Transform::new(zero_basis, zero)),
+                Variant::from_transform(&Transform::identity()),
+            ),
+            (
+                Variant::from_color(&Color::rgba(0.0, 0.0, 0.0, 0.0)),
+                Variant::from_color(&Color::rgb(1.0, 0.0, 0.0)),
+            ),
+        ];
+
+        for (zero, non_zero) in cases.iter() {
+            assert!(!zero.to_bool(), "{:?} should be false", zero.get_type());
+            assert!(
+                non_zero.to_bool(),
+                "{:?} should be true",
+                non_zero.get_type()
+            );
+        }
+
+        assert!(!Variant::from_rid(&Rid::new()).to_bool());
+    }
+
+    #[test]
+    fn dictionary_to_json() {
+        super::install();
+
+        let mut dict = Dictionary::new();
+        dict.set(&"a".to_variant(), &1.to_variant());
+        dict.set(&"b".to_variant(), &Variant::new());
+        assert_eq!("{\"a\":1,\"b\":null}", utf8(dict.to_json()));
+    }
+}
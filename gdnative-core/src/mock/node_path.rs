@@ -0,0 +1,110 @@
+use sys::{godot_bool, godot_int, godot_node_path, godot_string};
+
+use super::string::{new_string, string};
+use super::{get, put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_node_path_new = node_path_new;
+    api.godot_node_path_new_copy = node_path_new_copy;
+    api.godot_node_path_destroy = node_path_destroy;
+    api.godot_node_path_as_string = node_path_as_string;
+    api.godot_node_path_is_absolute = node_path_is_absolute;
+    api.godot_node_path_is_empty = node_path_is_empty;
+    api.godot_node_path_get_name_count = node_path_get_name_count;
+    api.godot_node_path_get_name = node_path_get_name;
+    api.godot_node_path_get_subname_count = node_path_get_subname_count;
+    api.godot_node_path_get_subname = node_path_get_subname;
+    api.godot_node_path_get_concatenated_subnames = node_path_get_concatenated_subnames;
+    api.godot_node_path_operator_equal = node_path_operator_equal;
+}
+
+/// Returns the path stored in an initialized `godot_node_path`.
+pub(super) unsafe fn node_path<'a>(path: *const godot_node_path) -> &'a String {
+    get(path)
+}
+
+/// Returns a new `godot_node_path` holding `path`.
+pub(super) unsafe fn new_node_path(path: String) -> godot_node_path {
+    super::new(path)
+}
+
+/// Splits a path of the form `/names/of/nodes:sub:names` into its names and subnames.
+fn parse(path: &str) -> (Vec<&str>, Vec<&str>) {
+    let (names, subnames) = match path.find(':') {
+        Some(i) => (&path[..i], &path[i + 1..]),
+        None => (path, ""),
+    };
+
+    let names = names.split('/').filter(|name| !name.is_empty()).collect();
+    let subnames = subnames
+        .split(':')
+        .filter(|name| !name.is_empty())
+        .collect();
+    (names, subnames)
+}
+
+unsafe fn name_at(names: Vec<&str>, idx: godot_int) -> godot_string {
+    let name = names.get(idx as usize).copied().unwrap_or_default();
+    new_string(name.to_owned())
+}
+
+unsafe extern "C" fn node_path_new(dest: *mut godot_node_path, from: *const godot_string) {
+    put(dest, string(from).clone());
+}
+
+unsafe extern "C" fn node_path_new_copy(dest: *mut godot_node_path, src: *const godot_node_path) {
+    put(dest, node_path(src).clone());
+}
+
+unsafe extern "C" fn node_path_destroy(path: *mut godot_node_path) {
+    take::<_, String>(path);
+}
+
+unsafe extern "C" fn node_path_as_string(path: *const godot_node_path) -> godot_string {
+    new_string(node_path(path).clone())
+}
+
+unsafe extern "C" fn node_path_is_absolute(path: *const godot_node_path) -> godot_bool {
+    node_path(path).starts_with('/')
+}
+
+unsafe extern "C" fn node_path_is_empty(path: *const godot_node_path) -> godot_bool {
+    node_path(path).is_empty()
+}
+
+unsafe extern "C" fn node_path_get_name_count(path: *const godot_node_path) -> godot_int {
+    parse(node_path(path)).0.len() as godot_int
+}
+
+unsafe extern "C" fn node_path_get_name(
+    path: *const godot_node_path,
+    idx: godot_int,
+) -> godot_string {
+    name_at(parse(node_path(path)).0, idx)
+}
+
+unsafe extern "C" fn node_path_get_subname_count(path: *const godot_node_path) -> godot_int {
+    parse(node_path(path)).1.len() as godot_int
+}
+
+unsafe extern "C" fn node_path_get_subname(
+    path: *const godot_node_path,
+    idx: godot_int,
+) -> godot_string {
+    name_at(parse(node_path(path)).1, idx)
+}
+
+unsafe extern "C" fn node_path_get_concatenated_subnames(
+    path: *const godot_node_path,
+) -> godot_string {
+    new_string(parse(node_path(path)).1.join(":"))
+}
+
+unsafe extern "C" fn node_path_operator_equal(
+    path: *const godot_node_path,
+    other: *const godot_node_path,
+) -> godot_bool {
+    let (path, other) = (node_path(path), node_path(other));
+    path.starts_with('/') == other.starts_with('/') && parse(path) == parse(other)
+}
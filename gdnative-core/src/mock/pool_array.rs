@@ -0,0 +1,494 @@
+use sys::*;
+
+use super::array::array;
+use super::string::{new_string, string};
+use super::variant::{same, Value, Var};
+use super::{get, put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_pool_byte_array_new = pool_new::<_, u8>;
+    api.godot_pool_byte_array_new_copy = pool_new_copy::<_, u8>;
+    api.godot_pool_byte_array_new_with_array = pool_new_with_array::<_, u8>;
+    api.godot_pool_byte_array_append = pool_append::<_, u8>;
+    api.godot_pool_byte_array_push_back = pool_append::<_, u8>;
+    api.godot_pool_byte_array_append_array = pool_append_array::<_, u8>;
+    api.godot_pool_byte_array_insert = pool_insert::<_, u8>;
+    api.godot_pool_byte_array_invert = pool_invert::<_, u8>;
+    api.godot_pool_byte_array_remove = pool_remove::<_, u8>;
+    api.godot_pool_byte_array_resize = pool_resize::<_, u8>;
+    api.godot_pool_byte_array_set = pool_set::<_, u8>;
+    api.godot_pool_byte_array_get = pool_get::<_, u8>;
+    api.godot_pool_byte_array_size = pool_size::<_, u8>;
+    api.godot_pool_byte_array_destroy = pool_destroy::<_, u8>;
+    api.godot_pool_byte_array_read = pool_read::<_, u8, _>;
+    api.godot_pool_byte_array_write = pool_write::<_, u8, _>;
+    api.godot_pool_byte_array_read_access_copy = access_copy;
+    api.godot_pool_byte_array_read_access_ptr = access_ptr::<_, u8>;
+    api.godot_pool_byte_array_read_access_destroy = access_destroy;
+    api.godot_pool_byte_array_write_access_copy = access_copy;
+    api.godot_pool_byte_array_write_access_ptr = access_ptr_mut::<_, u8>;
+    api.godot_pool_byte_array_write_access_destroy = access_destroy;
+
+    api.godot_pool_int_array_new = pool_new::<_, godot_int>;
+    api.godot_pool_int_array_new_copy = pool_new_copy::<_, godot_int>;
+    api.godot_pool_int_array_new_with_array = pool_new_with_array::<_, godot_int>;
+    api.godot_pool_int_array_append = pool_append::<_, godot_int>;
+    api.godot_pool_int_array_push_back = pool_append::<_, godot_int>;
+    api.godot_pool_int_array_append_array = pool_append_array::<_, godot_int>;
+    api.godot_pool_int_array_insert = pool_insert::<_, godot_int>;
+    api.godot_pool_int_array_invert = pool_invert::<_, godot_int>;
+    api.godot_pool_int_array_remove = pool_remove::<_, godot_int>;
+    api.godot_pool_int_array_resize = pool_resize::<_, godot_int>;
+    api.godot_pool_int_array_set = pool_set::<_, godot_int>;
+    api.godot_pool_int_array_get = pool_get::<_, godot_int>;
+    api.godot_pool_int_array_size = pool_size::<_, godot_int>;
+    api.godot_pool_int_array_destroy = pool_destroy::<_, godot_int>;
+    api.godot_pool_int_array_read = pool_read::<_, godot_int, _>;
+    api.godot_pool_int_array_write = pool_write::<_, godot_int, _>;
+    api.godot_pool_int_array_read_access_copy = access_copy;
+    api.godot_pool_int_array_read_access_ptr = access_ptr::<_, godot_int>;
+    api.godot_pool_int_array_read_access_destroy = access_destroy;
+    api.godot_pool_int_array_write_access_copy = access_copy;
+    api.godot_pool_int_array_write_access_ptr = access_ptr_mut::<_, godot_int>;
+    api.godot_pool_int_array_write_access_destroy = access_destroy;
+
+    api.godot_pool_real_array_new = pool_new::<_, godot_real>;
+    api.godot_pool_real_array_new_copy = pool_new_copy::<_, godot_real>;
+    api.godot_pool_real_array_new_with_array = pool_new_with_array::<_, godot_real>;
+    api.godot_pool_real_array_append = pool_append::<_, godot_real>;
+    api.godot_pool_real_array_push_back = pool_append::<_, godot_real>;
+    api.godot_pool_real_array_append_array = pool_append_array::<_, godot_real>;
+    api.godot_pool_real_array_insert = pool_insert::<_, godot_real>;
+    api.godot_pool_real_array_invert = pool_invert::<_, godot_real>;
+    api.godot_pool_real_array_remove = pool_remove::<_, godot_real>;
+    api.godot_pool_real_array_resize = pool_resize::<_, godot_real>;
+    api.godot_pool_real_array_set = pool_set::<_, godot_real>;
+    api.godot_pool_real_array_get = pool_get::<_, godot_real>;
+    api.godot_pool_real_array_size = pool_size::<_, godot_real>;
+    api.godot_pool_real_array_destroy = pool_destroy::<_, godot_real>;
+    api.godot_pool_real_array_read = pool_read::<_, godot_real, _>;
+    api.godot_pool_real_array_write = pool_write::<_, godot_real, _>;
+    api.godot_pool_real_array_read_access_copy = access_copy;
+    api.godot_pool_real_array_read_access_ptr = access_ptr::<_, godot_real>;
+    api.godot_pool_real_array_read_access_destroy = access_destroy;
+    api.godot_pool_real_array_write_access_copy = access_copy;
+    api.godot_pool_real_array_write_access_ptr = access_ptr_mut::<_, godot_real>;
+    api.godot_pool_real_array_write_access_destroy = access_destroy;
+
+    api.godot_pool_string_array_new = pool_new::<_, Str>;
+    api.godot_pool_string_array_new_copy = pool_new_copy::<_, Str>;
+    api.godot_pool_string_array_new_with_array = pool_new_with_array::<_, Str>;
+    api.godot_pool_string_array_append = pool_append_ref::<_, Str>;
+    api.godot_pool_string_array_push_back = pool_append_ref::<_, Str>;
+    api.godot_pool_string_array_append_array = pool_append_array::<_, Str>;
+    api.godot_pool_string_array_insert = pool_insert_ref::<_, Str>;
+    api.godot_pool_string_array_invert = pool_invert::<_, Str>;
+    api.godot_pool_string_array_remove = pool_remove::<_, Str>;
+    api.godot_pool_string_array_resize = pool_resize::<_, Str>;
+    api.godot_pool_string_array_set = pool_set_ref::<_, Str>;
+    api.godot_pool_string_array_get = pool_get::<_, Str>;
+    api.godot_pool_string_array_size = pool_size::<_, Str>;
+    api.godot_pool_string_array_destroy = pool_destroy::<_, Str>;
+    api.godot_pool_string_array_read = pool_read::<_, Str, _>;
+    api.godot_pool_string_array_write = pool_write::<_, Str, _>;
+    api.godot_pool_string_array_read_access_copy = access_copy;
+    api.godot_pool_string_array_read_access_ptr = access_ptr::<_, Str>;
+    api.godot_pool_string_array_read_access_destroy = access_destroy;
+    api.godot_pool_string_array_write_access_copy = access_copy;
+    api.godot_pool_string_array_write_access_ptr = access_ptr_mut::<_, Str>;
+    api.godot_pool_string_array_write_access_destroy = access_destroy;
+
+    api.godot_pool_vector2_array_new = pool_new::<_, godot_vector2>;
+    api.godot_pool_vector2_array_new_copy = pool_new_copy::<_, godot_vector2>;
+    api.godot_pool_vector2_array_new_with_array = pool_new_with_array::<_, godot_vector2>;
+    api.godot_pool_vector2_array_append = pool_append_ref::<_, godot_vector2>;
+    api.godot_pool_vector2_array_push_back = pool_append_ref::<_, godot_vector2>;
+    api.godot_pool_vector2_array_append_array = pool_append_array::<_, godot_vector2>;
+    api.godot_pool_vector2_array_insert = pool_insert_ref::<_, godot_vector2>;
+    api.godot_pool_vector2_array_invert = pool_invert::<_, godot_vector2>;
+    api.godot_pool_vector2_array_remove = pool_remove::<_, godot_vector2>;
+    api.godot_pool_vector2_array_resize = pool_resize::<_, godot_vector2>;
+    api.godot_pool_vector2_array_set = pool_set_ref::<_, godot_vector2>;
+    api.godot_pool_vector2_array_get = pool_get::<_, godot_vector2>;
+    api.godot_pool_vector2_array_size = pool_size::<_, godot_vector2>;
+    api.godot_pool_vector2_array_destroy = pool_destroy::<_, godot_vector2>;
+    api.godot_pool_vector2_array_read = pool_read::<_, godot_vector2, _>;
+    api.godot_pool_vector2_array_write = pool_write::<_, godot_vector2, _>;
+    api.godot_pool_vector2_array_read_access_copy = access_copy;
+    api.godot_pool_vector2_array_read_access_ptr = access_ptr::<_, godot_vector2>;
+    api.godot_pool_vector2_array_read_access_destroy = access_destroy;
+    api.godot_pool_vector2_array_write_access_copy = access_copy;
+    api.godot_pool_vector2_array_write_access_ptr = access_ptr_mut::<_, godot_vector2>;
+    api.godot_pool_vector2_array_write_access_destroy = access_destroy;
+
+    api.godot_pool_vector3_array_new = pool_new::<_, godot_vector3>;
+    api.godot_pool_vector3_array_new_copy = pool_new_copy::<_, godot_vector3>;
+    api.godot_pool_vector3_array_new_with_array = pool_new_with_array::<_, godot_vector3>;
+    api.godot_pool_vector3_array_append = pool_append_ref::<_, godot_vector3>;
+    api.godot_pool_vector3_array_push_back = pool_append_ref::<_, godot_vector3>;
+    api.godot_pool_vector3_array_append_array = pool_append_array::<_, godot_vector3>;
+    api.godot_pool_vector3_array_insert = pool_insert_ref::<_, godot_vector3>;
+    api.godot_pool_vector3_array_invert = pool_invert::<_, godot_vector3>;
+    api.godot_pool_vector3_array_remove = pool_remove::<_, godot_vector3>;
+    api.godot_pool_vector3_array_resize = pool_resize::<_, godot_vector3>;
+    api.godot_pool_vector3_array_set = pool_set_ref::<_, godot_vector3>;
+    api.godot_pool_vector3_array_get = pool_get::<_, godot_vector3>;
+    api.godot_pool_vector3_array_size = pool_size::<_, godot_vector3>;
+    api.godot_pool_vector3_array_destroy = pool_destroy::<_, godot_vector3>;
+    api.godot_pool_vector3_array_read = pool_read::<_, godot_vector3, _>;
+    api.godot_pool_vector3_array_write = pool_write::<_, godot_vector3, _>;
+    api.godot_pool_vector3_array_read_access_copy = access_copy;
+    api.godot_pool_vector3_array_read_access_ptr = access_ptr::<_, godot_vector3>;
+    api.godot_pool_vector3_array_read_access_destroy = access_destroy;
+    api.godot_pool_vector3_array_write_access_copy = access_copy;
+    api.godot_pool_vector3_array_write_access_ptr = access_ptr_mut::<_, godot_vector3>;
+    api.godot_pool_vector3_array_write_access_destroy = access_destroy;
+
+    api.godot_pool_color_array_new = pool_new::<_, godot_color>;
+    api.godot_pool_color_array_new_copy = pool_new_copy::<_, godot_color>;
+    api.godot_pool_color_array_new_with_array = pool_new_with_array::<_, godot_color>;
+    api.godot_pool_color_array_append = pool_append_ref::<_, godot_color>;
+    api.godot_pool_color_array_push_back = pool_append_ref::<_, godot_color>;
+    api.godot_pool_color_array_append_array = pool_append_array::<_, godot_color>;
+    api.godot_pool_color_array_insert = pool_insert_ref::<_, godot_color>;
+    api.godot_pool_color_array_invert = pool_invert::<_, godot_color>;
+    api.godot_pool_color_array_remove = pool_remove::<_, godot_color>;
+    api.godot_pool_color_array_resize = pool_resize::<_, godot_color>;
+    api.godot_pool_color_array_set = pool_set_ref::<_, godot_color>;
+    api.godot_pool_color_array_get = pool_get::<_, godot_color>;
+    api.godot_pool_color_array_size = pool_size::<_, godot_color>;
+    api.godot_pool_color_array_destroy = pool_destroy::<_, godot_color>;
+    api.godot_pool_color_array_read = pool_read::<_, godot_color, _>;
+    api.godot_pool_color_array_write = pool_write::<_, godot_color, _>;
+    api.godot_pool_color_array_read_access_copy = access_copy;
+    api.godot_pool_color_array_read_access_ptr = access_ptr::<_, godot_color>;
+    api.godot_pool_color_array_read_access_destroy = access_destroy;
+    api.godot_pool_color_array_write_access_copy = access_copy;
+    api.godot_pool_color_array_write_access_ptr = access_ptr_mut::<_, godot_color>;
+    api.godot_pool_color_array_write_access_destroy = access_destroy;
+}
+
+/// Element type of a pool array.
+///
+/// `Raw` is the type used by the API for single elements, which differs from the stored type
+/// for strings.
+pub(super) trait Element: Clone + Default + 'static {
+    type Raw;
+
+    fn wrap(pool: Vec<Self>) -> Value;
+    fn unwrap(value: &Value) -> Option<&Vec<Self>>;
+    fn to_value(&self) -> Value;
+    fn from_value(value: &Value) -> Self;
+    fn eq(&self, other: &Self) -> bool;
+
+    /// Returns an owned copy of `raw`.
+    unsafe fn from_raw(raw: &Self::Raw) -> Self;
+    /// Transfers the ownership of the element to the caller.
+    fn into_raw(self) -> Self::Raw;
+}
+
+/// Owned `godot_string`, destroyed on drop. Used as the element type of string pool arrays,
+/// which hand out pointers to their elements.
+#[repr(transparent)]
+pub(super) struct Str(godot_string);
+
+impl Str {
+    fn new(s: String) -> Self {
+        unsafe { Str(new_string(s)) }
+    }
+
+    fn as_str(&self) -> &str {
+        unsafe { string(&self.0) }
+    }
+}
+
+impl Default for Str {
+    fn default() -> Self {
+        Str::new(String::new())
+    }
+}
+
+impl Clone for Str {
+    fn clone(&self) -> Self {
+        Str::new(self.as_str().to_owned())
+    }
+}
+
+impl Drop for Str {
+    fn drop(&mut self) {
+        unsafe {
+            take::<_, String>(&mut self.0);
+        }
+    }
+}
+
+impl Element for Str {
+    type Raw = godot_string;
+
+    fn wrap(pool: Vec<Self>) -> Value {
+        Value::PoolStringArray(pool)
+    }
+
+    fn unwrap(value: &Value) -> Option<&Vec<Self>> {
+        match value {
+            Value::PoolStringArray(pool) => Some(pool),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.as_str().to_owned())
+    }
+
+    fn from_value(value: &Value) -> Self {
+        Str::new(value.to_string())
+    }
+
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+
+    unsafe fn from_raw(raw: &godot_string) -> Self {
+        Str::new(string(raw).clone())
+    }
+
+    fn into_raw(self) -> godot_string {
+        let raw = self.0;
+        std::mem::forget(self);
+        raw
+    }
+}
+
+macro_rules! impl_element {
+    ($element:ty, $pool:ident, $to_value:expr, $from_value:expr, $eq:expr) => {
+        impl Element for $element {
+            type Raw = $element;
+
+            fn wrap(pool: Vec<Self>) -> Value {
+                Value::$pool(pool)
+            }
+
+            fn unwrap(value: &Value) -> Option<&Vec<Self>> {
+                match value {
+                    Value::$pool(pool) => Some(pool),
+                    _ => None,
+                }
+            }
+
+            fn to_value(&self) -> Value {
+                $to_value(*self)
+            }
+
+            fn from_value(value: &Value) -> Self {
+                $from_value(value)
+            }
+
+            fn eq(&self, other: &Self) -> bool {
+                $eq(self, other)
+            }
+
+            unsafe fn from_raw(raw: &Self) -> Self {
+                *raw
+            }
+
+            fn into_raw(self) -> Self {
+                self
+            }
+        }
+    };
+}
+
+impl_element!(
+    u8,
+    PoolByteArray,
+    |b| Value::Int(i64::from(b)),
+    |v: &Value| v.to_int() as u8,
+    PartialEq::eq
+);
+impl_element!(
+    godot_int,
+    PoolIntArray,
+    |i| Value::Int(i64::from(i)),
+    |v: &Value| v.to_int() as godot_int,
+    PartialEq::eq
+);
+impl_element!(
+    godot_real,
+    PoolRealArray,
+    |r| Value::Real(f64::from(r)),
+    |v: &Value| v.to_real() as godot_real,
+    PartialEq::eq
+);
+
+macro_rules! impl_math_element {
+    ($element:ty, $pool:ident, $variant:ident) => {
+        impl_element!(
+            $element,
+            $pool,
+            Value::$variant,
+            |v: &Value| match v {
+                Value::$variant(v) => *v,
+                _ => <$element>::default(),
+            },
+            same
+        );
+    };
+}
+
+impl_math_element!(godot_vector2, PoolVector2Array, Vector2);
+impl_math_element!(godot_vector3, PoolVector3Array, Vector3);
+impl_math_element!(godot_color, PoolColorArray, Color);
+
+pub(super) fn pool_eq<T: Element>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.eq(b))
+}
+
+pub(super) fn pool_from_array<T: Element>(elements: &[Var]) -> Vec<T> {
+    elements
+        .iter()
+        .map(|element| T::from_value(element.value()))
+        .collect()
+}
+
+/// Returns the elements of an initialized pool array.
+pub(super) unsafe fn pool<'a, A, T>(arr: *const A) -> &'a mut Vec<T> {
+    get(arr)
+}
+
+unsafe extern "C" fn pool_new<A, T: Element>(dest: *mut A) {
+    put(dest, Vec::<T>::new());
+}
+
+unsafe extern "C" fn pool_new_copy<A, T: Element>(dest: *mut A, src: *const A) {
+    put(dest, pool::<A, T>(src).clone());
+}
+
+unsafe extern "C" fn pool_new_with_array<A, T: Element>(dest: *mut A, arr: *const godot_array) {
+    let elements = array(arr).lock().clone();
+    put(dest, pool_from_array::<T>(&elements));
+}
+
+unsafe extern "C" fn pool_destroy<A, T: Element>(arr: *mut A) {
+    take::<_, Vec<T>>(arr);
+}
+
+unsafe extern "C" fn pool_size<A, T: Element>(arr: *const A) -> godot_int {
+    pool::<A, T>(arr).len() as godot_int
+}
+
+unsafe extern "C" fn pool_append<A, T: Element<Raw = T>>(arr: *mut A, v: T) {
+    pool::<A, T>(arr).push(v);
+}
+
+unsafe extern "C" fn pool_append_ref<A, T: Element>(arr: *mut A, v: *const T::Raw) {
+    pool::<A, T>(arr).push(T::from_raw(&*v));
+}
+
+unsafe extern "C" fn pool_append_array<A, T: Element>(arr: *mut A, other: *const A) {
+    let other = pool::<A, T>(other).clone();
+    pool::<A, T>(arr).extend(other);
+}
+
+unsafe fn insert<A, T: Element>(arr: *mut A, idx: godot_int, v: T) -> godot_error {
+    let pool = pool::<A, T>(arr);
+    if idx >= 0 && idx as usize <= pool.len() {
+        pool.insert(idx as usize, v);
+        godot_error_GODOT_OK
+    } else {
+        godot_error_GODOT_ERR_INVALID_PARAMETER
+    }
+}
+
+unsafe extern "C" fn pool_insert<A, T: Element<Raw = T>>(
+    arr: *mut A,
+    idx: godot_int,
+    v: T,
+) -> godot_error {
+    insert(arr, idx, v)
+}
+
+unsafe extern "C" fn pool_insert_ref<A, T: Element>(
+    arr: *mut A,
+    idx: godot_int,
+    v: *const T::Raw,
+) -> godot_error {
+    insert(arr, idx, T::from_raw(&*v))
+}
+
+unsafe fn set<A, T: Element>(arr: *mut A, idx: godot_int, v: T) {
+    match pool::<A, T>(arr).get_mut(idx as usize) {
+        Some(element) => *element = v,
+        None => godot_error!("mock API: pool array index {} is out of bounds", idx),
+    }
+}
+
+unsafe extern "C" fn pool_set<A, T: Element<Raw = T>>(arr: *mut A, idx: godot_int, v: T) {
+    set(arr, idx, v)
+}
+
+unsafe extern "C" fn pool_set_ref<A, T: Element>(arr: *mut A, idx: godot_int, v: *const T::Raw) {
+    set(arr, idx, T::from_raw(&*v))
+}
+
+unsafe extern "C" fn pool_get<A, T: Element>(arr: *const A, idx: godot_int) -> T::Raw {
+    match pool::<A, T>(arr).get(idx as usize) {
+        Some(element) => element.clone().into_raw(),
+        None => {
+            godot_error!("mock API: pool array index {} is out of bounds", idx);
+            T::default().into_raw()
+        }
+    }
+}
+
+unsafe extern "C" fn pool_remove<A, T: Element>(arr: *mut A, idx: godot_int) {
+    let pool = pool::<A, T>(arr);
+    if idx >= 0 && (idx as usize) < pool.len() {
+        pool.remove(idx as usize);
+    } else {
+        godot_error!("mock API: pool array index {} is out of bounds", idx);
+    }
+}
+
+unsafe extern "C" fn pool_resize<A, T: Element>(arr: *mut A, size: godot_int) {
+    pool::<A, T>(arr).resize_with(size.max(0) as usize, T::default);
+}
+
+unsafe extern "C" fn pool_invert<A, T: Element>(arr: *mut A) {
+    pool::<A, T>(arr).reverse();
+}
+
+// Read and write accesses are boxed pointers to the first element. The elements of string
+// pool arrays are `Str`, which has the same layout as `godot_string`.
+
+struct Access(*mut u8);
+
+unsafe fn new_access<R>(ptr: *mut u8) -> *mut R {
+    Box::into_raw(Box::new(Access(ptr))) as *mut R
+}
+
+unsafe extern "C" fn pool_read<A, T: Element, R>(arr: *const A) -> *mut R {
+    new_access(pool::<A, T>(arr).as_mut_ptr() as *mut u8)
+}
+
+unsafe extern "C" fn pool_write<A, T: Element, W>(arr: *mut A) -> *mut W {
+    new_access(pool::<A, T>(arr).as_mut_ptr() as *mut u8)
+}
+
+unsafe extern "C" fn access_copy<R>(access: *const R) -> *mut R {
+    new_access((*(access as *const Access)).0)
+}
+
+unsafe extern "C" fn access_ptr<R, T: Element>(access: *const R) -> *const T::Raw {
+    (*(access as *const Access)).0 as *const T::Raw
+}
+
+unsafe extern "C" fn access_ptr_mut<R, T: Element>(access: *const R) -> *mut T::Raw {
+    (*(access as *const Access)).0 as *mut T::Raw
+}
+
+unsafe extern "C" fn access_destroy<R>(access: *mut R) {
+    if !access.is_null() {
+        drop(Box::from_raw(access as *mut Access));
+    }
+}
@@ -0,0 +1,724 @@
+use std::ffi::CStr;
+use std::slice;
+
+use libc::{c_char, c_double};
+use sys::{godot_bool, godot_char_string, godot_int, godot_real, godot_string, godot_string_name};
+
+use super::{get, put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_string_new = string_new;
+    api.godot_string_new_copy = string_new_copy;
+    api.godot_string_destroy = string_destroy;
+    api.godot_string_chars_to_utf8_with_len = string_chars_to_utf8_with_len;
+    api.godot_string_utf8 = string_utf8;
+    api.godot_string_length = string_length;
+    api.godot_string_empty = string_empty;
+    api.godot_string_operator_equal = string_operator_equal;
+    api.godot_string_begins_with = string_begins_with;
+    api.godot_string_begins_with_char_array = string_begins_with_char_array;
+    api.godot_string_ends_with = string_ends_with;
+    api.godot_string_find = string_find;
+    api.godot_string_find_from = string_find_from;
+    api.godot_string_find_last = string_find_last;
+    api.godot_string_substr = string_substr;
+    api.godot_string_to_lower = string_to_lower;
+    api.godot_string_to_upper = string_to_upper;
+    api.godot_string_capitalize = string_capitalize;
+    api.godot_string_camelcase_to_underscore = string_camelcase_to_underscore;
+    api.godot_string_camelcase_to_underscore_lowercased = string_camelcase_to_underscore_lowercased;
+    api.godot_string_hash = string_hash;
+    api.godot_string_hash64 = string_hash64;
+    api.godot_string_hex_to_int = string_hex_to_int;
+    api.godot_string_hex_to_int_without_prefix = string_hex_to_int_without_prefix;
+    api.godot_string_to_int = string_to_int;
+    api.godot_string_to_float = string_to_float;
+    api.godot_string_to_double = string_to_double;
+    api.godot_string_is_abs_path = string_is_abs_path;
+    api.godot_string_is_rel_path = string_is_rel_path;
+    api.godot_string_is_resource_file = string_is_resource_file;
+    api.godot_string_is_numeric = string_is_numeric;
+    api.godot_string_is_valid_float = string_is_valid_float;
+    api.godot_string_is_valid_hex_number = string_is_valid_hex_number;
+    api.godot_string_is_valid_html_color = string_is_valid_html_color;
+    api.godot_string_is_valid_identifier = string_is_valid_identifier;
+    api.godot_string_is_valid_integer = string_is_valid_integer;
+    api.godot_string_is_valid_ip_address = string_is_valid_ip_address;
+    api.godot_string_c_escape = string_c_escape;
+    api.godot_string_c_escape_multiline = string_c_escape_multiline;
+    api.godot_string_c_unescape = string_c_unescape;
+    api.godot_string_json_escape = string_json_escape;
+    api.godot_string_http_escape = string_http_escape;
+    api.godot_string_http_unescape = string_http_unescape;
+    api.godot_string_percent_encode = string_percent_encode;
+    api.godot_string_percent_decode = string_percent_decode;
+    api.godot_string_xml_escape = string_xml_escape;
+    api.godot_string_xml_escape_with_quotes = string_xml_escape_with_quotes;
+    api.godot_string_xml_unescape = string_xml_unescape;
+    api.godot_string_get_base_dir = string_get_base_dir;
+    api.godot_string_get_file = string_get_file;
+    api.godot_string_simplify_path = string_simplify_path;
+
+    api.godot_char_string_length = char_string_length;
+    api.godot_char_string_get_data = char_string_get_data;
+    api.godot_char_string_destroy = char_string_destroy;
+
+    api.godot_string_name_new = string_name_new;
+    api.godot_string_name_new_data = string_name_new_data;
+    api.godot_string_name_get_name = string_name_get_name;
+    api.godot_string_name_get_hash = string_name_get_hash;
+    api.godot_string_name_operator_equal = string_name_operator_equal;
+    api.godot_string_name_operator_less = string_name_operator_less;
+    api.godot_string_name_destroy = string_name_destroy;
+}
+
+/// Returns the contents of an initialized `godot_string`.
+pub(super) unsafe fn string<'a>(s: *const godot_string) -> &'a String {
+    get(s)
+}
+
+/// Returns a new `godot_string` holding `s`.
+pub(super) unsafe fn new_string(s: String) -> godot_string {
+    super::new(s)
+}
+
+pub(super) unsafe fn c_str<'a>(s: *const c_char) -> &'a str {
+    CStr::from_ptr(s).to_str().unwrap_or_default()
+}
+
+/// Index of the character starting at byte `byte` of `s`.
+fn char_index(s: &str, byte: usize) -> godot_int {
+    s[..byte].chars().count() as godot_int
+}
+
+/// Byte offset of the character with index `index`, which may be one past the end.
+fn byte_index(s: &str, index: usize) -> Option<usize> {
+    s.char_indices()
+        .map(|(i, _)| i)
+        .chain(Some(s.len()))
+        .nth(index)
+}
+
+/// Same hash as the engine uses for strings (djb2 over the characters).
+pub(super) fn hash(s: &str) -> u32 {
+    s.chars().fold(5381u32, |hash, c| {
+        hash.wrapping_shl(5)
+            .wrapping_add(hash)
+            .wrapping_add(c as u32)
+    })
+}
+
+unsafe extern "C" fn string_new(dest: *mut godot_string) {
+    put(dest, String::new());
+}
+
+unsafe extern "C" fn string_new_copy(dest: *mut godot_string, src: *const godot_string) {
+    put(dest, string(src).clone());
+}
+
+unsafe extern "C" fn string_destroy(s: *mut godot_string) {
+    take::<_, String>(s);
+}
+
+unsafe extern "C" fn string_chars_to_utf8_with_len(
+    utf8: *const c_char,
+    len: godot_int,
+) -> godot_string {
+    let bytes = slice::from_raw_parts(utf8 as *const u8, len.max(0) as usize);
+    new_string(String::from_utf8_lossy(bytes).into_owned())
+}
+
+unsafe extern "C" fn string_utf8(s: *const godot_string) -> godot_char_string {
+    let mut bytes = string(s).clone().into_bytes();
+    bytes.push(0);
+    super::new(bytes)
+}
+
+unsafe extern "C" fn string_length(s: *const godot_string) -> godot_int {
+    string(s).chars().count() as godot_int
+}
+
+unsafe extern "C" fn string_empty(s: *const godot_string) -> godot_bool {
+    string(s).is_empty()
+}
+
+unsafe extern "C" fn string_operator_equal(
+    s: *const godot_string,
+    other: *const godot_string,
+) -> godot_bool {
+    string(s) == string(other)
+}
+
+unsafe extern "C" fn string_begins_with(
+    s: *const godot_string,
+    prefix: *const godot_string,
+) -> godot_bool {
+    string(s).starts_with(string(prefix).as_str())
+}
+
+unsafe extern "C" fn string_begins_with_char_array(
+    s: *const godot_string,
+    prefix: *const c_char,
+) -> godot_bool {
+    string(s).starts_with(c_str(prefix))
+}
+
+unsafe extern "C" fn string_ends_with(
+    s: *const godot_string,
+    suffix: *const godot_string,
+) -> godot_bool {
+    string(s).ends_with(string(suffix).as_str())
+}
+
+fn find_from(s: &str, what: &str, from: godot_int) -> godot_int {
+    if what.is_empty() || from < 0 {
+        return -1;
+    }
+
+    match byte_index(s, from as usize) {
+        Some(start) => s[start..]
+            .find(what)
+            .map_or(-1, |i| char_index(s, start + i)),
+        None => -1,
+    }
+}
+
+// The `find` functions take `what` by value, but don't take ownership of it.
+
+unsafe extern "C" fn string_find(s: *const godot_string, what: godot_string) -> godot_int {
+    find_from(string(s), string(&what), 0)
+}
+
+unsafe extern "C" fn string_find_from(
+    s: *const godot_string,
+    what: godot_string,
+    from: godot_int,
+) -> godot_int {
+    find_from(string(s), string(&what), from)
+}
+
+unsafe extern "C" fn string_find_last(s: *const godot_string, what: godot_string) -> godot_int {
+    let s = string(s);
+    let what = string(&what);
+    if what.is_empty() {
+        return -1;
+    }
+    s.rfind(what.as_str()).map_or(-1, |i| char_index(s, i))
+}
+
+fn substr(s: &str, from: godot_int, chars: godot_int) -> String {
+    let chars = if chars < 0 {
+        usize::MAX
+    } else {
+        chars as usize
+    };
+    s.chars().skip(from.max(0) as usize).take(chars).collect()
+}
+
+unsafe extern "C" fn string_substr(
+    s: *const godot_string,
+    from: godot_int,
+    chars: godot_int,
+) -> godot_string {
+    new_string(substr(string(s), from, chars))
+}
+
+unsafe extern "C" fn string_to_lower(s: *const godot_string) -> godot_string {
+    new_string(string(s).to_lowercase())
+}
+
+unsafe extern "C" fn string_to_upper(s: *const godot_string) -> godot_string {
+    new_string(string(s).to_uppercase())
+}
+
+fn camelcase_to_underscore(s: &str, lowercase: bool) -> String {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(s.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(c) if c.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                result.push('_');
+            }
+        }
+        result.push(c);
+    }
+
+    if lowercase {
+        result.to_lowercase()
+    } else {
+        result
+    }
+}
+
+unsafe extern "C" fn string_capitalize(s: *const godot_string) -> godot_string {
+    let words = camelcase_to_underscore(string(s), true)
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    new_string(words.join(" "))
+}
+
+unsafe extern "C" fn string_camelcase_to_underscore(s: *const godot_string) -> godot_string {
+    new_string(camelcase_to_underscore(string(s), false))
+}
+
+unsafe extern "C" fn string_camelcase_to_underscore_lowercased(
+    s: *const godot_string,
+) -> godot_string {
+    new_string(camelcase_to_underscore(string(s), true))
+}
+
+unsafe extern "C" fn string_hash(s: *const godot_string) -> u32 {
+    hash(string(s))
+}
+
+unsafe extern "C" fn string_hash64(s: *const godot_string) -> u64 {
+    string(s).chars().fold(5381u64, |hash, c| {
+        hash.wrapping_shl(5)
+            .wrapping_add(hash)
+            .wrapping_add(c as u64)
+    })
+}
+
+/// Splits an optional sign off `s`, returning whether it was negative.
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+fn hex_to_int(s: &str, with_prefix: bool) -> godot_int {
+    let (negative, s) = split_sign(s);
+    let digits = if with_prefix {
+        match s.strip_prefix("0x") {
+            Some(digits) => digits,
+            None => return 0,
+        }
+    } else {
+        s
+    };
+
+    match i64::from_str_radix(digits, 16) {
+        Ok(value) if negative => -value as godot_int,
+        Ok(value) => value as godot_int,
+        Err(_) => 0,
+    }
+}
+
+unsafe extern "C" fn string_hex_to_int(s: *const godot_string) -> godot_int {
+    hex_to_int(string(s), true)
+}
+
+unsafe extern "C" fn string_hex_to_int_without_prefix(s: *const godot_string) -> godot_int {
+    hex_to_int(string(s), false)
+}
+
+/// Parses the integer part of `s` like the engine does, ignoring any non-digit characters.
+pub(super) fn to_int(s: &str) -> i64 {
+    let end = s.find('.').unwrap_or(s.len());
+
+    let mut integer: i64 = 0;
+    let mut sign = 1;
+    for c in s[..end].chars() {
+        if let Some(digit) = c.to_digit(10) {
+            integer = integer.wrapping_mul(10).wrapping_add(i64::from(digit));
+        } else if c == '-' && integer == 0 {
+            sign = -sign;
+        }
+    }
+
+    integer * sign
+}
+
+unsafe extern "C" fn string_to_int(s: *const godot_string) -> godot_int {
+    to_int(string(s)) as godot_int
+}
+
+/// Parses the longest prefix of `s` that is a valid number, or returns zero.
+pub(super) fn to_double(s: &str) -> f64 {
+    let s = s.trim_start();
+    let end = s
+        .find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c)))
+        .unwrap_or(s.len());
+
+    (1..=end)
+        .rev()
+        .filter_map(|len| s[..len].parse::<f64>().ok())
+        .next()
+        .unwrap_or(0.0)
+}
+
+unsafe extern "C" fn string_to_float(s: *const godot_string) -> godot_real {
+    to_double(string(s)) as godot_real
+}
+
+unsafe extern "C" fn string_to_double(s: *const godot_string) -> c_double {
+    to_double(string(s))
+}
+
+fn is_abs_path(s: &str) -> bool {
+    s.starts_with('/') || s.starts_with('\\') || s.contains(":/") || s.contains(":\\")
+}
+
+unsafe extern "C" fn string_is_abs_path(s: *const godot_string) -> godot_bool {
+    is_abs_path(string(s))
+}
+
+unsafe extern "C" fn string_is_rel_path(s: *const godot_string) -> godot_bool {
+    !is_abs_path(string(s))
+}
+
+unsafe extern "C" fn string_is_resource_file(s: *const godot_string) -> godot_bool {
+    let s = string(s);
+    s.starts_with("res://") && !s.contains("::")
+}
+
+unsafe extern "C" fn string_is_numeric(s: *const godot_string) -> godot_bool {
+    let s = string(s);
+    if s.is_empty() {
+        return false;
+    }
+
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    let mut dot = false;
+    digits.chars().all(|c| match c {
+        '.' if !dot => {
+            dot = true;
+            true
+        }
+        c => c.is_ascii_digit(),
+    })
+}
+
+unsafe extern "C" fn string_is_valid_float(s: *const godot_string) -> godot_bool {
+    let s = string(s);
+    s.chars().any(|c| c.is_ascii_digit())
+        && s.chars().all(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+        && s.parse::<f64>().is_ok()
+}
+
+fn is_valid_hex_number(s: &str, with_prefix: bool) -> bool {
+    let (_, s) = split_sign(s);
+    let digits = if with_prefix {
+        match s.strip_prefix("0x") {
+            Some(digits) => digits,
+            None => return false,
+        }
+    } else {
+        s
+    };
+
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+unsafe extern "C" fn string_is_valid_hex_number(
+    s: *const godot_string,
+    with_prefix: godot_bool,
+) -> godot_bool {
+    is_valid_hex_number(string(s), with_prefix)
+}
+
+unsafe extern "C" fn string_is_valid_html_color(s: *const godot_string) -> godot_bool {
+    let s = string(s);
+    let digits = s.strip_prefix('#').unwrap_or(s);
+    [3, 4, 6, 8].contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+unsafe extern "C" fn string_is_valid_identifier(s: *const godot_string) -> godot_bool {
+    let s = string(s);
+    match s.chars().next() {
+        Some(first) if !first.is_ascii_digit() => {
+            s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+unsafe extern "C" fn string_is_valid_integer(s: *const godot_string) -> godot_bool {
+    let s = string(s);
+    let digits = if s.len() > 1 { split_sign(s).1 } else { s };
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+unsafe extern "C" fn string_is_valid_ip_address(s: *const godot_string) -> godot_bool {
+    string(s).parse::<std::net::IpAddr>().is_ok()
+}
+
+fn escape(s: &str, escapes: &[(char, &str)]) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match escapes.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => result.push_str(to),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+fn unescape(s: &str, escapes: &[(char, &str)]) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    'outer: while let Some(c) = rest.chars().next() {
+        for (to, from) in escapes {
+            if let Some(after) = rest.strip_prefix(from) {
+                result.push(*to);
+                rest = after;
+                continue 'outer;
+            }
+        }
+        result.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    result
+}
+
+const C_ESCAPES: &[(char, &str)] = &[
+    ('\\', "\\\\"),
+    ('\u{7}', "\\a"),
+    ('\u{8}', "\\b"),
+    ('\u{c}', "\\f"),
+    ('\n', "\\n"),
+    ('\r', "\\r"),
+    ('\t', "\\t"),
+    ('\u{b}', "\\v"),
+    ('\'', "\\'"),
+    ('?', "\\?"),
+    ('"', "\\\""),
+];
+
+const XML_ESCAPES: &[(char, &str)] = &[('&', "&amp;"), ('<', "&lt;"), ('>', "&gt;")];
+
+const XML_ESCAPES_WITH_QUOTES: &[(char, &str)] = &[
+    ('&', "&amp;"),
+    ('<', "&lt;"),
+    ('>', "&gt;"),
+    ('\'', "&apos;"),
+    ('"', "&quot;"),
+];
+
+unsafe extern "C" fn string_c_escape(s: *const godot_string) -> godot_string {
+    new_string(escape(string(s), C_ESCAPES))
+}
+
+unsafe extern "C" fn string_c_escape_multiline(s: *const godot_string) -> godot_string {
+    new_string(escape(string(s), &[('\\', "\\\\"), ('"', "\\\"")]))
+}
+
+unsafe extern "C" fn string_c_unescape(s: *const godot_string) -> godot_string {
+    new_string(unescape(string(s), C_ESCAPES))
+}
+
+unsafe extern "C" fn string_json_escape(s: *const godot_string) -> godot_string {
+    new_string(escape(
+        string(s),
+        &[
+            ('\\', "\\\\"),
+            ('\u{8}', "\\b"),
+            ('\u{c}', "\\f"),
+            ('\n', "\\n"),
+            ('\r', "\\r"),
+            ('\t', "\\t"),
+            ('\u{b}', "\\v"),
+            ('"', "\\\""),
+        ],
+    ))
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            result.push(byte as char);
+        } else {
+            result.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    result
+}
+
+fn percent_decode(s: &str, plus_as_space: bool) -> String {
+    let bytes = s.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let decoded = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match decoded {
+            Some(byte) => {
+                result.push(byte);
+                i += 3;
+            }
+            None => {
+                result.push(if plus_as_space && bytes[i] == b'+' {
+                    b' '
+                } else {
+                    bytes[i]
+                });
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+unsafe extern "C" fn string_http_escape(s: *const godot_string) -> godot_string {
+    new_string(percent_encode(string(s)))
+}
+
+unsafe extern "C" fn string_http_unescape(s: *const godot_string) -> godot_string {
+    new_string(percent_decode(string(s), false))
+}
+
+unsafe extern "C" fn string_percent_encode(s: *const godot_string) -> godot_string {
+    new_string(percent_encode(string(s)))
+}
+
+unsafe extern "C" fn string_percent_decode(s: *const godot_string) -> godot_string {
+    new_string(percent_decode(string(s), true))
+}
+
+unsafe extern "C" fn string_xml_escape(s: *const godot_string) -> godot_string {
+    new_string(escape(string(s), XML_ESCAPES))
+}
+
+unsafe extern "C" fn string_xml_escape_with_quotes(s: *const godot_string) -> godot_string {
+    new_string(escape(string(s), XML_ESCAPES_WITH_QUOTES))
+}
+
+unsafe extern "C" fn string_xml_unescape(s: *const godot_string) -> godot_string {
+    new_string(unescape(string(s), XML_ESCAPES_WITH_QUOTES))
+}
+
+fn last_separator(s: &str) -> Option<usize> {
+    s.rfind(&['/', '\\'][..])
+}
+
+unsafe extern "C" fn string_get_base_dir(s: *const godot_string) -> godot_string {
+    let s = string(s);
+    let (base, rest) = match s.find("://") {
+        Some(i) => s.split_at(i + 3),
+        None if s.starts_with('/') => s.split_at(1),
+        None => ("", s.as_str()),
+    };
+
+    let dir = match last_separator(rest) {
+        Some(i) => format!("{}{}", base, &rest[..i]),
+        None => base.to_owned(),
+    };
+    new_string(dir)
+}
+
+unsafe extern "C" fn string_get_file(s: *const godot_string) -> godot_string {
+    let s = string(s);
+    let file = match last_separator(s) {
+        Some(i) => &s[i + 1..],
+        None => s.as_str(),
+    };
+    new_string(file.to_owned())
+}
+
+unsafe extern "C" fn string_simplify_path(s: *const godot_string) -> godot_string {
+    let s = string(s);
+
+    let drive_len = ["local://", "res://", "user://"]
+        .iter()
+        .find(|prefix| s.starts_with(*prefix))
+        .map(|prefix| prefix.len())
+        .or_else(|| {
+            if s.starts_with('/') || s.starts_with('\\') {
+                Some(1)
+            } else {
+                let colon = s.find(":/").or_else(|| s.find(":\\"))?;
+                match s.find('/') {
+                    Some(slash) if colon >= slash => None,
+                    _ => Some(colon + 2),
+                }
+            }
+        })
+        .unwrap_or(0);
+
+    let (drive, path) = s.split_at(drive_len);
+    let path = path.replace('\\', "/");
+
+    let mut dirs: Vec<&str> = Vec::new();
+    for dir in path.split('/').filter(|dir| !dir.is_empty()) {
+        match dir {
+            "." => {}
+            ".." => {
+                dirs.pop();
+            }
+            dir => dirs.push(dir),
+        }
+    }
+
+    new_string(format!("{}{}", drive, dirs.join("/")))
+}
+
+// Char strings hold the UTF-8 bytes of a string followed by a NUL byte.
+
+unsafe extern "C" fn char_string_length(cs: *const godot_char_string) -> godot_int {
+    (get::<_, Vec<u8>>(cs).len() - 1) as godot_int
+}
+
+unsafe extern "C" fn char_string_get_data(cs: *const godot_char_string) -> *const c_char {
+    get::<_, Vec<u8>>(cs).as_ptr() as *const c_char
+}
+
+unsafe extern "C" fn char_string_destroy(cs: *mut godot_char_string) {
+    take::<_, Vec<u8>>(cs);
+}
+
+unsafe extern "C" fn string_name_new(dest: *mut godot_string_name, name: *const godot_string) {
+    put(dest, string(name).clone());
+}
+
+unsafe extern "C" fn string_name_new_data(dest: *mut godot_string_name, name: *const c_char) {
+    put(dest, c_str(name).to_owned());
+}
+
+unsafe extern "C" fn string_name_get_name(name: *const godot_string_name) -> godot_string {
+    new_string(get::<_, String>(name).clone())
+}
+
+unsafe extern "C" fn string_name_get_hash(name: *const godot_string_name) -> u32 {
+    hash(get::<_, String>(name))
+}
+
+unsafe extern "C" fn string_name_operator_equal(
+    name: *const godot_string_name,
+    other: *const godot_string_name,
+) -> godot_bool {
+    get::<_, String>(name) == get::<_, String>(other)
+}
+
+unsafe extern "C" fn string_name_operator_less(
+    name: *const godot_string_name,
+    other: *const godot_string_name,
+) -> godot_bool {
+    get::<_, String>(name) < get::<_, String>(other)
+}
+
+unsafe extern "C" fn string_name_destroy(name: *mut godot_string_name) {
+    take::<_, String>(name);
+}
@@ -0,0 +1,642 @@
+use std::fmt;
+use std::slice;
+use std::sync::Arc;
+
+use libc::c_double;
+use sys::*;
+
+use super::array::{array, new_array, shared, Array};
+use super::dictionary::{dictionary, new_dictionary, Dictionary};
+use super::node_path::{new_node_path, node_path};
+use super::pool_array::{pool, pool_eq, pool_from_array, Element, Str};
+use super::string::{self, new_string};
+use super::{put, take};
+use crate::GodotApi;
+
+pub(super) fn bind(api: &mut GodotApi) {
+    api.godot_variant_new_copy = variant_new_copy;
+    api.godot_variant_new_nil = variant_new_nil;
+    api.godot_variant_new_bool = variant_new_bool;
+    api.godot_variant_new_uint = variant_new_uint;
+    api.godot_variant_new_int = variant_new_int;
+    api.godot_variant_new_real = variant_new_real;
+    api.godot_variant_new_string = variant_new_string;
+    api.godot_variant_new_vector2 = variant_new_vector2;
+    api.godot_variant_new_rect2 = variant_new_rect2;
+    api.godot_variant_new_vector3 = variant_new_vector3;
+    api.godot_variant_new_transform2d = variant_new_transform2d;
+    api.godot_variant_new_plane = variant_new_plane;
+    api.godot_variant_new_quat = variant_new_quat;
+    api.godot_variant_new_aabb = variant_new_aabb;
+    api.godot_variant_new_basis = variant_new_basis;
+    api.godot_variant_new_transform = variant_new_transform;
+    api.godot_variant_new_color = variant_new_color;
+    api.godot_variant_new_node_path = variant_new_node_path;
+    api.godot_variant_new_rid = variant_new_rid;
+    api.godot_variant_new_object = variant_new_object;
+    api.godot_variant_new_dictionary = variant_new_dictionary;
+    api.godot_variant_new_array = variant_new_array;
+    api.godot_variant_new_pool_byte_array = variant_new_pool::<_, u8>;
+    api.godot_variant_new_pool_int_array = variant_new_pool::<_, godot_int>;
+    api.godot_variant_new_pool_real_array = variant_new_pool::<_, godot_real>;
+    api.godot_variant_new_pool_string_array = variant_new_pool::<_, Str>;
+    api.godot_variant_new_pool_vector2_array = variant_new_pool::<_, godot_vector2>;
+    api.godot_variant_new_pool_vector3_array = variant_new_pool::<_, godot_vector3>;
+    api.godot_variant_new_pool_color_array = variant_new_pool::<_, godot_color>;
+
+    api.godot_variant_as_bool = variant_as_bool;
+    api.godot_variant_as_uint = variant_as_uint;
+    api.godot_variant_as_int = variant_as_int;
+    api.godot_variant_as_real = variant_as_real;
+    api.godot_variant_as_string = variant_as_string;
+    api.godot_variant_as_vector2 = variant_as_vector2;
+    api.godot_variant_as_rect2 = variant_as_rect2;
+    api.godot_variant_as_vector3 = variant_as_vector3;
+    api.godot_variant_as_transform2d = variant_as_transform2d;
+    api.godot_variant_as_plane = variant_as_plane;
+    api.godot_variant_as_quat = variant_as_quat;
+    api.godot_variant_as_aabb = variant_as_aabb;
+    api.godot_variant_as_basis = variant_as_basis;
+    api.godot_variant_as_transform = variant_as_transform;
+    api.godot_variant_as_color = variant_as_color;
+    api.godot_variant_as_node_path = variant_as_node_path;
+    api.godot_variant_as_rid = variant_as_rid;
+    api.godot_variant_as_object = variant_as_object;
+    api.godot_variant_as_dictionary = variant_as_dictionary;
+    api.godot_variant_as_array = variant_as_array;
+    api.godot_variant_as_pool_byte_array = variant_as_pool::<_, u8>;
+    api.godot_variant_as_pool_int_array = variant_as_pool::<_, godot_int>;
+    api.godot_variant_as_pool_real_array = variant_as_pool::<_, godot_real>;
+    api.godot_variant_as_pool_string_array = variant_as_pool::<_, Str>;
+    api.godot_variant_as_pool_vector2_array = variant_as_pool::<_, godot_vector2>;
+    api.godot_variant_as_pool_vector3_array = variant_as_pool::<_, godot_vector3>;
+    api.godot_variant_as_pool_color_array = variant_as_pool::<_, godot_color>;
+
+    api.godot_variant_get_type = variant_get_type;
+    api.godot_variant_operator_equal = variant_operator_equal;
+    api.godot_variant_has_method = variant_has_method;
+    api.godot_variant_call = variant_call;
+    api.godot_variant_destroy = variant_destroy;
+}
+
+/// Contents of a variant.
+///
+/// Math types are stored as their sys representation, and reference types as the Rust values
+/// stored in their handles by the mock.
+#[derive(Clone)]
+pub(super) enum Value {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    Real(f64),
+    String(String),
+    Vector2(godot_vector2),
+    Rect2(godot_rect2),
+    Vector3(godot_vector3),
+    Transform2D(godot_transform2d),
+    Plane(godot_plane),
+    Quat(godot_quat),
+    Aabb(godot_aabb),
+    Basis(godot_basis),
+    Transform(godot_transform),
+    Color(godot_color),
+    NodePath(String),
+    Rid(godot_rid),
+    Object(*mut godot_object),
+    Dictionary(Dictionary),
+    Array(Array),
+    PoolByteArray(Vec<u8>),
+    PoolIntArray(Vec<godot_int>),
+    PoolRealArray(Vec<godot_real>),
+    PoolStringArray(Vec<Str>),
+    PoolVector2Array(Vec<godot_vector2>),
+    PoolVector3Array(Vec<godot_vector3>),
+    PoolColorArray(Vec<godot_color>),
+}
+
+const NIL: &Value = &Value::Nil;
+
+/// Returns the contents of `v`. Uninitialized variants are nil.
+pub(super) unsafe fn value<'a>(v: *const godot_variant) -> &'a Value {
+    let ptr = std::ptr::read_unaligned(v as *const *const Value);
+    if ptr.is_null() {
+        NIL
+    } else {
+        &*ptr
+    }
+}
+
+/// Returns a new `godot_variant` holding `value`.
+pub(super) unsafe fn new_variant(value: Value) -> godot_variant {
+    super::new(value)
+}
+
+/// Owned `godot_variant`, destroyed on drop. Used as the element type of the containers, which
+/// hand out pointers to their elements.
+#[repr(transparent)]
+pub(super) struct Var(pub(super) godot_variant);
+
+impl Var {
+    pub(super) fn new(value: Value) -> Self {
+        unsafe { Var(new_variant(value)) }
+    }
+
+    pub(super) fn value(&self) -> &Value {
+        unsafe { value(&self.0) }
+    }
+}
+
+impl Clone for Var {
+    fn clone(&self) -> Self {
+        Var::new(self.value().clone())
+    }
+}
+
+impl Drop for Var {
+    fn drop(&mut self) {
+        unsafe {
+            take::<_, Value>(&mut self.0);
+        }
+    }
+}
+
+impl PartialEq for Var {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+/// Compares the bytes of two values of a math type.
+pub(super) fn same<T>(a: &T, b: &T) -> bool {
+    bytes(a) == bytes(b)
+}
+
+fn bytes<T>(v: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts(v as *const T as *const u8, std::mem::size_of::<T>()) }
+}
+
+/// Reads the components of a math type, which are all `godot_real`.
+pub(super) fn reals<T>(v: &T) -> Vec<godot_real> {
+    bytes(v)
+        .chunks(std::mem::size_of::<godot_real>())
+        .map(|chunk| {
+            let mut real = [0; 4];
+            real.copy_from_slice(chunk);
+            godot_real::from_ne_bytes(real)
+        })
+        .collect()
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        use self::Value::*;
+
+        match (self, other) {
+            (Nil, Nil) => true,
+            (Bool(a), Bool(b)) => a == b,
+            (Int(a), Int(b)) => a == b,
+            (Real(a), Real(b)) => a == b,
+            (Int(a), Real(b)) | (Real(b), Int(a)) => *a as f64 == *b,
+            (String(a), String(b)) => a == b,
+            (Vector2(a), Vector2(b)) => same(a, b),
+            (Rect2(a), Rect2(b)) => same(a, b),
+            (Vector3(a), Vector3(b)) => same(a, b),
+            (Transform2D(a), Transform2D(b)) => same(a, b),
+            (Plane(a), Plane(b)) => same(a, b),
+            (Quat(a), Quat(b)) => same(a, b),
+            (Aabb(a), Aabb(b)) => same(a, b),
+            (Basis(a), Basis(b)) => same(a, b),
+            (Transform(a), Transform(b)) => same(a, b),
+            (Color(a), Color(b)) => same(a, b),
+            (NodePath(a), NodePath(b)) => a == b,
+            (Rid(a), Rid(b)) => same(a, b),
+            (Object(a), Object(b)) => a == b,
+            // Dictionaries are compared by reference, like in the engine.
+            (Dictionary(a), Dictionary(b)) => Arc::ptr_eq(a, b),
+            (Array(a), Array(b)) => Arc::ptr_eq(a, b) || *a.lock() == *b.lock(),
+            (PoolByteArray(a), PoolByteArray(b)) => pool_eq(a, b),
+            (PoolIntArray(a), PoolIntArray(b)) => pool_eq(a, b),
+            (PoolRealArray(a), PoolRealArray(b)) => pool_eq(a, b),
+            (PoolStringArray(a), PoolStringArray(b)) => pool_eq(a, b),
+            (PoolVector2Array(a), PoolVector2Array(b)) => pool_eq(a, b),
+            (PoolVector3Array(a), PoolVector3Array(b)) => pool_eq(a, b),
+            (PoolColorArray(a), PoolColorArray(b)) => pool_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Value {
+    pub(super) fn get_type(&self) -> godot_variant_type {
+        use self::Value::*;
+
+        match self {
+            Nil => godot_variant_type_GODOT_VARIANT_TYPE_NIL,
+            Bool(_) => godot_variant_type_GODOT_VARIANT_TYPE_BOOL,
+            Int(_) => godot_variant_type_GODOT_VARIANT_TYPE_INT,
+            Real(_) => godot_variant_type_GODOT_VARIANT_TYPE_REAL,
+            String(_) => godot_variant_type_GODOT_VARIANT_TYPE_STRING,
+            Vector2(_) => godot_variant_type_GODOT_VARIANT_TYPE_VECTOR2,
+            Rect2(_) => godot_variant_type_GODOT_VARIANT_TYPE_RECT2,
+            Vector3(_) => godot_variant_type_GODOT_VARIANT_TYPE_VECTOR3,
+            Transform2D(_) => godot_variant_type_GODOT_VARIANT_TYPE_TRANSFORM2D,
+            Plane(_) => godot_variant_type_GODOT_VARIANT_TYPE_PLANE,
+            Quat(_) => godot_variant_type_GODOT_VARIANT_TYPE_QUAT,
+            Aabb(_) => godot_variant_type_GODOT_VARIANT_TYPE_AABB,
+            Basis(_) => godot_variant_type_GODOT_VARIANT_TYPE_BASIS,
+            Transform(_) => godot_variant_type_GODOT_VARIANT_TYPE_TRANSFORM,
+            Color(_) => godot_variant_type_GODOT_VARIANT_TYPE_COLOR,
+            NodePath(_) => godot_variant_type_GODOT_VARIANT_TYPE_NODE_PATH,
+            Rid(_) => godot_variant_type_GODOT_VARIANT_TYPE_RID,
+            Object(_) => godot_variant_type_GODOT_VARIANT_TYPE_OBJECT,
+            Dictionary(_) => godot_variant_type_GODOT_VARIANT_TYPE_DICTIONARY,
+            Array(_) => godot_variant_type_GODOT_VARIANT_TYPE_ARRAY,
+            PoolByteArray(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_BYTE_ARRAY,
+            PoolIntArray(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_INT_ARRAY,
+            PoolRealArray(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_REAL_ARRAY,
+            PoolStringArray(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_STRING_ARRAY,
+            PoolVector2Array(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_VECTOR2_ARRAY,
+            PoolVector3Array(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_VECTOR3_ARRAY,
+            PoolColorArray(_) => godot_variant_type_GODOT_VARIANT_TYPE_POOL_COLOR_ARRAY,
+        }
+    }
+
+    pub(super) fn to_bool(&self) -> bool {
+        use self::Value::*;
+
+        match self {
+            Nil => false,
+            Bool(b) => *b,
+            Int(i) => *i != 0,
+            Real(r) => *r != 0.0,
+            String(s) | NodePath(s) => !s.is_empty(),
+            Vector2(v) => !is_zero(v),
+            Rect2(v) => !is_zero(v),
+            Vector3(v) => !is_zero(v),
+            Transform2D(v) => !is_zero(v),
+            Plane(v) => !is_zero(v),
+            Quat(v) => !is_zero(v),
+            Aabb(v) => !is_zero(v),
+            Basis(v) => !is_zero(v),
+            Transform(v) => !is_zero(v),
+            Color(v) => !is_zero(v),
+            Rid(v) => !is_zero(v),
+            Object(o) => !o.is_null(),
+            Dictionary(d) => !d.lock().is_empty(),
+            Array(a) => !a.lock().is_empty(),
+            PoolByteArray(a) => !a.is_empty(),
+            PoolIntArray(a) => !a.is_empty(),
+            PoolRealArray(a) => !a.is_empty(),
+            PoolStringArray(a) => !a.is_empty(),
+            PoolVector2Array(a) => !a.is_empty(),
+            PoolVector3Array(a) => !a.is_empty(),
+            PoolColorArray(a) => !a.is_empty(),
+        }
+    }
+
+    pub(super) fn to_int(&self) -> i64 {
+        match self {
+            Value::Bool(b) => *b as i64,
+            Value::Int(i) => *i,
+            Value::Real(r) => *r as i64,
+            Value::String(s) => string::to_int(s),
+            _ => 0,
+        }
+    }
+
+    pub(super) fn to_real(&self) -> f64 {
+        match self {
+            Value::Bool(b) => *b as i64 as f64,
+            Value::Int(i) => *i as f64,
+            Value::Real(r) => *r,
+            Value::String(s) => string::to_double(s),
+            _ => 0.0,
+        }
+    }
+
+    /// Formats the value like the engine does when converting variants to strings.
+    fn format(&self) -> String {
+        use self::Value::*;
+
+        match self {
+            Nil => "Null".to_owned(),
+            Bool(true) => "True".to_owned(),
+            Bool(false) => "False".to_owned(),
+            Int(i) => i.to_string(),
+            Real(r) => r.to_string(),
+            String(s) | NodePath(s) => s.clone(),
+            Vector2(v) => tuple(&reals(v)),
+            Rect2(v) => {
+                let r = reals(v);
+                format!("{}, {}", tuple(&r[..2]), tuple(&r[2..]))
+            }
+            Vector3(v) => tuple(&reals(v)),
+            Transform2D(v) => {
+                let r = reals(v);
+                let columns = r.chunks(2).map(tuple).collect::<Vec<_>>();
+                columns.join(", ")
+            }
+            Plane(v) => {
+                let r = reals(v);
+                format!("{}, {}", tuple(&r[..3]), r[3])
+            }
+            Quat(v) => list(&reals(v)),
+            Color(v) => list(&reals(v)),
+            Aabb(v) => {
+                let r = reals(v);
+                format!("{} - {}", tuple(&r[..3]), tuple(&r[3..]))
+            }
+            Basis(v) => list(&reals(v)),
+            Transform(v) => {
+                let r = reals(v);
+                format!("{} - {}", list(&r[..9]), tuple(&r[9..]))
+            }
+            Rid(_) => "[RID]".to_owned(),
+            Object(o) if o.is_null() => "Null".to_owned(),
+            Object(o) => format!("[Object:{:p}]", o),
+            Dictionary(d) => {
+                let entries = d
+                    .lock()
+                    .iter()
+                    .map(|(key, value)| format!("{}:{}", key.value(), value.value()))
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", entries.join(", "))
+            }
+            Array(a) => {
+                let elements = a.lock().iter().map(|v| v.value().to_string()).collect();
+                brackets(elements)
+            }
+            PoolByteArray(a) => pool_to_string(a),
+            PoolIntArray(a) => pool_to_string(a),
+            PoolRealArray(a) => pool_to_string(a),
+            PoolStringArray(a) => pool_to_string(a),
+            PoolVector2Array(a) => pool_to_string(a),
+            PoolVector3Array(a) => pool_to_string(a),
+            PoolColorArray(a) => pool_to_string(a),
+        }
+    }
+
+    /// Returns the elements of arrays and pool arrays as variants.
+    pub(super) fn to_elements(&self) -> Vec<Var> {
+        fn convert<T: Element>(pool: &[T]) -> Vec<Var> {
+            pool.iter()
+                .map(|element| Var::new(element.to_value()))
+                .collect()
+        }
+
+        match self {
+            Value::Array(a) => a.lock().clone(),
+            Value::PoolByteArray(a) => convert(a),
+            Value::PoolIntArray(a) => convert(a),
+            Value::PoolRealArray(a) => convert(a),
+            Value::PoolStringArray(a) => convert(a),
+            Value::PoolVector2Array(a) => convert(a),
+            Value::PoolVector3Array(a) => convert(a),
+            Value::PoolColorArray(a) => convert(a),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.format())
+    }
+}
+
+fn is_zero<T>(v: &T) -> bool {
+    bytes(v).iter().all(|b| *b == 0)
+}
+
+fn list(reals: &[godot_real]) -> String {
+    reals
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn tuple(reals: &[godot_real]) -> String {
+    format!("({})", list(reals))
+}
+
+fn brackets(elements: Vec<String>) -> String {
+    format!("[{}]", elements.join(", "))
+}
+
+fn pool_to_string<T: Element>(pool: &[T]) -> String {
+    brackets(pool.iter().map(|e| e.to_value().to_string()).collect())
+}
+
+unsafe extern "C" fn variant_new_copy(dest: *mut godot_variant, src: *const godot_variant) {
+    put(dest, value(src).clone());
+}
+
+unsafe extern "C" fn variant_new_nil(dest: *mut godot_variant) {
+    put(dest, Value::Nil);
+}
+
+unsafe extern "C" fn variant_new_bool(dest: *mut godot_variant, b: godot_bool) {
+    put(dest, Value::Bool(b));
+}
+
+unsafe extern "C" fn variant_new_uint(dest: *mut godot_variant, i: u64) {
+    put(dest, Value::Int(i as i64));
+}
+
+unsafe extern "C" fn variant_new_int(dest: *mut godot_variant, i: i64) {
+    put(dest, Value::Int(i));
+}
+
+unsafe extern "C" fn variant_new_real(dest: *mut godot_variant, r: c_double) {
+    put(dest, Value::Real(r));
+}
+
+unsafe extern "C" fn variant_new_string(dest: *mut godot_variant, s: *const godot_string) {
+    put(dest, Value::String(string::string(s).clone()));
+}
+
+unsafe extern "C" fn variant_new_vector2(dest: *mut godot_variant, v: *const godot_vector2) {
+    put(dest, Value::Vector2(*v));
+}
+
+unsafe extern "C" fn variant_new_rect2(dest: *mut godot_variant, v: *const godot_rect2) {
+    put(dest, Value::Rect2(*v));
+}
+
+unsafe extern "C" fn variant_new_vector3(dest: *mut godot_variant, v: *const godot_vector3) {
+    put(dest, Value::Vector3(*v));
+}
+
+unsafe extern "C" fn variant_new_transform2d(
+    dest: *mut godot_variant,
+    v: *const godot_transform2d,
+) {
+    put(dest, Value::Transform2D(*v));
+}
+
+unsafe extern "C" fn variant_new_plane(dest: *mut godot_variant, v: *const godot_plane) {
+    put(dest, Value::Plane(*v));
+}
+
+unsafe extern "C" fn variant_new_quat(dest: *mut godot_variant, v: *const godot_quat) {
+    put(dest, Value::Quat(*v));
+}
+
+unsafe extern "C" fn variant_new_aabb(dest: *mut godot_variant, v: *const godot_aabb) {
+    put(dest, Value::Aabb(*v));
+}
+
+unsafe extern "C" fn variant_new_basis(dest: *mut godot_variant, v: *const godot_basis) {
+    put(dest, Value::Basis(*v));
+}
+
+unsafe extern "C" fn variant_new_transform(dest: *mut godot_variant, v: *const godot_transform) {
+    put(dest, Value::Transform(*v));
+}
+
+unsafe extern "C" fn variant_new_color(dest: *mut godot_variant, v: *const godot_color) {
+    put(dest, Value::Color(*v));
+}
+
+unsafe extern "C" fn variant_new_node_path(dest: *mut godot_variant, path: *const godot_node_path) {
+    put(dest, Value::NodePath(node_path(path).clone()));
+}
+
+unsafe extern "C" fn variant_new_rid(dest: *mut godot_variant, rid: *const godot_rid) {
+    put(dest, Value::Rid(*rid));
+}
+
+unsafe extern "C" fn variant_new_object(dest: *mut godot_variant, obj: *const godot_object) {
+    put(dest, Value::Object(obj as *mut godot_object));
+}
+
+unsafe extern "C" fn variant_new_dictionary(
+    dest: *mut godot_variant,
+    dict: *const godot_dictionary,
+) {
+    put(dest, Value::Dictionary(dictionary(dict).clone()));
+}
+
+unsafe extern "C" fn variant_new_array(dest: *mut godot_variant, arr: *const godot_array) {
+    put(dest, Value::Array(array(arr).clone()));
+}
+
+unsafe extern "C" fn variant_new_pool<A, T: Element>(dest: *mut godot_variant, arr: *const A) {
+    put(dest, T::wrap(pool::<A, T>(arr).clone()));
+}
+
+unsafe extern "C" fn variant_as_bool(v: *const godot_variant) -> godot_bool {
+    value(v).to_bool()
+}
+
+unsafe extern "C" fn variant_as_uint(v: *const godot_variant) -> u64 {
+    value(v).to_int() as u64
+}
+
+unsafe extern "C" fn variant_as_int(v: *const godot_variant) -> i64 {
+    value(v).to_int()
+}
+
+unsafe extern "C" fn variant_as_real(v: *const godot_variant) -> c_double {
+    value(v).to_real()
+}
+
+unsafe extern "C" fn variant_as_string(v: *const godot_variant) -> godot_string {
+    new_string(value(v).to_string())
+}
+
+macro_rules! as_math_type {
+    ($name:ident, $variant:ident, $sys_type:ty) => {
+        unsafe extern "C" fn $name(v: *const godot_variant) -> $sys_type {
+            match value(v) {
+                Value::$variant(v) => *v,
+                _ => <$sys_type>::default(),
+            }
+        }
+    };
+}
+
+as_math_type!(variant_as_vector2, Vector2, godot_vector2);
+as_math_type!(variant_as_rect2, Rect2, godot_rect2);
+as_math_type!(variant_as_vector3, Vector3, godot_vector3);
+as_math_type!(variant_as_transform2d, Transform2D, godot_transform2d);
+as_math_type!(variant_as_plane, Plane, godot_plane);
+as_math_type!(variant_as_quat, Quat, godot_quat);
+as_math_type!(variant_as_aabb, Aabb, godot_aabb);
+as_math_type!(variant_as_basis, Basis, godot_basis);
+as_math_type!(variant_as_transform, Transform, godot_transform);
+as_math_type!(variant_as_color, Color, godot_color);
+as_math_type!(variant_as_rid, Rid, godot_rid);
+
+unsafe extern "C" fn variant_as_node_path(v: *const godot_variant) -> godot_node_path {
+    match value(v) {
+        Value::NodePath(path) | Value::String(path) => new_node_path(path.clone()),
+        _ => new_node_path(String::new()),
+    }
+}
+
+unsafe extern "C" fn variant_as_object(v: *const godot_variant) -> *mut godot_object {
+    match value(v) {
+        Value::Object(o) => *o,
+        _ => std::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn variant_as_dictionary(v: *const godot_variant) -> godot_dictionary {
+    match value(v) {
+        Value::Dictionary(d) => new_dictionary(d.clone()),
+        _ => new_dictionary(Dictionary::default()),
+    }
+}
+
+unsafe extern "C" fn variant_as_array(v: *const godot_variant) -> godot_array {
+    match value(v) {
+        Value::Array(a) => new_array(a.clone()),
+        other => new_array(shared(other.to_elements())),
+    }
+}
+
+unsafe extern "C" fn variant_as_pool<A: Default, T: Element>(v: *const godot_variant) -> A {
+    let value = value(v);
+    let pool = T::unwrap(value)
+        .cloned()
+        .unwrap_or_else(|| pool_from_array(&value.to_elements()));
+    super::new(pool)
+}
+
+unsafe extern "C" fn variant_get_type(v: *const godot_variant) -> godot_variant_type {
+    value(v).get_type()
+}
+
+unsafe extern "C" fn variant_operator_equal(
+    v: *const godot_variant,
+    other: *const godot_variant,
+) -> godot_bool {
+    value(v) == value(other)
+}
+
+/// There are no objects with methods in the mock.
+unsafe extern "C" fn variant_has_method(
+    _v: *const godot_variant,
+    _method: *const godot_string,
+) -> godot_bool {
+    false
+}
+
+unsafe extern "C" fn variant_call(
+    _v: *mut godot_variant,
+    _method: *const godot_string,
+    _args: *mut *const godot_variant,
+    _argcount: godot_int,
+    r_error: *mut godot_variant_call_error,
+) -> godot_variant {
+    if let Some(r_error) = r_error.as_mut() {
+        r_error.error = godot_variant_call_error_error_GODOT_CALL_ERROR_CALL_ERROR_INVALID_METHOD;
+    }
+    new_variant(Value::Nil)
+}
+
+unsafe extern "C" fn variant_destroy(v: *mut godot_variant) {
+    take::<_, Value>(v);
+}
@@ -64,6 +64,11 @@ impl NodePath {
         unsafe { (get_api().godot_node_path_get_name_count)(&mut self.0) }
     }
 
+    /// Returns the node name of the specified `idx`, 0 to name_count()
+    pub fn get_name(&self, idx: i32) -> GodotString {
+        unsafe { GodotString((get_api().godot_node_path_get_name)(&self.0, idx)) }
+    }
+
     /// Returns the resource name of the specified `idx`, 0 to subname_count()
     pub fn get_subname(&self, idx: i32) -> GodotString {
         unsafe { GodotString((get_api().godot_node_path_get_subname)(&self.0, idx)) }
@@ -0,0 +1,1134 @@
+use crate::access::{Aligned, Guard, MaybeUnaligned, WritePtr};
+use crate::get_api;
+use crate::sys;
+use crate::Color;
+use crate::GodotString;
+use crate::VariantArray;
+use crate::Vector2;
+use crate::Vector3;
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::mem::transmute;
+use std::ops::Index;
+
+/// A reference-counted vector of `T` that uses Godot's pool allocator.
+///
+/// Godot provides pool arrays for a fixed set of element types, which implement
+/// `PoolElement`. The aliases `ByteArray`, `Int32Array`, `Float32Array`, `StringArray`,
+/// `Vector2Array`, `Vector3Array` and `ColorArray` are provided for each of them.
+pub struct PoolArray<T: PoolElement> {
+    inner: T::SysArray,
+    _marker: PhantomData<T>,
+}
+
+/// A reference-counted vector of bytes that uses Godot's pool allocator.
+pub type ByteArray = PoolArray<u8>;
+
+/// A reference-counted vector of `i32` that uses Godot's pool allocator.
+pub type Int32Array = PoolArray<i32>;
+
+/// A reference-counted vector of `f32` that uses Godot's pool allocator.
+pub type Float32Array = PoolArray<f32>;
+
+/// A reference-counted vector of `GodotString` that uses Godot's pool allocator.
+pub type StringArray = PoolArray<GodotString>;
+
+/// A reference-counted vector of `Vector2` that uses Godot's pool allocator.
+pub type Vector2Array = PoolArray<Vector2>;
+
+/// A reference-counted vector of `Vector3` that uses Godot's pool allocator.
+pub type Vector3Array = PoolArray<Vector3>;
+
+/// A reference-counted vector of `Color` that uses Godot's pool allocator.
+pub type ColorArray = PoolArray<Color>;
+
+pub type Read<'a, T> = Aligned<ReadGuard<'a, T>>;
+pub type Write<'a, T> = Aligned<WriteGuard<'a, T>>;
+
+impl<T: PoolElement> PoolArray<T> {
+    /// Creates an empty array.
+    pub fn new() -> Self {
+        PoolArray::default()
+    }
+
+    /// Creates an array by trying to convert each variant.
+    ///
+    /// When no viable conversion exists, the default value of `T` is pushed.
+    pub fn from_variant_array(array: &VariantArray) -> Self {
+        unsafe {
+            let mut inner = T::SysArray::default();
+            (T::new_with_array_fn(get_api()))(&mut inner, &array.0);
+            Self::from_sys(inner)
+        }
+    }
+
+    /// Creates an array with the elements of a slice.
+    pub fn from_slice(src: &[T]) -> Self {
+        let mut array = PoolArray::new();
+        array.extend_from_slice(src);
+        array
+    }
+
+    /// Creates an array with the elements of a `Vec`.
+    pub fn from_vec(src: Vec<T>) -> Self {
+        let mut array = PoolArray::new();
+        array.resize(src.len() as i32);
+        {
+            let mut write = array.write();
+            for (dest, value) in write.as_mut_slice().iter_mut().zip(src) {
+                *dest = value;
+            }
+        }
+        array
+    }
+
+    /// Copies the elements of the array into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.read().to_vec()
+    }
+
+    /// Appends an element at the end of the array.
+    pub fn push(&mut self, val: impl Borrow<T>) {
+        unsafe {
+            (T::append_fn(get_api()))(&mut self.inner, val.borrow().element_to_sys_ref());
+        }
+    }
+
+    /// Appends another array at the end of this array.
+    pub fn push_array(&mut self, array: &PoolArray<T>) {
+        unsafe {
+            (T::append_array_fn(get_api()))(&mut self.inner, array.sys());
+        }
+    }
+
+    /// Appends the elements of a slice at the end of this array.
+    pub fn extend_from_slice(&mut self, src: &[T]) {
+        let len = self.len() as usize;
+        self.resize((len + src.len()) as i32);
+        self.write().as_mut_slice()[len..].clone_from_slice(src);
+    }
+
+    // TODO(error handling)
+    /// Inserts an element at the given offset.
+    pub fn insert(&mut self, offset: i32, val: impl Borrow<T>) -> bool {
+        unsafe {
+            let status = (T::insert_fn(get_api()))(
+                &mut self.inner,
+                offset,
+                val.borrow().element_to_sys_ref(),
+            );
+            status != sys::godot_error_GODOT_OK
+        }
+    }
+
+    /// Inverts the order of the elements in the array.
+    pub fn invert(&mut self) {
+        unsafe { (T::invert_fn(get_api()))(&mut self.inner) }
+    }
+
+    /// Removes an element at the given offset.
+    pub fn remove(&mut self, idx: i32) {
+        unsafe {
+            (T::remove_fn(get_api()))(&mut self.inner, idx);
+        }
+    }
+
+    /// Changes the size of the array, possibly removing elements or pushing default values.
+    pub fn resize(&mut self, size: i32) {
+        unsafe {
+            (T::resize_fn(get_api()))(&mut self.inner, size);
+        }
+    }
+
+    /// Returns a copy of the element at the given offset.
+    pub fn get(&self, idx: i32) -> T {
+        unsafe { T::element_from_sys((T::get_fn(get_api()))(&self.inner, idx)) }
+    }
+
+    /// Sets the value of the element at the given offset.
+    pub fn set(&mut self, idx: i32, val: impl Borrow<T>) {
+        unsafe {
+            (T::set_fn(get_api()))(&mut self.inner, idx, val.borrow().element_to_sys_ref());
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> i32 {
+        unsafe { (T::size_fn(get_api()))(&self.inner) }
+    }
+
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn read<'a>(&'a self) -> Read<'a, T> {
+        unsafe {
+            MaybeUnaligned::new(ReadGuard::new(self.sys()))
+                .try_into_aligned()
+                .expect("Pool array access should be aligned. This indicates a bug in Godot")
+        }
+    }
+
+    pub fn write<'a>(&'a mut self) -> Write<'a, T> {
+        unsafe {
+            MaybeUnaligned::new(WriteGuard::new(self.sys() as *mut _))
+                .try_into_aligned()
+                .expect("Pool array access should be aligned. This indicates a bug in Godot")
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn sys(&self) -> *const T::SysArray {
+        &self.inner
+    }
+
+    #[doc(hidden)]
+    pub fn from_sys(sys: T::SysArray) -> Self {
+        PoolArray {
+            inner: sys,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new reference to this array.
+    pub fn new_ref(&self) -> Self {
+        unsafe {
+            let mut inner = T::SysArray::default();
+            (T::new_copy_fn(get_api()))(&mut inner, self.sys());
+            Self::from_sys(inner)
+        }
+    }
+}
+
+impl PoolArray<GodotString> {
+    /// Appends a `StringArray` at the end of this array.
+    pub fn push_string_array(&mut self, strings: &StringArray) {
+        self.push_array(strings)
+    }
+}
+
+impl<T: PoolElement> Drop for PoolArray<T> {
+    fn drop(&mut self) {
+        unsafe { (T::destroy_fn(get_api()))(&mut self.inner) }
+    }
+}
+
+impl<T: PoolElement> Default for PoolArray<T> {
+    fn default() -> Self {
+        unsafe {
+            let mut inner = T::SysArray::default();
+            (T::new_fn(get_api()))(&mut inner);
+            Self::from_sys(inner)
+        }
+    }
+}
+
+impl<T: PoolElement + fmt::Debug> fmt::Debug for PoolArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_list().entries(self.read().iter()).finish()
+    }
+}
+
+/// Indexes into the pool array without holding its lock while the reference is alive.
+///
+/// This relies on the memory of unlocked pool arrays never moving, which is true of Godot 3.x
+/// but not promised by the GDNative API. To keep the array locked while accessing elements,
+/// index into `read()` instead, e.g. `array.read()[i]`.
+impl<T: PoolElement> Index<usize> for PoolArray<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        let read = self.read();
+        let len = read.len();
+        assert!(
+            idx < len,
+            "index out of bounds: the len is {} but the index is {}",
+            len,
+            idx
+        );
+
+        // SAFETY: The data is owned by this reference to the pool array. Writes through other
+        // references copy the data first, and writes through this one need `&mut self`, so
+        // the element isn't modified or freed for the lifetime of the borrow.
+        //
+        // The read lock is released before returning, so this also depends on the allocation
+        // staying at the same address while unlocked. The lock exists to allow a compacting
+        // `MemoryPool`, but Godot 3.x never moves unlocked `PoolVector` memory: its pool
+        // allocations are plain `memalloc` blocks that are only reallocated by `resize`,
+        // which requires a unique reference (see `core/pool_vector.h`).
+        unsafe { &*read.as_ptr().add(idx) }
+    }
+}
+
+impl<T: PoolElement> IntoIterator for PoolArray<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_vec().into_iter()
+    }
+}
+
+impl<T: PoolElement> FromIterator<T> for PoolArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        PoolArray::from_vec(iter.into_iter().collect())
+    }
+}
+
+/// Read access guard for a `PoolArray`.
+pub struct ReadGuard<'a, T: PoolElement> {
+    access: *mut T::SysReadAccess,
+    len: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: PoolElement> ReadGuard<'a, T> {
+    unsafe fn new(arr: *const T::SysArray) -> Self {
+        let api = get_api();
+        let len = (T::size_fn(api))(arr) as usize;
+        let access = (T::read_fn(api))(arr);
+        ReadGuard {
+            access,
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a, T: PoolElement> Guard for ReadGuard<'a, T> {
+    type Target = T;
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn read_ptr(&self) -> *const T {
+        unsafe { (T::read_access_ptr_fn(get_api()))(self.access) as *const T }
+    }
+}
+
+impl<'a, T: PoolElement> Drop for ReadGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe { (T::read_access_destroy_fn(get_api()))(self.access) }
+    }
+}
+
+impl<'a, T: PoolElement> Clone for ReadGuard<'a, T> {
+    fn clone(&self) -> Self {
+        let access = unsafe { (T::read_access_copy_fn(get_api()))(self.access) };
+        ReadGuard {
+            access,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Write access guard for a `PoolArray`.
+pub struct WriteGuard<'a, T: PoolElement> {
+    access: *mut T::SysWriteAccess,
+    len: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T: PoolElement> WriteGuard<'a, T> {
+    unsafe fn new(arr: *mut T::SysArray) -> Self {
+        let api = get_api();
+        let len = (T::size_fn(api))(arr) as usize;
+        let access = (T::write_fn(api))(arr);
+        WriteGuard {
+            access,
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a, T: PoolElement> Guard for WriteGuard<'a, T> {
+    type Target = T;
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn read_ptr(&self) -> *const T {
+        unsafe { (T::write_access_ptr_fn(get_api()))(self.access) as *const T }
+    }
+}
+
+unsafe impl<'a, T: PoolElement> WritePtr for WriteGuard<'a, T> {}
+
+impl<'a, T: PoolElement> Drop for WriteGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe { (T::write_access_destroy_fn(get_api()))(self.access) }
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Trait for element types that can be contained in a `PoolArray`. This trait is sealed
+/// and has no public interface.
+pub trait PoolElement: private::Sealed + Clone {
+    #[doc(hidden)]
+    type SysArray: Default;
+    #[doc(hidden)]
+    type SysReadAccess;
+    #[doc(hidden)]
+    type SysWriteAccess;
+    #[doc(hidden)]
+    type SysTy;
+    #[doc(hidden)]
+    type SysRefTy;
+
+    #[doc(hidden)]
+    fn element_to_sys_ref(&self) -> Self::SysRefTy;
+    #[doc(hidden)]
+    unsafe fn element_from_sys(sys: Self::SysTy) -> Self;
+
+    #[doc(hidden)]
+    fn new_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray);
+    #[doc(hidden)]
+    fn new_copy_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysArray, *const Self::SysArray);
+    #[doc(hidden)]
+    fn new_with_array_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysArray, *const sys::godot_array);
+    #[doc(hidden)]
+    fn destroy_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray);
+    #[doc(hidden)]
+    fn append_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray, Self::SysRefTy);
+    #[doc(hidden)]
+    fn append_array_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysArray, *const Self::SysArray);
+    #[doc(hidden)]
+    fn insert_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int, Self::SysRefTy) -> sys::godot_error;
+    #[doc(hidden)]
+    fn invert_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray);
+    #[doc(hidden)]
+    fn remove_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int);
+    #[doc(hidden)]
+    fn resize_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int);
+    #[doc(hidden)]
+    fn get_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*const Self::SysArray, sys::godot_int) -> Self::SysTy;
+    #[doc(hidden)]
+    fn set_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int, Self::SysRefTy);
+    #[doc(hidden)]
+    fn size_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*const Self::SysArray) -> sys::godot_int;
+    #[doc(hidden)]
+    fn read_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*const Self::SysArray) -> *mut Self::SysReadAccess;
+    #[doc(hidden)]
+    fn read_access_ptr_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*const Self::SysReadAccess) -> *const Self::SysTy;
+    #[doc(hidden)]
+    fn read_access_copy_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*const Self::SysReadAccess) -> *mut Self::SysReadAccess;
+    #[doc(hidden)]
+    fn read_access_destroy_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysReadAccess);
+    #[doc(hidden)]
+    fn write_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysArray) -> *mut Self::SysWriteAccess;
+    #[doc(hidden)]
+    fn write_access_ptr_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*const Self::SysWriteAccess) -> *mut Self::SysTy;
+    #[doc(hidden)]
+    fn write_access_destroy_fn(
+        api: &sys::GodotApi,
+    ) -> unsafe extern "C" fn(*mut Self::SysWriteAccess);
+}
+
+macro_rules! impl_pool_element {
+    (
+        $(
+            impl PoolElement for $Element:ty {
+                array = $SysArray:ident,
+                read_access = $SysReadAccess:ident,
+                write_access = $SysWriteAccess:ident,
+                element = $SysTy:ty, by $SysRefTy:ty,
+                to_sys_ref = |$elem:ident| $to_sys_ref:expr,
+                from_sys = |$sys:ident| $from_sys:expr,
+                new = $new:ident,
+                new_copy = $new_copy:ident,
+                new_with_array = $new_with_array:ident,
+                destroy = $destroy:ident,
+                append = $append:ident,
+                append_array = $append_array:ident,
+                insert = $insert:ident,
+                invert = $invert:ident,
+                remove = $remove:ident,
+                resize = $resize:ident,
+                get = $get:ident,
+                set = $set:ident,
+                size = $size:ident,
+                read = $read:ident,
+                read_access_ptr = $read_access_ptr:ident,
+                read_access_copy = $read_access_copy:ident,
+                read_access_destroy = $read_access_destroy:ident,
+                write = $write:ident,
+                write_access_ptr = $write_access_ptr:ident,
+                write_access_destroy = $write_access_destroy:ident,
+            }
+        )*
+    ) => {
+        $(
+            impl private::Sealed for $Element {}
+
+            impl PoolElement for $Element {
+                type SysArray = sys::$SysArray;
+                type SysReadAccess = sys::$SysReadAccess;
+                type SysWriteAccess = sys::$SysWriteAccess;
+                type SysTy = $SysTy;
+                type SysRefTy = $SysRefTy;
+
+                #[inline]
+                fn element_to_sys_ref(&self) -> Self::SysRefTy {
+                    let $elem = self;
+                    $to_sys_ref
+                }
+
+                #[inline]
+                unsafe fn element_from_sys($sys: Self::SysTy) -> Self {
+                    $from_sys
+                }
+
+                #[inline]
+                fn new_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray) {
+                    api.$new
+                }
+
+                #[inline]
+                fn new_copy_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, *const Self::SysArray) {
+                    api.$new_copy
+                }
+
+                #[inline]
+                fn new_with_array_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, *const sys::godot_array) {
+                    api.$new_with_array
+                }
+
+                #[inline]
+                fn destroy_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray) {
+                    api.$destroy
+                }
+
+                #[inline]
+                fn append_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, Self::SysRefTy) {
+                    api.$append
+                }
+
+                #[inline]
+                fn append_array_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, *const Self::SysArray) {
+                    api.$append_array
+                }
+
+                #[inline]
+                fn insert_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(
+                    *mut Self::SysArray,
+                    sys::godot_int,
+                    Self::SysRefTy,
+                ) -> sys::godot_error {
+                    api.$insert
+                }
+
+                #[inline]
+                fn invert_fn(api: &sys::GodotApi) -> unsafe extern "C" fn(*mut Self::SysArray) {
+                    api.$invert
+                }
+
+                #[inline]
+                fn remove_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int) {
+                    api.$remove
+                }
+
+                #[inline]
+                fn resize_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int) {
+                    api.$resize
+                }
+
+                #[inline]
+                fn get_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*const Self::SysArray, sys::godot_int) -> Self::SysTy {
+                    api.$get
+                }
+
+                #[inline]
+                fn set_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray, sys::godot_int, Self::SysRefTy) {
+                    api.$set
+                }
+
+                #[inline]
+                fn size_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*const Self::SysArray) -> sys::godot_int {
+                    api.$size
+                }
+
+                #[inline]
+                fn read_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*const Self::SysArray) -> *mut Self::SysReadAccess {
+                    api.$read
+                }
+
+                #[inline]
+                fn read_access_ptr_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*const Self::SysReadAccess) -> *const Self::SysTy {
+                    api.$read_access_ptr
+                }
+
+                #[inline]
+                fn read_access_copy_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*const Self::SysReadAccess) -> *mut Self::SysReadAccess
+                {
+                    api.$read_access_copy
+                }
+
+                #[inline]
+                fn read_access_destroy_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysReadAccess) {
+                    api.$read_access_destroy
+                }
+
+                #[inline]
+                fn write_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysArray) -> *mut Self::SysWriteAccess {
+                    api.$write
+                }
+
+                #[inline]
+                fn write_access_ptr_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*const Self::SysWriteAccess) -> *mut Self::SysTy {
+                    api.$write_access_ptr
+                }
+
+                #[inline]
+                fn write_access_destroy_fn(
+                    api: &sys::GodotApi,
+                ) -> unsafe extern "C" fn(*mut Self::SysWriteAccess) {
+                    api.$write_access_destroy
+                }
+            }
+        )*
+    };
+}
+
+impl_pool_element! {
+    impl PoolElement for u8 {
+        array = godot_pool_byte_array,
+        read_access = godot_pool_byte_array_read_access,
+        write_access = godot_pool_byte_array_write_access,
+        element = u8, by u8,
+        to_sys_ref = |byte| *byte,
+        from_sys = |sys| sys,
+        new = godot_pool_byte_array_new,
+        new_copy = godot_pool_byte_array_new_copy,
+        new_with_array = godot_pool_byte_array_new_with_array,
+        destroy = godot_pool_byte_array_destroy,
+        append = godot_pool_byte_array_append,
+        append_array = godot_pool_byte_array_append_array,
+        insert = godot_pool_byte_array_insert,
+        invert = godot_pool_byte_array_invert,
+        remove = godot_pool_byte_array_remove,
+        resize = godot_pool_byte_array_resize,
+        get = godot_pool_byte_array_get,
+        set = godot_pool_byte_array_set,
+        size = godot_pool_byte_array_size,
+        read = godot_pool_byte_array_read,
+        read_access_ptr = godot_pool_byte_array_read_access_ptr,
+        read_access_copy = godot_pool_byte_array_read_access_copy,
+        read_access_destroy = godot_pool_byte_array_read_access_destroy,
+        write = godot_pool_byte_array_write,
+        write_access_ptr = godot_pool_byte_array_write_access_ptr,
+        write_access_destroy = godot_pool_byte_array_write_access_destroy,
+    }
+
+    impl PoolElement for i32 {
+        array = godot_pool_int_array,
+        read_access = godot_pool_int_array_read_access,
+        write_access = godot_pool_int_array_write_access,
+        element = sys::godot_int, by sys::godot_int,
+        to_sys_ref = |int| *int,
+        from_sys = |sys| sys,
+        new = godot_pool_int_array_new,
+        new_copy = godot_pool_int_array_new_copy,
+        new_with_array = godot_pool_int_array_new_with_array,
+        destroy = godot_pool_int_array_destroy,
+        append = godot_pool_int_array_append,
+        append_array = godot_pool_int_array_append_array,
+        insert = godot_pool_int_array_insert,
+        invert = godot_pool_int_array_invert,
+        remove = godot_pool_int_array_remove,
+        resize = godot_pool_int_array_resize,
+        get = godot_pool_int_array_get,
+        set = godot_pool_int_array_set,
+        size = godot_pool_int_array_size,
+        read = godot_pool_int_array_read,
+        read_access_ptr = godot_pool_int_array_read_access_ptr,
+        read_access_copy = godot_pool_int_array_read_access_copy,
+        read_access_destroy = godot_pool_int_array_read_access_destroy,
+        write = godot_pool_int_array_write,
+        write_access_ptr = godot_pool_int_array_write_access_ptr,
+        write_access_destroy = godot_pool_int_array_write_access_destroy,
+    }
+
+    impl PoolElement for f32 {
+        array = godot_pool_real_array,
+        read_access = godot_pool_real_array_read_access,
+        write_access = godot_pool_real_array_write_access,
+        element = sys::godot_real, by sys::godot_real,
+        to_sys_ref = |real| *real,
+        from_sys = |sys| sys,
+        new = godot_pool_real_array_new,
+        new_copy = godot_pool_real_array_new_copy,
+        new_with_array = godot_pool_real_array_new_with_array,
+        destroy = godot_pool_real_array_destroy,
+        append = godot_pool_real_array_append,
+        append_array = godot_pool_real_array_append_array,
+        insert = godot_pool_real_array_insert,
+        invert = godot_pool_real_array_invert,
+        remove = godot_pool_real_array_remove,
+        resize = godot_pool_real_array_resize,
+        get = godot_pool_real_array_get,
+        set = godot_pool_real_array_set,
+        size = godot_pool_real_array_size,
+        read = godot_pool_real_array_read,
+        read_access_ptr = godot_pool_real_array_read_access_ptr,
+        read_access_copy = godot_pool_real_array_read_access_copy,
+        read_access_destroy = godot_pool_real_array_read_access_destroy,
+        write = godot_pool_real_array_write,
+        write_access_ptr = godot_pool_real_array_write_access_ptr,
+        write_access_destroy = godot_pool_real_array_write_access_destroy,
+    }
+
+    impl PoolElement for GodotString {
+        array = godot_pool_string_array,
+        read_access = godot_pool_string_array_read_access,
+        write_access = godot_pool_string_array_write_access,
+        element = sys::godot_string, by *const sys::godot_string,
+        to_sys_ref = |string| string.sys(),
+        from_sys = |sys| GodotString::from_sys(sys),
+        new = godot_pool_string_array_new,
+        new_copy = godot_pool_string_array_new_copy,
+        new_with_array = godot_pool_string_array_new_with_array,
+        destroy = godot_pool_string_array_destroy,
+        append = godot_pool_string_array_append,
+        append_array = godot_pool_string_array_append_array,
+        insert = godot_pool_string_array_insert,
+        invert = godot_pool_string_array_invert,
+        remove = godot_pool_string_array_remove,
+        resize = godot_pool_string_array_resize,
+        get = godot_pool_string_array_get,
+        set = godot_pool_string_array_set,
+        size = godot_pool_string_array_size,
+        read = godot_pool_string_array_read,
+        read_access_ptr = godot_pool_string_array_read_access_ptr,
+        read_access_copy = godot_pool_string_array_read_access_copy,
+        read_access_destroy = godot_pool_string_array_read_access_destroy,
+        write = godot_pool_string_array_write,
+        write_access_ptr = godot_pool_string_array_write_access_ptr,
+        write_access_destroy = godot_pool_string_array_write_access_destroy,
+    }
+
+    impl PoolElement for Vector2 {
+        array = godot_pool_vector2_array,
+        read_access = godot_pool_vector2_array_read_access,
+        write_access = godot_pool_vector2_array_write_access,
+        element = sys::godot_vector2, by *const sys::godot_vector2,
+        to_sys_ref = |vector| vector as *const Vector2 as *const sys::godot_vector2,
+        from_sys = |sys| transmute(sys),
+        new = godot_pool_vector2_array_new,
+        new_copy = godot_pool_vector2_array_new_copy,
+        new_with_array = godot_pool_vector2_array_new_with_array,
+        destroy = godot_pool_vector2_array_destroy,
+        append = godot_pool_vector2_array_append,
+        append_array = godot_pool_vector2_array_append_array,
+        insert = godot_pool_vector2_array_insert,
+        invert = godot_pool_vector2_array_invert,
+        remove = godot_pool_vector2_array_remove,
+        resize = godot_pool_vector2_array_resize,
+        get = godot_pool_vector2_array_get,
+        set = godot_pool_vector2_array_set,
+        size = godot_pool_vector2_array_size,
+        read = godot_pool_vector2_array_read,
+        read_access_ptr = godot_pool_vector2_array_read_access_ptr,
+        read_access_copy = godot_pool_vector2_array_read_access_copy,
+        read_access_destroy = godot_pool_vector2_array_read_access_destroy,
+        write = godot_pool_vector2_array_write,
+        write_access_ptr = godot_pool_vector2_array_write_access_ptr,
+        write_access_destroy = godot_pool_vector2_array_write_access_destroy,
+    }
+
+    impl PoolElement for Vector3 {
+        array = godot_pool_vector3_array,
+        read_access = godot_pool_vector3_array_read_access,
+        write_access = godot_pool_vector3_array_write_access,
+        element = sys::godot_vector3, by *const sys::godot_vector3,
+        to_sys_ref = |vector| vector as *const Vector3 as *const sys::godot_vector3,
+        from_sys = |sys| transmute(sys),
+        new = godot_pool_vector3_array_new,
+        new_copy = godot_pool_vector3_array_new_copy,
+        new_with_array = godot_pool_vector3_array_new_with_array,
+        destroy = godot_pool_vector3_array_destroy,
+        append = godot_pool_vector3_array_append,
+        append_array = godot_pool_vector3_array_append_array,
+        insert = godot_pool_vector3_array_insert,
+        invert = godot_pool_vector3_array_invert,
+        remove = godot_pool_vector3_array_remove,
+        resize = godot_pool_vector3_array_resize,
+        get = godot_pool_vector3_array_get,
+        set = godot_pool_vector3_array_set,
+        size = godot_pool_vector3_array_size,
+        read = godot_pool_vector3_array_read,
+        read_access_ptr = godot_pool_vector3_array_read_access_ptr,
+        read_access_copy = godot_pool_vector3_array_read_access_copy,
+        read_access_destroy = godot_pool_vector3_array_read_access_destroy,
+        write = godot_pool_vector3_array_write,
+        write_access_ptr = godot_pool_vector3_array_write_access_ptr,
+        write_access_destroy = godot_pool_vector3_array_write_access_destroy,
+    }
+
+    impl PoolElement for Color {
+        array = godot_pool_color_array,
+        read_access = godot_pool_color_array_read_access,
+        write_access = godot_pool_color_array_write_access,
+        element = sys::godot_color, by *const sys::godot_color,
+        to_sys_ref = |color| color as *const Color as *const sys::godot_color,
+        from_sys = |sys| transmute(sys),
+        new = godot_pool_color_array_new,
+        new_copy = godot_pool_color_array_new_copy,
+        new_with_array = godot_pool_color_array_new_with_array,
+        destroy = godot_pool_color_array_destroy,
+        append = godot_pool_color_array_append,
+        append_array = godot_pool_color_array_append_array,
+        insert = godot_pool_color_array_insert,
+        invert = godot_pool_color_array_invert,
+        remove = godot_pool_color_array_remove,
+        resize = godot_pool_color_array_resize,
+        get = godot_pool_color_array_get,
+        set = godot_pool_color_array_set,
+        size = godot_pool_color_array_size,
+        read = godot_pool_color_array_read,
+        read_access_ptr = godot_pool_color_array_read_access_ptr,
+        read_access_copy = godot_pool_color_array_read_access_copy,
+        read_access_destroy = godot_pool_color_array_read_access_destroy,
+        write = godot_pool_color_array_write,
+        write_access_ptr = godot_pool_color_array_write_access_ptr,
+        write_access_destroy = godot_pool_color_array_write_access_destroy,
+    }
+}
+
+godot_test!(
+    test_pool_array_slices {
+        let mut arr = Int32Array::from_slice(&[1, 2, 3]);
+        arr.extend_from_slice(&[4, 5]);
+        assert_eq!(vec![1, 2, 3, 4, 5], arr.to_vec());
+        assert_eq!(3, arr[2]);
+
+        let doubled = arr.new_ref().into_iter().map(|i| i * 2).collect::<Int32Array>();
+        assert_eq!(&[2, 4, 6, 8, 10], doubled.read().as_slice());
+
+        let strings = StringArray::from_vec(vec![GodotString::from("foo"), GodotString::from("bar")]);
+        assert_eq!(2, strings.len());
+        assert_eq!(GodotString::from("bar"), strings[1]);
+        assert_eq!("[\"foo\", \"bar\"]", format!("{:?}", strings));
+    }
+);
+
+godot_test!(
+    test_byte_array_access {
+        let mut arr = ByteArray::new();
+        for i in 0..8 {
+            arr.push(i);
+        }
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(8, write.len());
+            for i in write.as_mut_slice() {
+                *i *= 2;
+            }
+        }
+
+        for i in 0..8 {
+            assert_eq!(i * 2, cow_arr.get(i as i32));
+        }
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7], original_read.as_slice());
+    }
+);
+
+godot_test!(
+    test_int32_array_access {
+        let mut arr = Int32Array::new();
+        for i in 0..8 {
+            arr.push(i);
+        }
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(8, write.len());
+            for i in write.as_mut_slice() {
+                *i *= 2;
+            }
+        }
+
+        for i in 0..8 {
+            assert_eq!(i * 2, cow_arr.get(i as i32));
+        }
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7], original_read.as_slice());
+    }
+);
+
+godot_test!(
+    test_float32_array_access {
+        let mut arr = Float32Array::new();
+        for i in 0..8 {
+            arr.push(i as f32);
+        }
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(8, write.len());
+            for i in write.as_mut_slice() {
+                *i *= 2.0;
+            }
+        }
+
+        for i in 0..8 {
+            assert_eq!(i as f32 * 2.0, cow_arr.get(i as i32));
+        }
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0], original_read.as_slice());
+    }
+);
+
+godot_test!(
+    test_string_array_access {
+        let mut arr = StringArray::new();
+        arr.push(&GodotString::from("foo"));
+        arr.push(&GodotString::from("bar"));
+        arr.push(&GodotString::from("baz"));
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[
+                GodotString::from("foo"),
+                GodotString::from("bar"),
+                GodotString::from("baz"),
+            ], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(3, write.len());
+            for s in write.as_mut_slice() {
+                *s = s.to_uppercase();
+            }
+        }
+
+        assert_eq!(GodotString::from("FOO"), cow_arr.get(0));
+        assert_eq!(GodotString::from("BAR"), cow_arr.get(1));
+        assert_eq!(GodotString::from("BAZ"), cow_arr.get(2));
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[
+            GodotString::from("foo"),
+            GodotString::from("bar"),
+            GodotString::from("baz"),
+        ], original_read.as_slice());
+    }
+);
+
+godot_test!(
+    test_vector2_array_access {
+        let mut arr = Vector2Array::new();
+        arr.push(&Vector2::new(1.0, 2.0));
+        arr.push(&Vector2::new(3.0, 4.0));
+        arr.push(&Vector2::new(5.0, 6.0));
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[
+                Vector2::new(1.0, 2.0),
+                Vector2::new(3.0, 4.0),
+                Vector2::new(5.0, 6.0),
+            ], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(3, write.len());
+            for s in write.as_mut_slice() {
+                s.x += 1.0;
+            }
+        }
+
+        assert_eq!(Vector2::new(2.0, 2.0), cow_arr.get(0));
+        assert_eq!(Vector2::new(4.0, 4.0), cow_arr.get(1));
+        assert_eq!(Vector2::new(6.0, 6.0), cow_arr.get(2));
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, 4.0),
+            Vector2::new(5.0, 6.0),
+        ], original_read.as_slice());
+    }
+);
+
+godot_test!(
+    test_vector3_array_access {
+        let mut arr = Vector3Array::new();
+        arr.push(&Vector3::new(1.0, 2.0, 3.0));
+        arr.push(&Vector3::new(3.0, 4.0, 5.0));
+        arr.push(&Vector3::new(5.0, 6.0, 7.0));
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(3.0, 4.0, 5.0),
+                Vector3::new(5.0, 6.0, 7.0),
+            ], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(3, write.len());
+            for s in write.as_mut_slice() {
+                s.x += 2.0;
+                s.y += 1.0;
+            }
+        }
+
+        assert_eq!(Vector3::new(3.0, 3.0, 3.0), cow_arr.get(0));
+        assert_eq!(Vector3::new(5.0, 5.0, 5.0), cow_arr.get(1));
+        assert_eq!(Vector3::new(7.0, 7.0, 7.0), cow_arr.get(2));
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(3.0, 4.0, 5.0),
+            Vector3::new(5.0, 6.0, 7.0),
+        ], original_read.as_slice());
+    }
+);
+
+godot_test!(
+    test_color_array_access {
+        let mut arr = ColorArray::new();
+        arr.push(&Color::rgb(1.0, 0.0, 0.0));
+        arr.push(&Color::rgb(0.0, 1.0, 0.0));
+        arr.push(&Color::rgb(0.0, 0.0, 1.0));
+
+        let original_read = {
+            let read = arr.read();
+            assert_eq!(&[
+                Color::rgb(1.0, 0.0, 0.0),
+                Color::rgb(0.0, 1.0, 0.0),
+                Color::rgb(0.0, 0.0, 1.0),
+            ], read.as_slice());
+            read.clone()
+        };
+
+        let mut cow_arr = arr.new_ref();
+
+        {
+            let mut write = cow_arr.write();
+            assert_eq!(3, write.len());
+            for i in write.as_mut_slice() {
+                i.b = 1.0;
+            }
+        }
+
+        assert_eq!(Color::rgb(1.0, 0.0, 1.0), cow_arr.get(0));
+        assert_eq!(Color::rgb(0.0, 1.0, 1.0), cow_arr.get(1));
+        assert_eq!(Color::rgb(0.0, 0.0, 1.0), cow_arr.get(2));
+
+        // the write shouldn't have affected the original array
+        assert_eq!(&[
+            Color::rgb(1.0, 0.0, 0.0),
+            Color::rgb(0.0, 1.0, 0.0),
+            Color::rgb(0.0, 0.0, 1.0),
+        ], original_read.as_slice());
+    }
+);
@@ -0,0 +1,172 @@
+use crate::geom::CMP_EPSILON;
+use crate::{Basis, Quat, Vector3};
+
+/// Helper methods for `Quat`.
+///
+/// Trait used to provide additional methods that are equivalent to Godot's methods.
+/// See the official [`Godot documentation`](https://docs.godotengine.org/en/3.1/classes/class_quat.html).
+///
+/// The interpolation methods use Godot's formulas, and unlike the inherent `slerp` method of
+/// euclid's rotations, they don't require the quaternions to be normalized.
+pub trait QuatGodot {
+    /// Creates a quaternion from Euler angles in radians, in the YXZ convention used by
+    /// Godot.
+    fn from_euler(euler: Vector3) -> Self;
+    /// Returns the rotation as Euler angles in radians, in the YXZ convention used by Godot.
+    fn get_euler(self) -> Vector3;
+    /// Returns the dot product with `b`.
+    fn dot(self, b: Self) -> f32;
+    /// Spherically interpolates between this quaternion and `b`, taking the shortest path.
+    /// `t` is in the range of 0.0 - 1.0, representing the amount of interpolation.
+    fn slerp(self, b: Self, t: f32) -> Self;
+    /// Spherically interpolates between this quaternion and `b`, without checking whether
+    /// the rotation path is the shortest.
+    fn slerpni(self, b: Self, t: f32) -> Self;
+    /// Performs a spherical cubic interpolation between this quaternion and `b`, using
+    /// `pre_a` and `post_b` as handles.
+    fn cubic_slerp(self, b: Self, pre_a: Self, post_b: Self, t: f32) -> Self;
+}
+
+impl QuatGodot for Quat {
+    #[inline]
+    fn from_euler(euler: Vector3) -> Self {
+        Basis::from_euler(euler).to_quat()
+    }
+
+    #[inline]
+    fn get_euler(self) -> Vector3 {
+        Basis::from_quat(self).get_euler()
+    }
+
+    #[inline]
+    fn dot(self, b: Self) -> f32 {
+        self.i * b.i + self.j * b.j + self.k * b.k + self.r * b.r
+    }
+
+    fn slerp(self, b: Self, t: f32) -> Self {
+        let mut cosom = self.dot(b);
+        let b = if cosom < 0.0 {
+            cosom = -cosom;
+            Quat::quaternion(-b.i, -b.j, -b.k, -b.r)
+        } else {
+            b
+        };
+
+        let (scale0, scale1) = if 1.0 - cosom > CMP_EPSILON {
+            let omega = cosom.acos();
+            let sinom = omega.sin();
+            (((1.0 - t) * omega).sin() / sinom, (t * omega).sin() / sinom)
+        } else {
+            // the quaternions are very close, so linear interpolation is precise enough
+            (1.0 - t, t)
+        };
+
+        Quat::quaternion(
+            scale0 * self.i + scale1 * b.i,
+            scale0 * self.j + scale1 * b.j,
+            scale0 * self.k + scale1 * b.k,
+            scale0 * self.r + scale1 * b.r,
+        )
+    }
+
+    fn slerpni(self, b: Self, t: f32) -> Self {
+        let dot = self.dot(b);
+        if dot.abs() > 0.9999 {
+            return self;
+        }
+
+        let theta = dot.acos();
+        let sin_t = 1.0 / theta.sin();
+        let new_factor = (t * theta).sin() * sin_t;
+        let inv_factor = ((1.0 - t) * theta).sin() * sin_t;
+
+        Quat::quaternion(
+            inv_factor * self.i + new_factor * b.i,
+            inv_factor * self.j + new_factor * b.j,
+            inv_factor * self.k + new_factor * b.k,
+            inv_factor * self.r + new_factor * b.r,
+        )
+    }
+
+    #[inline]
+    fn cubic_slerp(self, b: Self, pre_a: Self, post_b: Self, t: f32) -> Self {
+        let t2 = (1.0 - t) * t * 2.0;
+        let sp = QuatGodot::slerp(self, b, t);
+        let sq = pre_a.slerpni(post_b, t);
+        sp.slerpni(sq, t2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quat::QuatGodot;
+    use crate::{Quat, Vector3};
+    use euclid::approxeq::ApproxEq;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn around_y(angle: f32) -> Quat {
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Quat::quaternion(0.0, sin, 0.0, cos)
+    }
+
+    fn approx_eq(a: Quat, b: Quat) -> bool {
+        a.i.approx_eq(&b.i) && a.j.approx_eq(&b.j) && a.k.approx_eq(&b.k) && a.r.approx_eq(&b.r)
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<sys::godot_quat>(), size_of::<Quat>());
+    }
+
+    #[test]
+    fn euler_roundtrip() {
+        let euler = Vector3::new(0.3, -1.2, 2.0);
+        assert!(euler.approx_eq(&Quat::from_euler(euler).get_euler()));
+        assert!(approx_eq(
+            around_y(FRAC_PI_2),
+            Quat::from_euler(Vector3::new(0.0, FRAC_PI_2, 0.0))
+        ));
+    }
+
+    #[test]
+    fn slerp_is_sane() {
+        let from = around_y(0.0);
+        let to = around_y(FRAC_PI_2);
+
+        assert!(approx_eq(around_y(FRAC_PI_2 / 2.0), from.slerp(to, 0.5)));
+        assert!(approx_eq(from, from.slerp(to, 0.0)));
+        assert!(approx_eq(to, from.slerp(to, 1.0)));
+
+        // takes the shortest path when the quaternions are in opposite hemispheres
+        let negated = Quat::quaternion(-to.i, -to.j, -to.k, -to.r);
+        assert!(approx_eq(
+            around_y(FRAC_PI_2 / 2.0),
+            from.slerp(negated, 0.5)
+        ));
+    }
+
+    #[test]
+    fn slerpni_is_sane() {
+        let from = around_y(0.0);
+        let to = around_y(FRAC_PI_2);
+
+        assert!(approx_eq(around_y(FRAC_PI_2 / 4.0), from.slerpni(to, 0.25)));
+        assert!(approx_eq(from, from.slerpni(from, 0.5)));
+    }
+
+    #[test]
+    fn cubic_slerp_is_sane() {
+        let pre_a = around_y(-0.5);
+        let a = around_y(0.0);
+        let b = around_y(0.5);
+        let post_b = around_y(1.0);
+
+        assert!(approx_eq(a, a.cubic_slerp(b, pre_a, post_b, 0.0)));
+        assert!(approx_eq(b, a.cubic_slerp(b, pre_a, post_b, 1.0)));
+        assert!(approx_eq(
+            around_y(0.25),
+            a.cubic_slerp(b, pre_a, post_b, 0.5)
+        ));
+    }
+}
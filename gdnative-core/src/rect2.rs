@@ -0,0 +1,134 @@
+use crate::{Point2, Rect2, Vector2};
+
+/// A side of a rectangle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Margin {
+    Left = 0,
+    Top = 1,
+    Right = 2,
+    Bottom = 3,
+}
+
+/// Helper methods for `Rect2`.
+///
+/// Trait used to provide additional methods that are equivalent to Godot's methods.
+/// See the official [`Godot documentation`](https://docs.godotengine.org/en/3.1/classes/class_rect2.html).
+pub trait Rect2Godot {
+    /// Returns the rectangle extended by `by` on all sides.
+    fn grow(self, by: f32) -> Self;
+    /// Returns the rectangle extended by the given amount on each side.
+    fn grow_individual(self, left: f32, top: f32, right: f32, bottom: f32) -> Self;
+    /// Returns the rectangle extended by `by` on the side `margin`.
+    fn grow_margin(self, margin: Margin, by: f32) -> Self;
+    /// Returns the rectangle expanded to include `to`.
+    fn expand(self, to: Vector2) -> Self;
+    /// Returns the intersection of this rectangle and `b`, or an empty rectangle at the origin
+    /// if they don't overlap.
+    fn clip(self, b: Self) -> Self;
+    /// Returns `true` if the rectangle contains `point`. Points on the right and bottom edges
+    /// are not contained.
+    fn has_point(self, point: Vector2) -> bool;
+}
+
+impl Rect2Godot for Rect2 {
+    #[inline]
+    fn grow(self, by: f32) -> Self {
+        self.grow_individual(by, by, by, by)
+    }
+
+    #[inline]
+    fn grow_individual(self, left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        let mut rect = self;
+        rect.origin.x -= left;
+        rect.origin.y -= top;
+        rect.size.width += left + right;
+        rect.size.height += top + bottom;
+        rect
+    }
+
+    #[inline]
+    fn grow_margin(self, margin: Margin, by: f32) -> Self {
+        match margin {
+            Margin::Left => self.grow_individual(by, 0.0, 0.0, 0.0),
+            Margin::Top => self.grow_individual(0.0, by, 0.0, 0.0),
+            Margin::Right => self.grow_individual(0.0, 0.0, by, 0.0),
+            Margin::Bottom => self.grow_individual(0.0, 0.0, 0.0, by),
+        }
+    }
+
+    #[inline]
+    fn expand(self, to: Vector2) -> Self {
+        let begin = self.origin.min(to.to_point());
+        let end = self.max().max(to.to_point());
+        Rect2::new(begin, (end - begin).to_size())
+    }
+
+    #[inline]
+    fn clip(self, b: Self) -> Self {
+        if !self.intersects(&b) {
+            return Rect2::zero();
+        }
+
+        let origin = self.origin.max(b.origin);
+        let end = self.max().min(b.max());
+        Rect2::new(origin, (end - origin).to_size())
+    }
+
+    #[inline]
+    fn has_point(self, point: Vector2) -> bool {
+        let Point2 { x, y, .. } = point.to_point();
+        x >= self.origin.x && y >= self.origin.y && x < self.max_x() && y < self.max_y()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rect2::{Margin, Rect2Godot};
+    use crate::{Point2, Rect2, Vector2};
+
+    fn rect(x: f32, y: f32, width: f32, height: f32) -> Rect2 {
+        Rect2::new(Point2::new(x, y), euclid::size2(width, height))
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(size_of::<sys::godot_rect2>(), size_of::<Rect2>());
+    }
+
+    #[test]
+    fn grow_is_sane() {
+        let r = rect(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(rect(0.0, 1.0, 5.0, 6.0), r.grow(1.0));
+        assert_eq!(
+            rect(0.0, 0.0, 7.0, 10.0),
+            r.grow_individual(1.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(rect(-1.0, 2.0, 5.0, 4.0), r.grow_margin(Margin::Left, 2.0));
+        assert_eq!(rect(1.0, 2.0, 3.0, 6.0), r.grow_margin(Margin::Bottom, 2.0));
+    }
+
+    #[test]
+    fn expand_is_sane() {
+        let r = rect(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(rect(0.0, 2.0, 4.0, 4.0), r.expand(Vector2::new(0.0, 3.0)));
+        assert_eq!(rect(1.0, 2.0, 5.0, 8.0), r.expand(Vector2::new(6.0, 10.0)));
+        assert_eq!(r, r.expand(Vector2::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn clip_is_sane() {
+        let r = rect(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(rect(2.0, 1.0, 2.0, 3.0), r.clip(rect(2.0, 1.0, 5.0, 5.0)));
+        assert_eq!(rect(0.0, 0.0, 0.0, 0.0), r.clip(rect(5.0, 5.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn has_point_is_sane() {
+        let r = rect(0.0, 0.0, 4.0, 4.0);
+        assert!(r.has_point(Vector2::new(0.0, 2.0)));
+        assert!(!r.has_point(Vector2::new(4.0, 2.0)));
+        assert!(!r.has_point(Vector2::new(-1.0, 2.0)));
+    }
+}
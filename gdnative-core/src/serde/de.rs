@@ -0,0 +1,364 @@
+use serde_::de::{
+    self, Deserialize, DeserializeOwned, DeserializeSeed, Deserializer, EnumAccess, MapAccess,
+    SeqAccess, VariantAccess, Visitor,
+};
+use serde_::ser::Serialize;
+
+use super::{is_natural, variant_type_name, Error, VariantSerializer};
+use crate::{Dictionary, ToVariant, Variant, VariantArray, VariantType};
+
+/// Deserializes a value from a `Variant`.
+///
+/// Typed values such as `Vector2` or `Color` can be read from variants holding either the
+/// typed value itself, or its serialized form (e.g. an array of two numbers for `Vector2`).
+pub fn from_variant<T>(variant: &Variant) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(variant)
+}
+
+/// Deserializes a value of the concrete type corresponding to `ty`, and wraps it in a `Variant`.
+pub(super) fn deserialize_typed<'de, D>(
+    ty: VariantType,
+    deserializer: D,
+) -> Result<Variant, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match_variant_type!(
+        ty,
+        |T| T::deserialize(deserializer).map(|value| value.to_variant()),
+        Err(de::Error::custom(unsupported(ty)))
+    )
+}
+
+fn unsupported(ty: VariantType) -> String {
+    format!("{:?} variants are not supported by serde", ty)
+}
+
+/// Seed deserializing the content of a tagged `Variant`.
+pub(super) struct TypedSeed(pub(super) VariantType);
+
+impl<'de> DeserializeSeed<'de> for TypedSeed {
+    type Value = Variant;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Variant, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_typed(self.0, deserializer)
+    }
+}
+
+/// Converts typed values to their serialized form, so they can be read by the generic
+/// `deserialize_*` methods. Other values are returned as-is.
+fn to_generic(variant: &Variant) -> Result<Variant, Error> {
+    let ty = variant.get_type();
+    if is_natural(ty) {
+        return Ok(variant.clone());
+    }
+
+    match_variant_value!(
+        variant,
+        |value| value.serialize(VariantSerializer),
+        Err(de::Error::custom(unsupported(ty)))
+    )
+}
+
+macro_rules! forward_to_generic {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Error>
+            where
+                V: Visitor<'de>,
+            {
+                to_generic(self)?.deserialize_any(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for &Variant {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.get_type() {
+            VariantType::Nil => visitor.visit_unit(),
+            VariantType::Bool => visitor.visit_bool(self.to_bool()),
+            VariantType::I64 => visitor.visit_i64(self.to_i64()),
+            VariantType::F64 => visitor.visit_f64(self.to_f64()),
+            VariantType::GodotString => visitor.visit_string(self.to_string()),
+            VariantType::VariantArray => visitor.visit_seq(ArrayAccess::new(self.to_array())),
+            VariantType::Dictionary => {
+                visitor.visit_map(DictionaryAccess::new(self.to_dictionary()))
+            }
+            ty @ VariantType::Object | ty @ VariantType::Rid => {
+                Err(de::Error::custom(unsupported(ty)))
+            }
+            ty => visitor.visit_enum(VariantEnumAccess {
+                tag: Variant::from_str(variant_type_name(ty)),
+                value: self.clone(),
+            }),
+        }
+    }
+
+    forward_to_generic! {
+        deserialize_bool
+        deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_f32 deserialize_f64
+        deserialize_char deserialize_str deserialize_string
+        deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.try_to_byte_array() {
+            Some(bytes) => visitor.visit_bytes(&bytes.read()),
+            None => to_generic(self)?.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.is_nil() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.get_type() {
+            // Unit variants
+            VariantType::GodotString => visitor.visit_enum(VariantEnumAccess {
+                tag: self.clone(),
+                value: Variant::new(),
+            }),
+            // Externally tagged variants, as produced by `ToVariant`
+            VariantType::Dictionary => {
+                let dict = self.to_dictionary();
+                if dict.len() != 1 {
+                    return Err(de::Error::invalid_length(
+                        dict.len() as usize,
+                        &"a dictionary with a single entry",
+                    ));
+                }
+
+                let tag = dict.keys().get_val(0);
+                let value = dict.get(&tag);
+                visitor.visit_enum(VariantEnumAccess { tag, value })
+            }
+            ty if !is_natural(ty) => self.deserialize_any(visitor),
+            ty => Err(de::Error::custom(format!(
+                "expected an enum, found a {:?} variant",
+                ty
+            ))),
+        }
+    }
+}
+
+struct ArrayAccess {
+    array: VariantArray,
+    index: i32,
+}
+
+impl ArrayAccess {
+    fn new(array: VariantArray) -> Self {
+        ArrayAccess { array, index: 0 }
+    }
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        if self.index >= self.array.len() {
+            return Ok(None);
+        }
+
+        let value = seed.deserialize(self.array.get_ref(self.index))?;
+        self.index += 1;
+        Ok(Some(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.array.len() - self.index) as usize)
+    }
+}
+
+struct DictionaryAccess {
+    dict: Dictionary,
+    keys: VariantArray,
+    index: i32,
+}
+
+impl DictionaryAccess {
+    fn new(dict: Dictionary) -> Self {
+        let keys = dict.keys();
+        DictionaryAccess {
+            dict,
+            keys,
+            index: 0,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for DictionaryAccess {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        if self.index >= self.keys.len() {
+            return Ok(None);
+        }
+
+        seed.deserialize(self.keys.get_ref(self.index)).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let value = seed.deserialize(self.dict.get_ref(self.keys.get_ref(self.index)))?;
+        self.index += 1;
+        Ok(value)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.keys.len() - self.index) as usize)
+    }
+}
+
+struct VariantEnumAccess {
+    tag: Variant,
+    value: Variant,
+}
+
+impl<'de> EnumAccess<'de> for VariantEnumAccess {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let tag = seed.deserialize(&self.tag)?;
+        Ok((tag, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantEnumAccess {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        if self.value.is_nil() {
+            Ok(())
+        } else {
+            Err(de::Error::custom("expected a unit variant"))
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(&self.value)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value.deserialize_tuple(len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value.deserialize_struct("", fields, visitor)
+    }
+}
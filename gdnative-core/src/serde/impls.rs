@@ -0,0 +1,408 @@
+use std::fmt;
+
+use serde_::de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess, Visitor};
+use serde_::ser::{self, Serialize, SerializeMap, Serializer};
+
+use super::de::{deserialize_typed, TypedSeed};
+use super::{
+    is_natural, variant_type_from_index, variant_type_from_name, variant_type_name, VARIANT_ENUM,
+    VARIANT_TYPE_NAMES,
+};
+use crate::*;
+
+impl Serialize for VariantType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit_variant("VariantType", *self as u32, variant_type_name(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for VariantType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct VariantTypeVisitor;
+
+        impl<'de> Visitor<'de> for VariantTypeVisitor {
+            type Value = VariantType;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a variant type")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<VariantType, E>
+            where
+                E: de::Error,
+            {
+                variant_type_from_index(value).ok_or_else(|| {
+                    E::invalid_value(de::Unexpected::Unsigned(value), &"a variant type index")
+                })
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<VariantType, E>
+            where
+                E: de::Error,
+            {
+                variant_type_from_name(value)
+                    .ok_or_else(|| E::unknown_variant(value, VARIANT_TYPE_NAMES))
+            }
+
+            fn visit_bytes<E>(self, value: &[u8]) -> Result<VariantType, E>
+            where
+                E: de::Error,
+            {
+                match std::str::from_utf8(value) {
+                    Ok(value) => self.visit_str(value),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(VariantTypeVisitor)
+    }
+}
+
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ty = self.get_type();
+        if serializer.is_human_readable() && is_natural(ty) {
+            return match_variant_value!(self, |value| value.serialize(serializer), unreachable!());
+        }
+
+        if ty == VariantType::Nil {
+            return serializer.serialize_unit_variant(VARIANT_ENUM, ty as u32, "Nil");
+        }
+
+        match_variant_value!(
+            self,
+            |value| serializer.serialize_newtype_variant(
+                VARIANT_ENUM,
+                ty as u32,
+                variant_type_name(ty),
+                &value
+            ),
+            Err(ser::Error::custom(format!(
+                "{:?} variants are not supported by serde",
+                ty
+            )))
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(VariantVisitor)
+        } else {
+            deserializer.deserialize_enum(VARIANT_ENUM, VARIANT_TYPE_NAMES, VariantVisitor)
+        }
+    }
+}
+
+struct VariantVisitor;
+
+impl<'de> Visitor<'de> for VariantVisitor {
+    type Value = Variant;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a variant")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Variant, E> {
+        Ok(Variant::from_bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Variant, E> {
+        Ok(Variant::from_i64(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Variant, E> {
+        Ok(Variant::from_u64(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Variant, E> {
+        Ok(Variant::from_f64(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Variant, E> {
+        Ok(Variant::from_str(value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Variant, E> {
+        Ok(ByteArray::from_slice(value).to_variant())
+    }
+
+    fn visit_unit<E>(self) -> Result<Variant, E> {
+        Ok(Variant::new())
+    }
+
+    fn visit_none<E>(self) -> Result<Variant, E> {
+        Ok(Variant::new())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Variant, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Variant::deserialize(deserializer)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Variant, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Variant::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Variant, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        VariantArrayVisitor
+            .visit_seq(seq)
+            .map(|array| array.to_variant())
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Variant, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let dict = DictionaryVisitor.visit_map(map)?;
+
+        // A single entry keyed by the name of a non-natural type is a tagged value.
+        if dict.len() == 1 {
+            let key = dict.keys().get_val(0);
+            let ty = key
+                .try_to_string()
+                .and_then(|name| variant_type_from_name(&name));
+            if let Some(ty) = ty {
+                if !is_natural(ty) {
+                    if let Ok(variant) = deserialize_typed(ty, dict.get_ref(&key)) {
+                        return Ok(variant);
+                    }
+                }
+            }
+        }
+
+        Ok(dict.to_variant())
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Variant, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        use serde_::de::VariantAccess;
+
+        let (ty, access) = data.variant::<VariantType>()?;
+        if ty == VariantType::Nil {
+            access.unit_variant()?;
+            return Ok(Variant::new());
+        }
+
+        access.newtype_variant_seed(TypedSeed(ty))
+    }
+}
+
+impl Serialize for Dictionary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let keys = self.keys();
+        let mut map = serializer.serialize_map(Some(keys.len() as usize))?;
+        for key in keys.iter() {
+            map.serialize_entry(key, self.get_ref(key))?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Dictionary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DictionaryVisitor)
+    }
+}
+
+struct DictionaryVisitor;
+
+impl<'de> Visitor<'de> for DictionaryVisitor {
+    type Value = Dictionary;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a dictionary")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Dictionary, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dict = Dictionary::new();
+        while let Some((key, value)) = map.next_entry::<Variant, Variant>()? {
+            dict.set(&key, &value);
+        }
+        Ok(dict)
+    }
+}
+
+impl Serialize for VariantArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for VariantArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(VariantArrayVisitor)
+    }
+}
+
+struct VariantArrayVisitor;
+
+impl<'de> Visitor<'de> for VariantArrayVisitor {
+    type Value = VariantArray;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<VariantArray, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array = VariantArray::new();
+        while let Some(value) = seq.next_element::<Variant>()? {
+            array.push(&value);
+        }
+        Ok(array)
+    }
+}
+
+impl Serialize for GodotString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for GodotString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(GodotString::from_str)
+    }
+}
+
+impl Serialize for NodePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NodePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|path| NodePath::from_str(&path))
+    }
+}
+
+impl Serialize for ByteArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&self.read())
+    }
+}
+
+impl<'de> Deserialize<'de> for ByteArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ByteArrayVisitor;
+
+        impl<'de> Visitor<'de> for ByteArrayVisitor {
+            type Value = ByteArray;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a byte array")
+            }
+
+            fn visit_bytes<E>(self, value: &[u8]) -> Result<ByteArray, E> {
+                Ok(ByteArray::from_slice(value))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<ByteArray, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::new();
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    bytes.push(byte);
+                }
+                Ok(ByteArray::from_vec(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(ByteArrayVisitor)
+    }
+}
+
+macro_rules! impl_serde_for_pool_array {
+    ($($Array:ident: $Element:ty,)*) => {
+        $(
+            impl Serialize for $Array {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_seq(self.read().iter())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $Array {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Vec::<$Element>::deserialize(deserializer).map($Array::from_vec)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_for_pool_array! {
+    Int32Array: i32,
+    Float32Array: f32,
+    StringArray: GodotString,
+    Vector2Array: Vector2,
+    Vector3Array: Vector3,
+    ColorArray: Color,
+}
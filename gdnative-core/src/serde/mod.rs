@@ -0,0 +1,310 @@
+//! Serde support for `Variant` and the core types.
+//!
+//! This module is only available with the `serde` feature enabled. It provides:
+//!
+//! - `Serialize` and `Deserialize` implementations for `Variant`, `Dictionary`, `VariantArray`,
+//!   `GodotString`, `NodePath`, the pool arrays and the math types, so they can be written
+//!   with any serde format.
+//! - [`VariantSerializer`](struct.VariantSerializer.html), a `Serializer` that produces a
+//!   `Variant`, and a `Deserializer` implementation for `&Variant`, so any serde-enabled type
+//!   can be converted to and from `Variant`s with [`to_variant`](fn.to_variant.html) and
+//!   [`from_variant`](fn.from_variant.html).
+//!
+//! ## Representation of `Variant`
+//!
+//! In human-readable formats (such as JSON), nil, booleans, integers, floats, strings, arrays
+//! and dictionaries are represented naturally. All other types are written as a map with a
+//! single entry, keyed by the name of the `VariantType`:
+//!
+//! ```ignore
+//! {"position": {"Vector2": [1.0, 2.0]}, "name": "player", "hp": 100}
+//! ```
+//!
+//! In binary formats, every `Variant` is written as an externally tagged enum named `Variant`,
+//! with the `VariantType` as the variant.
+//!
+//! `Object` and `Rid` variants can't be serialized.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct SaveGame {
+//!     level: String,
+//!     score: u32,
+//! }
+//!
+//! let variant = gdnative::serde::to_variant(&save)?;
+//! let save: SaveGame = gdnative::serde::from_variant(&variant)?;
+//! ```
+
+use std::fmt;
+
+use crate::VariantType;
+
+/// Evaluates `$body` with `$value` bound to the content of `$variant`, as its concrete type.
+/// `Object`s and `Rid`s evaluate to `$unsupported` instead.
+macro_rules! match_variant_value {
+    ($variant:expr, |$value:ident| $body:expr, $unsupported:expr) => {
+        match $variant.get_type() {
+            VariantType::Nil => {
+                let $value = ();
+                $body
+            }
+            VariantType::Bool => {
+                let $value = $variant.to_bool();
+                $body
+            }
+            VariantType::I64 => {
+                let $value = $variant.to_i64();
+                $body
+            }
+            VariantType::F64 => {
+                let $value = $variant.to_f64();
+                $body
+            }
+            VariantType::GodotString => {
+                let $value = $variant.to_godot_string();
+                $body
+            }
+            VariantType::Vector2 => {
+                let $value = $variant.to_vector2();
+                $body
+            }
+            VariantType::Rect2 => {
+                let $value = $variant.to_rect2();
+                $body
+            }
+            VariantType::Vector3 => {
+                let $value = $variant.to_vector3();
+                $body
+            }
+            VariantType::Transform2D => {
+                let $value = $variant.to_transform2d();
+                $body
+            }
+            VariantType::Plane => {
+                let $value = $variant.to_plane();
+                $body
+            }
+            VariantType::Quat => {
+                let $value = $variant.to_quat();
+                $body
+            }
+            VariantType::Aabb => {
+                let $value = $variant.to_aabb();
+                $body
+            }
+            VariantType::Basis => {
+                let $value = $variant.to_basis();
+                $body
+            }
+            VariantType::Transform => {
+                let $value = $variant.to_transform();
+                $body
+            }
+            VariantType::Color => {
+                let $value = $variant.to_color();
+                $body
+            }
+            VariantType::NodePath => {
+                let $value = $variant.to_node_path();
+                $body
+            }
+            VariantType::Dictionary => {
+                let $value = $variant.to_dictionary();
+                $body
+            }
+            VariantType::VariantArray => {
+                let $value = $variant.to_array();
+                $body
+            }
+            VariantType::ByteArray => {
+                let $value = $variant.to_byte_array();
+                $body
+            }
+            VariantType::Int32Array => {
+                let $value = $variant.to_int32_array();
+                $body
+            }
+            VariantType::Float32Array => {
+                let $value = $variant.to_float32_array();
+                $body
+            }
+            VariantType::StringArray => {
+                let $value = $variant.to_string_array();
+                $body
+            }
+            VariantType::Vector2Array => {
+                let $value = $variant.to_vector2_array();
+                $body
+            }
+            VariantType::Vector3Array => {
+                let $value = $variant.to_vector3_array();
+                $body
+            }
+            VariantType::ColorArray => {
+                let $value = $variant.to_color_array();
+                $body
+            }
+            VariantType::Rid | VariantType::Object => $unsupported,
+        }
+    };
+}
+
+/// Evaluates `$body` with `$T` aliased to the concrete type corresponding to `$ty`.
+/// `Object` and `Rid` evaluate to `$unsupported` instead.
+macro_rules! match_variant_type {
+    ($ty:expr, |$T:ident| $body:expr, $unsupported:expr) => {
+        match_variant_type!(@arms $ty, $T, $body, $unsupported;
+            Nil => (),
+            Bool => bool,
+            I64 => i64,
+            F64 => f64,
+            GodotString => crate::GodotString,
+            Vector2 => crate::Vector2,
+            Rect2 => crate::Rect2,
+            Vector3 => crate::Vector3,
+            Transform2D => crate::Transform2D,
+            Plane => crate::Plane,
+            Quat => crate::Quat,
+            Aabb => crate::Aabb,
+            Basis => crate::Basis,
+            Transform => crate::Transform,
+            Color => crate::Color,
+            NodePath => crate::NodePath,
+            Dictionary => crate::Dictionary,
+            VariantArray => crate::VariantArray,
+            ByteArray => crate::ByteArray,
+            Int32Array => crate::Int32Array,
+            Float32Array => crate::Float32Array,
+            StringArray => crate::StringArray,
+            Vector2Array => crate::Vector2Array,
+            Vector3Array => crate::Vector3Array,
+            ColorArray => crate::ColorArray,
+        )
+    };
+    (@arms $ty:expr, $T:ident, $body:expr, $unsupported:expr; $($variant:ident => $Type:ty,)*) => {
+        match $ty {
+            $(
+                VariantType::$variant => {
+                    type $T = $Type;
+                    $body
+                }
+            )*
+            VariantType::Rid | VariantType::Object => $unsupported,
+        }
+    };
+}
+
+mod de;
+mod impls;
+mod ser;
+
+pub use self::de::from_variant;
+pub use self::ser::{to_variant, VariantSerializer};
+
+/// Error type for conversions between `Variant` and serde data formats.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    fn new<T: fmt::Display>(message: T) -> Self {
+        Error {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde_::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(msg)
+    }
+}
+
+impl serde_::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(msg)
+    }
+}
+
+/// Name of the enum used to represent tagged `Variant`s. It can't be the name of a Rust type,
+/// so that user enums named `Variant` aren't mistaken for it by `VariantSerializer`.
+const VARIANT_ENUM: &str = "$gdnative::Variant";
+
+/// Names of the variant types, indexed by their discriminants.
+const VARIANT_TYPE_NAMES: &[&str] = &[
+    "Nil",
+    "Bool",
+    "I64",
+    "F64",
+    "GodotString",
+    "Vector2",
+    "Rect2",
+    "Vector3",
+    "Transform2D",
+    "Plane",
+    "Quat",
+    "Aabb",
+    "Basis",
+    "Transform",
+    "Color",
+    "NodePath",
+    "Rid",
+    "Object",
+    "Dictionary",
+    "VariantArray",
+    "ByteArray",
+    "Int32Array",
+    "Float32Array",
+    "StringArray",
+    "Vector2Array",
+    "Vector3Array",
+    "ColorArray",
+];
+
+fn variant_type_name(ty: VariantType) -> &'static str {
+    VARIANT_TYPE_NAMES[ty as usize]
+}
+
+fn variant_type_from_index(index: u64) -> Option<VariantType> {
+    if index < VARIANT_TYPE_NAMES.len() as u64 {
+        Some(VariantType::from_sys(
+            index as crate::sys::godot_variant_type,
+        ))
+    } else {
+        None
+    }
+}
+
+fn variant_type_from_name(name: &str) -> Option<VariantType> {
+    VARIANT_TYPE_NAMES
+        .iter()
+        .position(|n| *n == name)
+        .and_then(|index| variant_type_from_index(index as u64))
+}
+
+/// Returns `true` if values of type `ty` are represented without a tag in human-readable
+/// formats.
+fn is_natural(ty: VariantType) -> bool {
+    match ty {
+        VariantType::Nil
+        | VariantType::Bool
+        | VariantType::I64
+        | VariantType::F64
+        | VariantType::GodotString
+        | VariantType::Dictionary
+        | VariantType::VariantArray => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,381 @@
+use serde_::ser::{self, Serialize};
+
+use super::{de, variant_type_from_name, Error, VARIANT_ENUM};
+use crate::{ByteArray, Dictionary, ToVariant, Variant, VariantArray, VariantType};
+
+/// Serializes a value into a `Variant`.
+///
+/// Sequences become `VariantArray`s, and maps and structs become `Dictionary`s. Enums use the
+/// same externally tagged representation as the `ToVariant` derive macro.
+pub fn to_variant<T>(value: &T) -> Result<Variant, Error>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(VariantSerializer)
+}
+
+/// A serde `Serializer` that produces `Variant`s.
+///
+/// Serializing a `Variant` with this serializer produces an identical copy of it.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct VariantSerializer;
+
+impl ser::Serializer for VariantSerializer {
+    type Ok = Variant;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeTagged<SerializeArray>;
+    type SerializeMap = SerializeDictionary;
+    type SerializeStruct = SerializeDictionary;
+    type SerializeStructVariant = SerializeTagged<SerializeDictionary>;
+
+    fn serialize_bool(self, v: bool) -> Result<Variant, Error> {
+        Ok(Variant::from_bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Variant, Error> {
+        Ok(Variant::from_i64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Variant, Error> {
+        Ok(Variant::from_u64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Variant, Error> {
+        Ok(Variant::from_f64(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Variant, Error> {
+        Ok(Variant::from_f64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Variant, Error> {
+        let mut buf = [0; 4];
+        Ok(Variant::from_str(v.encode_utf8(&mut buf)))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Variant, Error> {
+        Ok(Variant::from_str(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Variant, Error> {
+        let mut bytes = ByteArray::new();
+        for &byte in v {
+            bytes.push(byte);
+        }
+        Ok(bytes.to_variant())
+    }
+
+    fn serialize_none(self) -> Result<Variant, Error> {
+        Ok(Variant::new())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Variant, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Variant, Error> {
+        Ok(Variant::new())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Variant, Error> {
+        Ok(Variant::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Variant, Error> {
+        if name == VARIANT_ENUM && variant_type_from_name(variant) == Some(VariantType::Nil) {
+            return Ok(Variant::new());
+        }
+
+        Ok(Variant::from_str(variant))
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Variant, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Variant, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(self)?;
+
+        // Tagged `Variant`s are converted back to the type they name.
+        if name == VARIANT_ENUM {
+            if let Some(ty) = variant_type_from_name(variant) {
+                return de::deserialize_typed(ty, &value);
+            }
+        }
+
+        Ok(tagged(variant, value))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray {
+            array: VariantArray::new(),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTagged<SerializeArray>, Error> {
+        Ok(SerializeTagged {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeDictionary, Error> {
+        Ok(SerializeDictionary {
+            dict: Dictionary::new(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeDictionary, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTagged<SerializeDictionary>, Error> {
+        Ok(SerializeTagged {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+fn tagged(variant: &str, value: Variant) -> Variant {
+    let mut dict = Dictionary::new();
+    dict.set(&Variant::from_str(variant), &value);
+    dict.to_variant()
+}
+
+#[doc(hidden)]
+pub struct SerializeArray {
+    array: VariantArray,
+}
+
+impl SerializeArray {
+    fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.array.push(&value.serialize(VariantSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> VariantArray {
+        self.array
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.finish().to_variant())
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.finish().to_variant())
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.finish().to_variant())
+    }
+}
+
+#[doc(hidden)]
+pub struct SerializeDictionary {
+    dict: Dictionary,
+    key: Option<Variant>,
+}
+
+impl ser::SerializeMap for SerializeDictionary {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.key = Some(key.serialize(VariantSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::new("serialize_value called before serialize_key"))?;
+        self.dict.set(&key, &value.serialize(VariantSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.dict.to_variant())
+    }
+}
+
+impl ser::SerializeStruct for SerializeDictionary {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.dict.set(
+            &Variant::from_str(key),
+            &value.serialize(VariantSerializer)?,
+        );
+        Ok(())
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(self.dict.to_variant())
+    }
+}
+
+#[doc(hidden)]
+pub struct SerializeTagged<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for SerializeTagged<SerializeArray> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(tagged(self.variant, self.inner.finish().to_variant()))
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeTagged<SerializeDictionary> {
+    type Ok = Variant;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Variant, Error> {
+        Ok(tagged(self.variant, self.inner.dict.to_variant()))
+    }
+}
@@ -0,0 +1,135 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+
+use futures::executor::{LocalPool, LocalSpawner};
+use futures::task::LocalSpawnExt;
+use futures::FutureExt;
+
+use crate::init::ClassBuilder;
+use crate::user_data::LocalCellData;
+use crate::{NativeClass, NativeClassMethods, Object};
+
+thread_local! {
+    static POOL: RefCell<LocalPool> = RefCell::new(LocalPool::new());
+    static SPAWNER: RefCell<LocalSpawner> = POOL.with(|pool| RefCell::new(pool.borrow().spawner()));
+}
+
+/// Spawns a future on the executor of the current thread.
+///
+/// The future is not polled immediately, but on the next call to [`poll`](fn.poll.html) on
+/// this thread. Panics inside the future are caught and reported with `godot_error!`.
+pub fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + 'static,
+{
+    let future = AssertUnwindSafe(future).catch_unwind().map(|result| {
+        if result.is_err() {
+            godot_error!("gdnative-core: async task panicked");
+        }
+    });
+
+    SPAWNER.with(|spawner| {
+        if let Err(err) = spawner.borrow().spawn_local(future) {
+            godot_error!("gdnative-core: failed to spawn async task: {:?}", err);
+        }
+    });
+}
+
+/// Runs all tasks spawned on the current thread until none of them can make progress.
+///
+/// This is called every frame by [`AsyncExecutorDriver`](struct.AsyncExecutorDriver.html),
+/// and can also be called manually. Nested calls, such as from inside a running task, are
+/// ignored.
+pub fn poll() {
+    POOL.with(|pool| {
+        if let Ok(mut pool) = pool.try_borrow_mut() {
+            pool.run_until_stalled();
+        }
+    });
+}
+
+/// Drops all pending tasks of the current thread, while the API is still available for
+/// destructors of the values they hold.
+pub(crate) fn cleanup() {
+    let new_pool = LocalPool::new();
+    let new_spawner = new_pool.spawner();
+
+    let old_pool = POOL.with(|pool| {
+        pool.try_borrow_mut()
+            .ok()
+            .map(|mut pool| std::mem::replace(&mut *pool, new_pool))
+    });
+
+    if old_pool.is_some() {
+        SPAWNER.with(|spawner| *spawner.borrow_mut() = new_spawner);
+    }
+
+    drop(old_pool);
+}
+
+/// Script class that polls the executor of the main thread every frame.
+///
+/// An instance of this class should be attached to a `Node` that is always in the scene
+/// tree, for example an autoload singleton. Register it with
+/// [`register_runtime`](fn.register_runtime.html).
+pub struct AsyncExecutorDriver;
+
+impl NativeClass for AsyncExecutorDriver {
+    type Base = Object;
+    type UserData = LocalCellData<AsyncExecutorDriver>;
+
+    fn class_name() -> &'static str {
+        "AsyncExecutorDriver"
+    }
+
+    fn init(_owner: Object) -> Self {
+        AsyncExecutorDriver
+    }
+}
+
+impl AsyncExecutorDriver {
+    fn _process(&mut self, _owner: Object, _delta: f64) {
+        poll();
+    }
+}
+
+impl NativeClassMethods for AsyncExecutorDriver {
+    fn register(builder: &ClassBuilder<Self>) {
+        builder.add_method(
+            "_process",
+            godot_wrap_method!(
+                AsyncExecutorDriver,
+                fn _process(&mut self, _owner: Object, _delta: f64)
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn spawned_tasks_run_on_poll() {
+        let counter = Rc::new(Cell::new(0));
+
+        let task_counter = counter.clone();
+        spawn(async move {
+            task_counter.set(task_counter.get() + 1);
+
+            let nested_counter = task_counter.clone();
+            spawn(async move {
+                nested_counter.set(nested_counter.get() + 10);
+            });
+        });
+
+        assert_eq!(0, counter.get());
+        poll();
+        assert_eq!(11, counter.get());
+        poll();
+        assert_eq!(11, counter.get());
+    }
+}
@@ -0,0 +1,72 @@
+use std::future::Future;
+
+use crate::init::{ClassBuilder, ExportInfo, PropertyUsage, Signal, SignalArgument};
+use crate::user_data::LocalCellData;
+use crate::{
+    GodotString, Instance, NativeClass, NativeClassMethods, Reference, ToVariant, Variant,
+    VariantType,
+};
+
+/// Object returned to the caller of an `async` method, which emits its `completed` signal with
+/// the result of the method once it finishes.
+///
+/// From GDScript, the result can be awaited with `yield`:
+///
+/// ```ignore
+/// var result = yield(node.some_async_method(), "completed")
+/// ```
+pub struct FunctionState;
+
+impl NativeClass for FunctionState {
+    type Base = Reference;
+    type UserData = LocalCellData<FunctionState>;
+
+    fn class_name() -> &'static str {
+        "FunctionState"
+    }
+
+    fn init(_owner: Reference) -> Self {
+        FunctionState
+    }
+
+    fn register_properties(builder: &ClassBuilder<Self>) {
+        builder.add_signal(Signal {
+            name: "completed",
+            args: &[SignalArgument {
+                name: "result",
+                default: Variant::new(),
+                export_info: ExportInfo::new(VariantType::Nil),
+                usage: PropertyUsage::DEFAULT,
+            }],
+        });
+    }
+}
+
+impl NativeClassMethods for FunctionState {
+    fn register(_builder: &ClassBuilder<Self>) {}
+}
+
+/// Spawns the future of an exported `async` method, and returns a `FunctionState` that
+/// emits `completed` with its output.
+///
+/// The signal is never emitted before this function returns, so callers always get a chance
+/// to connect to it.
+#[doc(hidden)]
+pub fn spawn_method<F>(future: F) -> Variant
+where
+    F: Future + 'static,
+    F::Output: ToVariant,
+{
+    let state = Instance::<FunctionState>::new();
+    let owner = state.base().new_ref();
+
+    super::spawn(async move {
+        let result = future.await.to_variant();
+        let mut owner = owner;
+        unsafe {
+            owner.emit_signal(GodotString::from_str("completed"), &[result]);
+        }
+    });
+
+    state.to_variant()
+}
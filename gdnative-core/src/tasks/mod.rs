@@ -0,0 +1,62 @@
+//! Async runtime driven by the Godot main loop.
+//!
+//! This module is only available with the `async` feature enabled. It provides:
+//!
+//! - A single-threaded executor per thread. Futures are started with [`spawn`](fn.spawn.html)
+//!   and run whenever [`poll`](fn.poll.html) is called on the same thread.
+//! - [`AsyncExecutorDriver`](struct.AsyncExecutorDriver.html), a script class that calls
+//!   `poll` from `_process`. Attach it to a node that stays in the scene tree, such as an
+//!   autoload, to run tasks on the main thread every frame.
+//! - [`SignalFuture`](struct.SignalFuture.html), which turns the next emission of any signal
+//!   into a future resolving to its arguments.
+//! - Support for `async fn` in `#[methods]` impl blocks. Exported `async` methods take an
+//!   `Instance<Self>` instead of `self` and the owner, and return a `FunctionState` object to
+//!   the caller, which emits `completed` with the result when the method finishes.
+//!
+//! The classes used by the runtime must be registered with
+//! [`register_runtime`](fn.register_runtime.html) before any of this is used.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! #[methods]
+//! impl Enemy {
+//!     #[export]
+//!     async fn attack(this: Instance<Self>, damage: i64) -> bool {
+//!         this.signal_future("animation_finished").unwrap().await;
+//!         unsafe { this.map_mut_aliased(|enemy, _| enemy.hit(damage)) }.unwrap()
+//!     }
+//! }
+//!
+//! fn init(handle: gdnative::init::InitHandle) {
+//!     gdnative::tasks::register_runtime(&handle);
+//!     handle.add_class::<Enemy>();
+//! }
+//! ```
+//!
+//! Since futures may be kept across frames, they must not hold on to objects that can be
+//! freed in the meantime, such as instances with a manually managed base.
+
+mod executor;
+mod function_state;
+mod signal;
+
+pub use self::executor::{poll, spawn, AsyncExecutorDriver};
+#[doc(hidden)]
+pub use self::function_state::spawn_method;
+pub use self::function_state::FunctionState;
+#[doc(hidden)]
+pub use self::signal::SignalForwarder;
+pub use self::signal::{signal_future, SignalFuture};
+
+pub(crate) use self::executor::cleanup;
+
+use crate::init::InitHandle;
+
+/// Registers the classes used by the async runtime: `AsyncExecutorDriver`, `FunctionState`
+/// and `SignalForwarder`.
+pub fn register_runtime(handle: &InitHandle) {
+    handle.add_class::<AsyncExecutorDriver>();
+    handle.add_class::<FunctionState>();
+    handle.add_class::<SignalForwarder>();
+}
@@ -0,0 +1,146 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use crate::init::ClassBuilder;
+use crate::user_data::LocalCellData;
+use crate::{
+    GodotError, GodotObject, GodotString, Instance, NativeClass, NativeClassMethods, Object,
+    Reference, Variant, VariantArray,
+};
+
+/// Future that resolves to the arguments of the next emission of a signal.
+///
+/// Created with [`signal_future`](fn.signal_future.html), `Object::signal_future` or
+/// `Instance::signal_future`. If the future is dropped before the signal is emitted, the
+/// connection is removed.
+pub struct SignalFuture {
+    forwarder: Instance<SignalForwarder>,
+}
+
+impl Future for SignalFuture {
+    type Output = Vec<Variant>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = self
+            .forwarder
+            .map_mut(|forwarder, _| match forwarder.args.take() {
+                Some(args) => Poll::Ready(args),
+                None => {
+                    forwarder.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            });
+
+        match result {
+            Ok(poll) => poll,
+            Err(err) => {
+                godot_error!("gdnative-core: failed to poll signal future: {:?}", err);
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Returns a future that resolves to the arguments of the next emission of `signal` on
+/// `source`.
+///
+/// The signal is connected immediately, so emissions that happen before the future is first
+/// polled are not lost. Requires the classes registered by
+/// [`register_runtime`](fn.register_runtime.html).
+///
+/// # Safety
+///
+/// `source` must point to a valid Godot object.
+pub unsafe fn signal_future<T: GodotObject>(
+    source: &T,
+    signal: &str,
+) -> Result<SignalFuture, GodotError> {
+    let forwarder = Instance::<SignalForwarder>::new();
+
+    let mut source = Object::from_sys(source.to_sys());
+    source.connect(
+        GodotString::from_str(signal),
+        Some(forwarder.base().to_object()),
+        GodotString::from_str("resolve"),
+        VariantArray::new(),
+        Object::CONNECT_ONESHOT,
+    )?;
+
+    Ok(SignalFuture { forwarder })
+}
+
+impl Object {
+    /// Returns a future that resolves to the arguments of the next emission of `signal`.
+    ///
+    /// See [`tasks::signal_future`](tasks/fn.signal_future.html).
+    pub fn signal_future(&self, signal: &str) -> Result<SignalFuture, GodotError> {
+        unsafe { signal_future(self, signal) }
+    }
+}
+
+impl<T: NativeClass> Instance<T> {
+    /// Returns a future that resolves to the arguments of the next emission of `signal` on the
+    /// owner of this instance.
+    ///
+    /// See [`tasks::signal_future`](tasks/fn.signal_future.html).
+    pub fn signal_future(&self, signal: &str) -> Result<SignalFuture, GodotError> {
+        unsafe { signal_future(self.base(), signal) }
+    }
+}
+
+/// Script class connected to the signal awaited by a `SignalFuture`.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct SignalForwarder {
+    args: Option<Vec<Variant>>,
+    waker: Option<Waker>,
+}
+
+impl NativeClass for SignalForwarder {
+    type Base = Reference;
+    type UserData = LocalCellData<SignalForwarder>;
+
+    fn class_name() -> &'static str {
+        "SignalForwarder"
+    }
+
+    fn init(_owner: Reference) -> Self {
+        SignalForwarder::default()
+    }
+}
+
+impl NativeClassMethods for SignalForwarder {
+    fn register(builder: &ClassBuilder<Self>) {
+        builder.add_method("resolve", resolve);
+    }
+}
+
+/// Stores the arguments of the signal and wakes the waiting task. Takes any number of
+/// arguments, so it can't be wrapped with `godot_wrap_method!`.
+unsafe extern "C" fn resolve(
+    this: *mut sys::godot_object,
+    _method_data: *mut libc::c_void,
+    user_data: *mut libc::c_void,
+    num_args: libc::c_int,
+    args: *mut *mut sys::godot_variant,
+) -> sys::godot_variant {
+    let instance = Instance::<SignalForwarder>::from_raw(this, user_data);
+
+    let args = (0..num_args as isize)
+        .map(|i| (*(*args.offset(i) as *const Variant)).clone())
+        .collect::<Vec<_>>();
+
+    let result = instance.map_mut(|forwarder, _| {
+        forwarder.args = Some(args);
+        forwarder.waker.take()
+    });
+
+    match result {
+        Ok(Some(waker)) => waker.wake(),
+        Ok(None) => {}
+        Err(err) => godot_error!("gdnative-core: failed to resolve signal future: {:?}", err),
+    }
+
+    Variant::new().forget()
+}
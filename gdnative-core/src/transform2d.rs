@@ -0,0 +1,218 @@
+use crate::{Angle, Transform2D, Vector2};
+
+/// Helper methods for `Transform2D`.
+///
+/// Trait used to provide additional methods that are equivalent to Godot's methods.
+/// See the official [`Godot documentation`](https://docs.godotengine.org/en/3.1/classes/class_transform2d.html).
+///
+/// Like in Godot, the X axis of the transform is `(m11, m12)`, the Y axis is `(m21, m22)` and
+/// the origin is `(m31, m32)`.
+pub trait Transform2DGodot {
+    /// Creates a transform from a rotation and an origin.
+    fn from_rotation(rotation: Angle, origin: Vector2) -> Self;
+    /// Returns the X axis of the transform.
+    fn x_axis(&self) -> Vector2;
+    /// Returns the Y axis of the transform.
+    fn y_axis(&self) -> Vector2;
+    /// Returns the translation offset of the transform.
+    fn get_origin(&self) -> Vector2;
+    /// Returns the rotation of the transform. If the transform contains a reflection, it is
+    /// treated as a flip of the Y axis, consistently with `get_scale`.
+    fn get_rotation(&self) -> Angle;
+    /// Returns the scale of the transform. The Y scale is negated if the transform contains
+    /// a reflection.
+    fn get_scale(&self) -> Vector2;
+    /// Transforms `v` by the basis of the transform, without translation.
+    fn basis_xform(&self, v: Vector2) -> Vector2;
+    /// Transforms `v` by the inverse of the basis of the transform, without translation,
+    /// assuming that the basis is a rotation.
+    fn basis_xform_inv(&self, v: Vector2) -> Vector2;
+    /// Transforms `v` by the inverse of the transform, assuming that the basis is a rotation.
+    fn xform_inv(&self, v: Vector2) -> Vector2;
+    /// Returns the transform with normalized axes that are perpendicular to each other.
+    fn orthonormalized(&self) -> Self;
+    /// Interpolates between this transform and `other`, spherically for the rotations and
+    /// linearly for the scales and origins. `weight` is in the range of 0.0 - 1.0.
+    fn interpolate_with(&self, other: &Self, weight: f32) -> Self;
+}
+
+impl Transform2DGodot for Transform2D {
+    #[inline]
+    fn from_rotation(rotation: Angle, origin: Vector2) -> Self {
+        let (sin, cos) = rotation.radians.sin_cos();
+        Transform2D::row_major(cos, sin, -sin, cos, origin.x, origin.y)
+    }
+
+    #[inline]
+    fn x_axis(&self) -> Vector2 {
+        Vector2::new(self.m11, self.m12)
+    }
+
+    #[inline]
+    fn y_axis(&self) -> Vector2 {
+        Vector2::new(self.m21, self.m22)
+    }
+
+    #[inline]
+    fn get_origin(&self) -> Vector2 {
+        Vector2::new(self.m31, self.m32)
+    }
+
+    #[inline]
+    fn get_rotation(&self) -> Angle {
+        let x = self.orthonormalized().x_axis();
+        // the reflection is absorbed into the Y scale, which negates the Y components
+        let y = if self.determinant() < 0.0 { -x.y } else { x.y };
+        Angle::radians(y.atan2(x.x))
+    }
+
+    #[inline]
+    fn get_scale(&self) -> Vector2 {
+        let sign = self.determinant().signum();
+        Vector2::new(self.x_axis().length(), sign * self.y_axis().length())
+    }
+
+    #[inline]
+    fn basis_xform(&self, v: Vector2) -> Vector2 {
+        self.x_axis() * v.x + self.y_axis() * v.y
+    }
+
+    #[inline]
+    fn basis_xform_inv(&self, v: Vector2) -> Vector2 {
+        Vector2::new(self.x_axis().dot(v), self.y_axis().dot(v))
+    }
+
+    #[inline]
+    fn xform_inv(&self, v: Vector2) -> Vector2 {
+        self.basis_xform_inv(v - self.get_origin())
+    }
+
+    #[inline]
+    fn orthonormalized(&self) -> Self {
+        let x = self.x_axis().normalize();
+        let y = self.y_axis();
+        let y = (y - x * x.dot(y)).normalize();
+
+        Transform2D::row_major(x.x, x.y, y.x, y.y, self.m31, self.m32)
+    }
+
+    fn interpolate_with(&self, other: &Self, weight: f32) -> Self {
+        let (r1, r2) = (self.get_rotation(), other.get_rotation());
+        let v1 = Vector2::new(r1.radians.cos(), r1.radians.sin());
+        let v2 = Vector2::new(r2.radians.cos(), r2.radians.sin());
+
+        let dot = v1.dot(v2).clamp(-1.0, 1.0);
+        let v = if dot > 0.9995 {
+            // linearly interpolate to avoid numerical precision issues
+            v1.lerp(v2, weight).normalize()
+        } else {
+            let angle = weight * dot.acos();
+            let v3 = (v2 - v1 * dot).normalize();
+            v1 * angle.cos() + v3 * angle.sin()
+        };
+
+        let origin = self.get_origin().lerp(other.get_origin(), weight);
+        let scale = self.get_scale().lerp(other.get_scale(), weight);
+
+        let mut result = Transform2D::from_rotation(Angle::radians(v.y.atan2(v.x)), origin);
+        result.m11 *= scale.x;
+        result.m12 *= scale.y;
+        result.m21 *= scale.x;
+        result.m22 *= scale.y;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transform2d::Transform2DGodot;
+    use crate::{Angle, Transform2D, Vector2};
+    use euclid::approxeq::ApproxEq;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn approx_eq(a: &Transform2D, b: &Transform2D) -> bool {
+        a.to_row_major_array()
+            .iter()
+            .zip(b.to_row_major_array().iter())
+            .all(|(a, b)| a.approx_eq(b))
+    }
+
+    #[test]
+    fn it_has_the_same_size() {
+        use std::mem::size_of;
+        assert_eq!(
+            size_of::<sys::godot_transform2d>(),
+            size_of::<Transform2D>()
+        );
+    }
+
+    #[test]
+    fn from_rotation_is_sane() {
+        let t = Transform2D::from_rotation(Angle::radians(FRAC_PI_2), Vector2::new(1.0, 2.0));
+        assert!(Vector2::new(0.0, 1.0).approx_eq(&t.x_axis()));
+        assert!(Vector2::new(-1.0, 0.0).approx_eq(&t.y_axis()));
+        assert!(FRAC_PI_2.approx_eq(&t.get_rotation().radians));
+        assert_eq!(Vector2::new(1.0, 2.0), t.get_origin());
+
+        // consistent with euclid's transform_point
+        let p = t.transform_point(euclid::point2(1.0, 0.0));
+        assert!(Vector2::new(1.0, 3.0).approx_eq(&p.to_vector()));
+    }
+
+    #[test]
+    fn basis_xform_is_sane() {
+        let t = Transform2D::from_rotation(Angle::radians(FRAC_PI_2), Vector2::new(1.0, 2.0));
+        let v = Vector2::new(1.0, 0.0);
+        assert!(Vector2::new(0.0, 1.0).approx_eq(&t.basis_xform(v)));
+        assert!(v.approx_eq(&t.basis_xform_inv(t.basis_xform(v))));
+
+        let p = t.transform_point(euclid::point2(3.0, -1.0)).to_vector();
+        assert!(Vector2::new(3.0, -1.0).approx_eq(&t.xform_inv(p)));
+    }
+
+    #[test]
+    fn get_scale_is_sane() {
+        let t = Transform2D::row_major(2.0, 0.0, 0.0, 3.0, 0.0, 0.0);
+        assert!(Vector2::new(2.0, 3.0).approx_eq(&t.get_scale()));
+
+        let flipped = Transform2D::row_major(2.0, 0.0, 0.0, -3.0, 0.0, 0.0);
+        assert!(Vector2::new(2.0, -3.0).approx_eq(&flipped.get_scale()));
+    }
+
+    #[test]
+    fn orthonormalized_is_sane() {
+        let t = Transform2D::row_major(2.0, 0.0, 1.0, 3.0, 4.0, 5.0).orthonormalized();
+        assert!(approx_eq(
+            &Transform2D::row_major(1.0, 0.0, 0.0, 1.0, 4.0, 5.0),
+            &t
+        ));
+    }
+
+    #[test]
+    fn interpolate_with_is_sane() {
+        let from = Transform2D::identity();
+        let mut to = Transform2D::from_rotation(Angle::radians(FRAC_PI_2), Vector2::new(2.0, 4.0));
+        to.m11 *= 3.0;
+        to.m12 *= 3.0;
+        to.m21 *= 3.0;
+        to.m22 *= 3.0;
+
+        let halfway = from.interpolate_with(&to, 0.5);
+        assert!(FRAC_PI_4.approx_eq(&halfway.get_rotation().radians));
+        assert!(Vector2::new(2.0, 2.0).approx_eq(&halfway.get_scale()));
+        assert!(Vector2::new(1.0, 2.0).approx_eq(&halfway.get_origin()));
+
+        assert!(approx_eq(&from, &from.interpolate_with(&to, 0.0)));
+        assert!(approx_eq(&to, &from.interpolate_with(&to, 1.0)));
+    }
+
+    #[test]
+    fn interpolate_with_keeps_reflections() {
+        let t = Transform2D::row_major(0.0, 1.0, 1.0, 0.0, 3.0, 4.0);
+        assert!((-FRAC_PI_2).approx_eq(&t.get_rotation().radians));
+        assert!(Vector2::new(1.0, -1.0).approx_eq(&t.get_scale()));
+
+        assert!(approx_eq(&t, &t.interpolate_with(&t, 0.0)));
+        assert!(approx_eq(&t, &t.interpolate_with(&t, 1.0)));
+    }
+}
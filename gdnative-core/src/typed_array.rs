@@ -0,0 +1,335 @@
+use crate::FromVariant;
+use crate::FromVariantError;
+use crate::ToVariant;
+use crate::Variant;
+use crate::VariantArray;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::{Extend, FromIterator};
+use std::marker::PhantomData;
+
+/// A Godot `VariantArray` with elements of type `T`.
+///
+/// Unlike `TypedDictionary`, the elements are checked when the array is created from an
+/// untyped `VariantArray` or `Variant`, so reading from the array doesn't return errors.
+/// The underlying array can still be shared with the engine, which doesn't enforce the
+/// element type, so the accessors panic if an element was replaced with a value of another
+/// type in the meantime.
+///
+/// When exported as a property, the element type and hint are shown in the editor inspector.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut waypoints = TypedArray::<Vector3>::new();
+/// waypoints.push(Vector3::new(1.0, 0.0, 2.0));
+///
+/// for waypoint in &waypoints {
+///     path.add_point(waypoint);
+/// }
+/// ```
+pub struct TypedArray<T> {
+    array: VariantArray,
+    _marker: PhantomData<T>,
+}
+
+impl<T> TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    /// Creates an empty `TypedArray`.
+    pub fn new() -> Self {
+        TypedArray {
+            array: VariantArray::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a `TypedArray` from an existing `VariantArray`, checking that all elements
+    /// can be converted to `T`.
+    pub fn from_array(array: VariantArray) -> Result<Self, FromVariantError> {
+        for (index, element) in array.iter().enumerate() {
+            T::from_variant(element).map_err(|err| FromVariantError::InvalidItem {
+                index,
+                error: Box::new(err),
+            })?;
+        }
+
+        Ok(TypedArray {
+            array,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the underlying `VariantArray`.
+    pub fn into_array(self) -> VariantArray {
+        self.array
+    }
+
+    /// Returns a reference to the underlying `VariantArray`.
+    pub fn as_array(&self) -> &VariantArray {
+        &self.array
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.array.len() as usize
+    }
+
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.array.is_empty()
+    }
+
+    /// Removes all elements from the array.
+    pub fn clear(&mut self) {
+        self.array.clear()
+    }
+
+    /// Appends an element at the end of the array.
+    pub fn push(&mut self, val: T) {
+        self.array.push(&val.to_variant())
+    }
+
+    /// Removes the element at the end of the array and returns it, or `None` if the array
+    /// is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let idx = self.len() - 1;
+        Some(convert(idx, &self.array.pop()))
+    }
+
+    /// Returns the element at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> T {
+        match self.array.get_checked(idx) {
+            Some(val) => convert(idx, val),
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                idx
+            ),
+        }
+    }
+
+    /// Returns the element at `idx`, or `None` if it is out of bounds.
+    pub fn get_checked(&self, idx: usize) -> Option<T> {
+        self.array.get_checked(idx).map(|val| convert(idx, val))
+    }
+
+    /// Sets the element at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn set(&mut self, idx: usize, val: T) {
+        match self.array.get_mut_checked(idx) {
+            Some(element) => *element = val.to_variant(),
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                idx
+            ),
+        }
+    }
+
+    /// Returns an iterator over the elements of the array.
+    pub fn iter(&self) -> TypedArrayIter<'_, T> {
+        TypedArrayIter {
+            iter: self.array.iter().enumerate(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a new reference to this array.
+    pub fn new_ref(&self) -> Self {
+        TypedArray {
+            array: self.array.new_ref(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Converts an element that was checked when the array was created.
+fn convert<T: FromVariant>(idx: usize, val: &Variant) -> T {
+    T::from_variant(val).unwrap_or_else(|err| {
+        panic!(
+            "element {} of the TypedArray changed to an invalid type: {}",
+            idx, err
+        )
+    })
+}
+
+impl<T> Default for TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    fn default() -> Self {
+        TypedArray::new()
+    }
+}
+
+impl<T> TryFrom<VariantArray> for TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    type Error = FromVariantError;
+
+    fn try_from(array: VariantArray) -> Result<Self, Self::Error> {
+        TypedArray::from_array(array)
+    }
+}
+
+impl<T> ToVariant for TypedArray<T> {
+    fn to_variant(&self) -> Variant {
+        self.array.to_variant()
+    }
+}
+
+impl<T> FromVariant for TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        VariantArray::from_variant(variant).and_then(TypedArray::from_array)
+    }
+}
+
+impl<T> fmt::Debug for TypedArray<T>
+where
+    T: ToVariant + FromVariant + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        TypedArray {
+            array: iter.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Extend<T> for TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.array.extend(iter)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    type Item = T;
+    type IntoIter = TypedArrayIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for TypedArray<T>
+where
+    T: ToVariant + FromVariant,
+{
+    type Item = T;
+    type IntoIter = TypedArrayIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TypedArrayIntoIter {
+            iter: self.array.into_iter().enumerate(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the elements of a `TypedArray`, created by `TypedArray::iter`.
+pub struct TypedArrayIter<'a, T> {
+    iter: std::iter::Enumerate<crate::variant_array::Iter<'a>>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Iterator for TypedArrayIter<'a, T>
+where
+    T: FromVariant,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(idx, val)| convert(idx, val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Owning iterator over the elements of a `TypedArray`.
+pub struct TypedArrayIntoIter<T> {
+    iter: std::iter::Enumerate<crate::variant_array::IntoIter>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Iterator for TypedArrayIntoIter<T>
+where
+    T: FromVariant,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(idx, val)| convert(idx, &val))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+godot_test!(test_typed_array {
+    let mut array: TypedArray<i64> = vec![1, 2].into_iter().collect();
+    array.push(3);
+    array.extend(vec![4]);
+
+    assert_eq!(4, array.len());
+    assert_eq!(2, array.get(1));
+    assert_eq!(None, array.get_checked(4));
+    assert_eq!(10, array.iter().sum::<i64>());
+
+    array.set(0, 10);
+    assert_eq!(Some(4), array.pop());
+    assert_eq!(vec![10, 2, 3], array.new_ref().into_iter().collect::<Vec<_>>());
+
+    let variant = array.to_variant();
+    let from_variant = TypedArray::<i64>::from_variant(&variant).unwrap();
+    assert_eq!(15, (&from_variant).into_iter().sum::<i64>());
+
+    let mut untyped = array.into_array();
+    untyped.push(&"not a number".to_variant());
+    match TypedArray::<i64>::try_from(untyped) {
+        Err(FromVariantError::InvalidItem { index, .. }) => assert_eq!(3, index),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(TypedArray::<i64>::from_variant(&"not an array".to_variant()).is_err());
+
+    use crate::init::property::Export;
+    use crate::VariantType;
+    let info = TypedArray::<f64>::export_info(None);
+    assert_eq!(VariantType::VariantArray, info.variant_type);
+    assert_eq!("3/0:", info.hint_string.to_string());
+});
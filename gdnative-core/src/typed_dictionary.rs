@@ -0,0 +1,442 @@
+use crate::Dictionary;
+use crate::DictionaryIntoIter;
+use crate::DictionaryIter;
+use crate::FromVariant;
+use crate::FromVariantError;
+use crate::ToVariant;
+use crate::Variant;
+
+use std::fmt;
+use std::iter::{Extend, FromIterator};
+use std::marker::PhantomData;
+
+/// A typed view over a Godot `Dictionary`, with keys of type `K` and values of type `V`.
+///
+/// Keys and values are converted to and from `Variant`s as they are accessed. Since the
+/// underlying `Dictionary` can be shared with the engine, which doesn't enforce the types,
+/// methods reading from the dictionary return a `FromVariantError` when an entry can't be
+/// converted.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use gdnative_core::{FromVariantError, TypedDictionary};
+/// # fn main() -> Result<(), FromVariantError> {
+/// let mut stats = TypedDictionary::<String, i64>::new();
+/// stats.insert("hp".to_string(), 100);
+///
+/// // Values are copies, so changes have to be written back
+/// let mp = stats.entry("mp".to_string()).or_insert(0)?;
+/// stats.insert("mp".to_string(), mp + 10);
+///
+/// assert_eq!(Ok(Some(100)), stats.get(&"hp".to_string()));
+/// # Ok(())
+/// # }
+/// ```
+pub struct TypedDictionary<K, V> {
+    dict: Dictionary,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V> TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    /// Creates an empty `TypedDictionary`.
+    pub fn new() -> Self {
+        TypedDictionary::from_dictionary(Dictionary::new())
+    }
+
+    /// Creates a typed view over an existing `Dictionary`. The entries are not checked until
+    /// they are accessed.
+    pub fn from_dictionary(dict: Dictionary) -> Self {
+        TypedDictionary {
+            dict,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the underlying `Dictionary`.
+    pub fn into_dictionary(self) -> Dictionary {
+        self.dict
+    }
+
+    /// Returns a reference to the underlying `Dictionary`.
+    pub fn as_dictionary(&self) -> &Dictionary {
+        &self.dict
+    }
+
+    /// Returns the number of entries in the dictionary.
+    pub fn len(&self) -> i32 {
+        self.dict.len()
+    }
+
+    /// Returns `true` if the dictionary contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.dict.is_empty()
+    }
+
+    /// Removes all entries from the dictionary.
+    pub fn clear(&mut self) {
+        self.dict.clear()
+    }
+
+    /// Returns `true` if the dictionary contains the specified key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.dict.contains(&key.to_variant())
+    }
+
+    /// Returns the value corresponding to the key, or `None` if the key doesn't exist.
+    pub fn get(&self, key: &K) -> Result<Option<V>, FromVariantError> {
+        self.dict.get_as(key)
+    }
+
+    /// Inserts a key-value pair into the dictionary, replacing the previous value if any.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.dict.set(&key.to_variant(), &value.to_variant())
+    }
+
+    /// Removes a key from the dictionary, returning its previous value if any.
+    pub fn remove(&mut self, key: &K) -> Result<Option<V>, FromVariantError> {
+        let key = key.to_variant();
+        if !self.dict.contains(&key) {
+            return Ok(None);
+        }
+
+        let value = V::from_variant(self.dict.get_ref(&key));
+        self.dict.erase(&key);
+        value.map(Some)
+    }
+
+    /// Returns an iterator over the entries of the dictionary, in insertion order.
+    pub fn iter(&self) -> TypedDictionaryIter<'_, K, V> {
+        TypedDictionaryIter {
+            iter: self.dict.iter(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the entry corresponding to the key, for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let key_variant = key.to_variant();
+        if self.dict.contains(&key_variant) {
+            Entry::Occupied(OccupiedEntry {
+                dict: self,
+                key,
+                key_variant,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                dict: self,
+                key,
+                key_variant,
+            })
+        }
+    }
+}
+
+impl<K, V> Default for TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    fn default() -> Self {
+        TypedDictionary::new()
+    }
+}
+
+impl<K, V> From<Dictionary> for TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    fn from(dict: Dictionary) -> Self {
+        TypedDictionary::from_dictionary(dict)
+    }
+}
+
+impl<K, V> ToVariant for TypedDictionary<K, V> {
+    fn to_variant(&self) -> Variant {
+        self.dict.to_variant()
+    }
+}
+
+impl<K, V> FromVariant for TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        Dictionary::from_variant(variant).map(TypedDictionary::from_dictionary)
+    }
+}
+
+impl<K, V> fmt::Debug for TypedDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.dict.fmt(f)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        TypedDictionary::from_dictionary(iter.into_iter().collect())
+    }
+}
+
+impl<K, V> Extend<(K, V)> for TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.dict.extend(iter)
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    type Item = Result<(K, V), FromVariantError>;
+    type IntoIter = TypedDictionaryIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<K, V> IntoIterator for TypedDictionary<K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    type Item = Result<(K, V), FromVariantError>;
+    type IntoIter = TypedDictionaryIntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TypedDictionaryIntoIter {
+            iter: self.dict.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn convert_entry<K, V>(key: &Variant, value: &Variant) -> Result<(K, V), FromVariantError>
+where
+    K: FromVariant,
+    V: FromVariant,
+{
+    Ok((K::from_variant(key)?, V::from_variant(value)?))
+}
+
+/// Iterator over the entries of a `TypedDictionary`, created by `TypedDictionary::iter`.
+pub struct TypedDictionaryIter<'a, K, V> {
+    iter: DictionaryIter<'a>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<'a, K, V> Iterator for TypedDictionaryIter<'a, K, V>
+where
+    K: FromVariant,
+    V: FromVariant,
+{
+    type Item = Result<(K, V), FromVariantError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(key, value)| convert_entry(key, value))
+    }
+}
+
+/// Owning iterator over the entries of a `TypedDictionary`.
+pub struct TypedDictionaryIntoIter<K, V> {
+    iter: DictionaryIntoIter,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V> Iterator for TypedDictionaryIntoIter<K, V>
+where
+    K: FromVariant,
+    V: FromVariant,
+{
+    type Item = Result<(K, V), FromVariantError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(key, value)| convert_entry(&key, &value))
+    }
+}
+
+/// A view into a single entry of a `TypedDictionary`, created by `TypedDictionary::entry`.
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Returns the value of the entry, inserting `default` first if it is vacant.
+    pub fn or_insert(self, default: V) -> Result<V, FromVariantError> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Returns the value of the entry, inserting the result of `default` first if it is
+    /// vacant.
+    pub fn or_insert_with<F>(self, default: F) -> Result<V, FromVariantError>
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.get(),
+            Entry::Vacant(entry) => Ok(entry.insert(default())),
+        }
+    }
+
+    /// Returns the value of the entry, inserting `V::default()` first if it is vacant.
+    pub fn or_default(self) -> Result<V, FromVariantError>
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Modifies the value of an occupied entry with `f`, writing the result back to the
+    /// dictionary. Vacant entries are left untouched.
+    pub fn and_modify<F>(self, f: F) -> Result<Self, FromVariantError>
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                let mut value = entry.get()?;
+                f(&mut value);
+                entry.insert(value)?;
+                Ok(Entry::Occupied(entry))
+            }
+            Entry::Vacant(entry) => Ok(Entry::Vacant(entry)),
+        }
+    }
+}
+
+/// An occupied entry of a `TypedDictionary`.
+pub struct OccupiedEntry<'a, K, V> {
+    dict: &'a mut TypedDictionary<K, V>,
+    key: K,
+    key_variant: Variant,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the value of this entry.
+    pub fn get(&self) -> Result<V, FromVariantError> {
+        V::from_variant(self.dict.dict.get_ref(&self.key_variant))
+    }
+
+    /// Sets the value of this entry, returning the previous value.
+    pub fn insert(&mut self, value: V) -> Result<V, FromVariantError> {
+        let old = self.get();
+        self.dict.dict.set(&self.key_variant, &value.to_variant());
+        old
+    }
+
+    /// Removes this entry from the dictionary, returning its value.
+    pub fn remove(self) -> Result<V, FromVariantError> {
+        let value = self.get();
+        self.dict.dict.erase(&self.key_variant);
+        value
+    }
+}
+
+/// A vacant entry of a `TypedDictionary`.
+pub struct VacantEntry<'a, K, V> {
+    dict: &'a mut TypedDictionary<K, V>,
+    key: K,
+    key_variant: Variant,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V>
+where
+    K: ToVariant + FromVariant,
+    V: ToVariant + FromVariant,
+{
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the key of this entry, without inserting anything.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts a value into the dictionary at the key of this entry, returning the value.
+    pub fn insert(self, value: V) -> V {
+        self.dict.dict.set(&self.key_variant, &value.to_variant());
+        value
+    }
+}
+
+godot_test!(test_typed_dictionary {
+    let mut dict: TypedDictionary<String, i64> =
+        vec![("hp".to_string(), 100)].into_iter().collect();
+    dict.insert("mp".to_string(), 20);
+    dict.extend(vec![("xp".to_string(), 0)]);
+
+    assert_eq!(3, dict.len());
+    assert!(dict.contains_key(&"hp".to_string()));
+    assert_eq!(Ok(Some(20)), dict.get(&"mp".to_string()));
+    assert_eq!(Ok(None), dict.get(&"gold".to_string()));
+
+    assert_eq!(Ok(5), dict.entry("gold".to_string()).or_insert(5));
+    assert_eq!(Ok(100), dict.entry("hp".to_string()).or_insert(5));
+    assert!(dict
+        .entry("xp".to_string())
+        .and_modify(|xp| *xp += 10)
+        .is_ok());
+    assert_eq!(Ok(Some(10)), dict.get(&"xp".to_string()));
+
+    match dict.entry("gold".to_string()) {
+        Entry::Occupied(entry) => assert_eq!(Ok(5), entry.remove()),
+        Entry::Vacant(_) => panic!("entry should be occupied"),
+    }
+    assert_eq!(Ok(Some(20)), dict.remove(&"mp".to_string()));
+    assert_eq!(Ok(None), dict.remove(&"mp".to_string()));
+
+    let entries = dict.iter().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(
+        vec![("hp".to_string(), 100), ("xp".to_string(), 10)],
+        entries
+    );
+
+    let mut untyped = dict.as_dictionary().new_ref();
+    untyped.set(&"bad".to_variant(), &"not a number".to_variant());
+    assert!(dict.get(&"bad".to_string()).is_err());
+    assert_eq!(1, dict.into_iter().filter(Result::is_err).count());
+});
@@ -1,7 +1,12 @@
 use super::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::default::Default;
 use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FromIterator;
 use std::mem::{forget, transmute};
+use std::rc::Rc;
+use std::sync::Arc;
 
 // TODO: implement Debug, PartialEq, etc.
 
@@ -146,45 +151,71 @@ impl CallError {
     }
 }
 
-// TODO: Looks like this is missing from the godot_headers bindings.
-// It's risky to redefine it here and count on the fact that the integer
-// constants will be the same.
+/// Operators that can be applied to variants with `Variant::evaluate`.
 #[repr(u32)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum VariantOperator {
     //comparison
-    Equal,        // = OP_EQUAL,
-    NotEqual,     // = OP_NOT_EQUAL,
-    Less,         // = OP_LESS,
-    LessEqual,    // =  OP_LESS_EQUAL,
-    Greater,      // =  OP_GREATER,
-    GreaterEqual, // =  OP_GREATER_EQUAL,
+    Equal = sys::godot_variant_operator_GODOT_VARIANT_OP_EQUAL as u32,
+    NotEqual = sys::godot_variant_operator_GODOT_VARIANT_OP_NOT_EQUAL as u32,
+    Less = sys::godot_variant_operator_GODOT_VARIANT_OP_LESS as u32,
+    LessEqual = sys::godot_variant_operator_GODOT_VARIANT_OP_LESS_EQUAL as u32,
+    Greater = sys::godot_variant_operator_GODOT_VARIANT_OP_GREATER as u32,
+    GreaterEqual = sys::godot_variant_operator_GODOT_VARIANT_OP_GREATER_EQUAL as u32,
     //mathematic
-    Add,      // = OP_ADD,
-    Subtact,  // = OP_SUBTRACT,
-    Multiply, // = OP_MULTIPLY,
-    Divide,   // = OP_DIVIDE,
-    Negate,   // = OP_NEGATE,
-    Positive, // = OP_POSITIVE,
-    Module,   // = OP_MODULE,
-    Concat,   // = OP_STRING_CONCAT,
+    Add = sys::godot_variant_operator_GODOT_VARIANT_OP_ADD as u32,
+    Subtact = sys::godot_variant_operator_GODOT_VARIANT_OP_SUBTRACT as u32,
+    Multiply = sys::godot_variant_operator_GODOT_VARIANT_OP_MULTIPLY as u32,
+    Divide = sys::godot_variant_operator_GODOT_VARIANT_OP_DIVIDE as u32,
+    Negate = sys::godot_variant_operator_GODOT_VARIANT_OP_NEGATE as u32,
+    Positive = sys::godot_variant_operator_GODOT_VARIANT_OP_POSITIVE as u32,
+    Module = sys::godot_variant_operator_GODOT_VARIANT_OP_MODULE as u32,
+    Concat = sys::godot_variant_operator_GODOT_VARIANT_OP_STRING_CONCAT as u32,
     //bitwise
-    ShiftLeft,  // = OP_SHIFT_LEFT,
-    ShiftRight, // = OP_SHIFT_RIGHT,
-    BitAnd,     // = OP_BIT_AND,
-    BitOr,      // = OP_BIT_OR,
-    BitXor,     // = OP_BIT_XOR,
-    BitNegate,  // = OP_BIT_NEGATE,
+    ShiftLeft = sys::godot_variant_operator_GODOT_VARIANT_OP_SHIFT_LEFT as u32,
+    ShiftRight = sys::godot_variant_operator_GODOT_VARIANT_OP_SHIFT_RIGHT as u32,
+    BitAnd = sys::godot_variant_operator_GODOT_VARIANT_OP_BIT_AND as u32,
+    BitOr = sys::godot_variant_operator_GODOT_VARIANT_OP_BIT_OR as u32,
+    BitXor = sys::godot_variant_operator_GODOT_VARIANT_OP_BIT_XOR as u32,
+    BitNegate = sys::godot_variant_operator_GODOT_VARIANT_OP_BIT_NEGATE as u32,
     //logic
-    And, // = OP_AND,
-    Or,  // = OP_OR,
-    Xor, // = OP_XOR,
-    Not, // = OP_NOT,
+    And = sys::godot_variant_operator_GODOT_VARIANT_OP_AND as u32,
+    Or = sys::godot_variant_operator_GODOT_VARIANT_OP_OR as u32,
+    Xor = sys::godot_variant_operator_GODOT_VARIANT_OP_XOR as u32,
+    Not = sys::godot_variant_operator_GODOT_VARIANT_OP_NOT as u32,
     //containment
-    In,  // = OP_IN,
-    Max, // = OP_MAX
+    In = sys::godot_variant_operator_GODOT_VARIANT_OP_IN as u32,
+    Max = sys::godot_variant_operator_GODOT_VARIANT_OP_MAX as u32,
 }
 
+impl VariantOperator {
+    #[doc(hidden)]
+    pub fn to_sys(self) -> sys::godot_variant_operator {
+        self as u32 as sys::godot_variant_operator
+    }
+}
+
+/// Error returned by `Variant::evaluate` when the operator is not defined for the types of the
+/// operands, or the operation is invalid, like a division by zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidOp {
+    pub op: VariantOperator,
+    pub left: VariantType,
+    pub right: VariantType,
+}
+
+impl fmt::Display for InvalidOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid operands {:?} and {:?} for operator {:?}",
+            self.left, self.right, self.op
+        )
+    }
+}
+
+impl std::error::Error for InvalidOp {}
+
 //fn to_godot_varianty_type(v: VariantType) -> sys::godot_variant_type {
 //    unsafe { transmute(v) }
 //}
@@ -549,6 +580,41 @@ impl Variant {
         }
     }
 
+    /// Applies `op` to `a` and `b`, with the same semantics as the operators of GDScript,
+    /// including the promotion of integers to floats when mixed. The second operand is ignored
+    /// for the unary operators `Negate`, `Positive`, `BitNegate` and `Not`.
+    ///
+    /// Comparisons evaluate to `bool` variants. Returns an error if the operator is not defined
+    /// for the types of the operands, or if the operation is invalid, like a division by zero.
+    ///
+    /// This calls the engine's implementation. Under the `mock_api` feature, a pure-Rust
+    /// implementation is used instead, covering numbers, vectors, quaternions, colors, strings
+    /// and containers.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let sum = Variant::evaluate(VariantOperator::Add, &1.to_variant(), &0.5.to_variant())?;
+    /// assert_eq!(Some(1.5), sum.try_to_f64());
+    /// ```
+    pub fn evaluate(op: VariantOperator, a: &Variant, b: &Variant) -> Result<Variant, InvalidOp> {
+        unsafe {
+            let mut ret = Variant::new();
+            let mut valid = false;
+            (get_api().godot_variant_evaluate)(op.to_sys(), &a.0, &b.0, &mut ret.0, &mut valid);
+
+            if valid {
+                Ok(ret)
+            } else {
+                Err(InvalidOp {
+                    op,
+                    left: a.get_type(),
+                    right: b.get_type(),
+                })
+            }
+        }
+    }
+
     pub(crate) fn cast_ref<'l>(ptr: *const sys::godot_variant) -> &'l Variant {
         unsafe { transmute(ptr) }
     }
@@ -603,6 +669,78 @@ impl Default for Variant {
     }
 }
 
+/// Compares variants with the comparison operators of the engine. Returns `None` if they
+/// can't be compared, e.g. when the types are different. Types that only support `==`, like
+/// `Color` or `Dictionary`, are ordered only when they are equal.
+impl PartialOrd for Variant {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+
+        let test = |op, a, b| Variant::evaluate(op, a, b).ok()?.try_to_bool();
+
+        // Equality is tested first, since `<` isn't defined for all types that have `==`.
+        if test(VariantOperator::Equal, self, other)? {
+            Some(Ordering::Equal)
+        } else if test(VariantOperator::Less, self, other)? {
+            Some(Ordering::Less)
+        } else if test(VariantOperator::Less, other, self)? {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_variant_binary_ops {
+    ($($Trait:ident, $method:ident => $op:ident;)*) => {
+        $(
+            /// Evaluates the operator with `Variant::evaluate`.
+            impl<'a> std::ops::$Trait<&'a Variant> for &'a Variant {
+                type Output = Result<Variant, InvalidOp>;
+
+                fn $method(self, rhs: &'a Variant) -> Self::Output {
+                    Variant::evaluate(VariantOperator::$op, self, rhs)
+                }
+            }
+
+            /// Evaluates the operator with `Variant::evaluate`.
+            impl std::ops::$Trait<Variant> for Variant {
+                type Output = Result<Variant, InvalidOp>;
+
+                fn $method(self, rhs: Variant) -> Self::Output {
+                    Variant::evaluate(VariantOperator::$op, &self, &rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_variant_binary_ops! {
+    Add, add => Add;
+    Sub, sub => Subtact;
+    Mul, mul => Multiply;
+    Div, div => Divide;
+    Rem, rem => Module;
+}
+
+/// Evaluates `VariantOperator::Negate` with `Variant::evaluate`.
+impl std::ops::Neg for &Variant {
+    type Output = Result<Variant, InvalidOp>;
+
+    fn neg(self) -> Self::Output {
+        Variant::evaluate(VariantOperator::Negate, self, &Variant::new())
+    }
+}
+
+/// Evaluates `VariantOperator::Negate` with `Variant::evaluate`.
+impl std::ops::Neg for Variant {
+    type Output = Result<Variant, InvalidOp>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
 impl fmt::Debug for Variant {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         write!(f, "{:?}({})", self.get_type(), self.to_string())
@@ -737,6 +875,48 @@ godot_test!(
         assert!(v_false.try_to_array().is_none());
 
     }
+
+    test_variant_evaluate {
+        use std::cmp::Ordering;
+
+        let sum = (Variant::from_i64(1) + Variant::from_f64(0.5)).unwrap();
+        assert_eq!(Some(1.5), sum.try_to_f64());
+
+        let quotient = (Variant::from_i64(7) / Variant::from_i64(2)).unwrap();
+        assert_eq!(Some(3), quotient.try_to_i64());
+
+        let err = (Variant::from_i64(1) / Variant::from_i64(0)).unwrap_err();
+        assert_eq!(VariantOperator::Divide, err.op);
+        assert!((Variant::from_i64(1) + Variant::from_str("a")).is_err());
+
+        let scaled = (Variant::from_vector2(&Vector2::new(1.0, 2.0)) * Variant::from_i64(2)).unwrap();
+        assert_eq!(Some(Vector2::new(2.0, 4.0)), scaled.try_to_vector2());
+
+        let negated = (-Variant::from_vector3(&Vector3::new(1.0, -2.0, 3.0))).unwrap();
+        assert_eq!(Some(Vector3::new(-1.0, 2.0, -3.0)), negated.try_to_vector3());
+
+        let concat = (Variant::from_str("foo") + Variant::from_str("bar")).unwrap();
+        assert_eq!(Some("foobar".to_string()), concat.try_to_string());
+
+        let array = (vec![1i64].to_variant() + vec![2i64].to_variant()).unwrap();
+        assert_eq!(Ok(vec![1i64, 2]), Vec::<i64>::from_variant(&array));
+
+        let contains = Variant::evaluate(VariantOperator::In, &Variant::from_i64(2), &array).unwrap();
+        assert_eq!(Some(true), contains.try_to_bool());
+
+        assert_eq!(Some(Ordering::Less), Variant::from_i64(1).partial_cmp(&Variant::from_f64(1.5)));
+        assert_eq!(Some(Ordering::Equal), Variant::from_str("a").partial_cmp(&Variant::from_str("a")));
+        assert!(Variant::from_str("b") > Variant::from_str("a"));
+        assert_eq!(None, Variant::from_i64(1).partial_cmp(&Variant::from_str("a")));
+
+        let red = Variant::from_color(&Color::rgb(1.0, 0.0, 0.0));
+        let blue = Variant::from_color(&Color::rgb(0.0, 0.0, 1.0));
+        assert_eq!(Some(Ordering::Equal), red.partial_cmp(&red.clone()));
+        assert_eq!(None, red.partial_cmp(&blue));
+
+        let dict = Dictionary::new().to_variant();
+        assert_eq!(Some(Ordering::Equal), dict.partial_cmp(&dict.clone()));
+    }
 );
 
 /// Types that can be converted to a `Variant`.
@@ -748,8 +928,12 @@ godot_test!(
 /// - `Option<T>` is unwrapped to inner value, or `Nil` if `None`
 /// - `Result<T, E>` is represented as an externally tagged `Dictionary` (see below).
 /// - `PhantomData<T>` is represented as `Nil`.
-/// - `&[T]` and `Vec<T>` are represented as `VariantArray`s. `FromVariant` is only implemented
-/// for `Vec<T>`.
+/// - `Box<T>`, `Rc<T>` and `Arc<T>` are represented as the inner value.
+/// - `&[T]`, `Vec<T>` and `[T; N]` are represented as `VariantArray`s, or as the matching pool
+///   array if `T` is `u8`, `i32`, `f32`, `GodotString`, `String`, `Vector2`, `Vector3` or
+///   `Color`. `FromVariant` is only implemented for `Vec<T>` and `[T; N]`.
+/// - `VecDeque<T>`, `HashSet<T>` and `BTreeSet<T>` are represented as `VariantArray`s.
+/// - `HashMap<K, V>` and `BTreeMap<K, V>` are represented as `Dictionary`s.
 ///
 /// ## Deriving `ToVariant`
 ///
@@ -757,13 +941,49 @@ godot_test!(
 ///
 /// - `Newtype(inner)` is unwrapped to `inner`
 /// - `Tuple(a, b, c)` is represented as a `VariantArray` (`[a, b, c]`)
-/// - `Struct { a, b, c }` is represented as a `Dictionary` (`{ "a": a, "b": b, "c": c }`)
+/// - `Struct { a, b, c }` is represented as a `Dictionary` (`{ "a": a, "b": b, "c": c }`).
+///   Fields missing from the dictionary are converted from `Nil`, so they are only allowed for
+///   types like `Option<T>`, unless a `default` is given.
 /// - `Unit` is represented as an empty `Dictionary` (`{}`)
 /// - `Enum::Variant(a, b, c)` is represented as an externally tagged `Dictionary`
 ///   (`{ "Variant": [a, b, c] }`)
 ///
 /// Behavior of the derive macros can be customized using attributes:
 ///
+/// ### Container attributes
+///
+/// - `#[variant(rename_all = "camelCase")]`
+///
+/// Rename all the fields of a struct, or all the variants of an enum, according to the given
+/// case convention. The possible values are `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`,
+/// `"camelCase"`, `"snake_case"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` and
+/// `"SCREAMING-KEBAB-CASE"`.
+///
+/// The following attributes change the representation of enums:
+///
+/// - `#[variant(tag = "type")]`
+///
+/// Represent the enum as an internally tagged `Dictionary`, where the fields of the variant
+/// are stored next to the variant name: `{ "type": "Variant", "a": a, "b": b }`. Only unit
+/// and struct variants are supported.
+///
+/// - `#[variant(tag = "t", content = "c")]`
+///
+/// Represent the enum as an adjacently tagged `Dictionary`: `{ "t": "Variant", "c": [a, b] }`.
+/// The content is omitted for unit variants.
+///
+/// - `#[variant(untagged)]`
+///
+/// Represent the enum as the value of the variant, without any tag. Unit variants are
+/// represented as `Nil`. When converting from a `Variant`, each variant is tried in order,
+/// and the first one that matches is returned.
+///
+/// - `#[variant(as_int)]`
+///
+/// Represent a fieldless enum as its discriminant, as an integer. `ToVariant` also implements
+/// `Export` for such enums, so they can be used as properties, with an `EnumHint` generated
+/// from the variant names.
+///
 /// ### Field attributes
 ///
 /// - `#[variant(to_variant_with = "path::to::func")]`
@@ -781,8 +1001,43 @@ godot_test!(
 ///
 /// Convenience attribute that sets `to_variant_with` to `path::to::mod::to_variant` and
 /// `from_variant_with` to `path::to::mod::from_variant`.
+///
+/// - `#[variant(rename = "name")]`
+///
+/// Use the given name as the dictionary key of the field, instead of its Rust name.
+///
+/// - `#[variant(skip)]`, `#[variant(skip_to_variant)]`, `#[variant(skip_from_variant)]`
+///
+/// Skip the field when converting to and/or from a `Variant`. Fields skipped when converting
+/// from a `Variant` are initialized with `Default::default()`, or with the function given to
+/// `default`. In tuples, skipped fields don't take up a position in the array.
+///
+/// - `#[variant(default)]`, `#[variant(default = "path::to::func")]`
+///
+/// Use `Default::default()`, or the given function with the signature `fn() -> T`, for the
+/// field when its key is missing from the dictionary.
+///
+/// - `#[variant(flatten)]`
+///
+/// Merge the entries of the field, which should be represented as a `Dictionary`, into the
+/// dictionary of the containing struct. When converting from a `Variant`, the field is
+/// converted from the dictionary of the containing struct as a whole.
+///
+/// Values of the field that aren't represented as a `Dictionary`, like `None` for an
+/// `Option`, can't be merged, and are stored under the name of the field instead. They are
+/// read back from that key if the conversion from the whole dictionary fails.
 pub trait ToVariant {
     fn to_variant(&self) -> Variant;
+
+    /// Converts a slice of `Self` to a `Variant`. Overridden by the element types of pool
+    /// arrays, so that slices of them are converted to pool arrays instead of `VariantArray`s.
+    #[doc(hidden)]
+    fn slice_to_variant(slice: &[Self]) -> Variant
+    where
+        Self: Sized,
+    {
+        slice.iter().collect::<VariantArray>().to_variant()
+    }
 }
 
 /// Types that can be converted from a `Variant`.
@@ -792,18 +1047,43 @@ pub trait ToVariant {
 /// `Option<T>` requires the Variant to be `T` or `Nil`, in that order. For looser semantics,
 /// use `MaybeNot<T>`, which will catch all variant values that are not `T` as well.
 ///
-/// ## `Vec<T>`
+/// ## `Vec<T>` and other collections
 ///
 /// The `FromVariant` implementation for `Vec<T>` only allow homogeneous arrays. If you want to
 /// manually handle potentially heterogeneous values e.g. for error reporting, use `VariantArray`
 /// directly or compose with an appropriate wrapper: `Vec<Option<T>>` or `Vec<MaybeNot<T>>`.
 ///
+/// Sequences can be converted from both `VariantArray`s and the matching pool arrays. Invalid
+/// elements are reported with `FromVariantError::InvalidItem`, using the index of the element
+/// in the array, or the index of the entry in the dictionary for maps.
+///
 /// ## Deriving `FromVariant`
 ///
 /// The derive macro provides implementation consistent with derived `ToVariant`. See `ToVariant`
 /// for detailed documentation.
 pub trait FromVariant: Sized {
     fn from_variant(variant: &Variant) -> Result<Self, FromVariantError>;
+
+    /// Converts a `Variant` to a `Vec` of `Self`. Overridden by the element types of pool
+    /// arrays, so that they can also be converted from pool arrays.
+    #[doc(hidden)]
+    fn vec_from_variant(variant: &Variant) -> Result<Vec<Self>, FromVariantError> {
+        vec_from_variant_array(variant)
+    }
+}
+
+/// Converts a `VariantArray` to a `Vec`, reporting the index of the invalid elements.
+fn vec_from_variant_array<T: FromVariant>(variant: &Variant) -> Result<Vec<T>, FromVariantError> {
+    let arr = VariantArray::from_variant(variant)?;
+    arr.iter()
+        .enumerate()
+        .map(|(index, item)| {
+            T::from_variant(item).map_err(|e| FromVariantError::InvalidItem {
+                index,
+                error: Box::new(e),
+            })
+        })
+        .collect()
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -877,6 +1157,10 @@ pub enum FromVariantError {
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum VariantEnumRepr {
     ExternallyTagged,
+    InternallyTagged,
+    AdjacentlyTagged,
+    Untagged,
+    Int,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -1048,41 +1332,70 @@ impl ToVariant for f64 {
     }
 }
 
+/// Converts slices of pool array element types to the matching pool array.
+macro_rules! pool_element_to_variant {
+    () => {};
+    ($pool:ident) => {
+        fn slice_to_variant(slice: &[Self]) -> Variant {
+            $pool::from_slice(slice).to_variant()
+        }
+    };
+}
+
+/// Converts the matching pool array to `Vec`s of pool array element types, in addition to
+/// `VariantArray`s.
+macro_rules! pool_element_from_variant {
+    () => {};
+    ($pool:ident) => {
+        fn vec_from_variant(variant: &Variant) -> Result<Vec<Self>, FromVariantError> {
+            if variant.get_type() == VariantType::$pool {
+                $pool::from_variant(variant).map(|array| array.to_vec())
+            } else {
+                vec_from_variant_array(variant)
+            }
+        }
+    };
+}
+
 macro_rules! impl_to_variant_for_num {
     (
-        $($ty:ty : $src_ty:ty)*
+        $($ty:ty : $src_ty:ty $(as $pool:ident)?,)*
     ) => {
         $(
             impl ToVariant for $ty {
                 fn to_variant(&self) -> Variant {
                     ((*self) as $src_ty).to_variant()
                 }
+
+                pool_element_to_variant!($($pool)?);
             }
 
             impl FromVariant for $ty {
                 fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
                     <$src_ty>::from_variant(variant).map(|i| i as Self)
                 }
+
+                pool_element_from_variant!($($pool)?);
             }
         )*
     };
 }
 
 impl_to_variant_for_num!(
-    i8: i64
-    i16: i64
-    i32: i64
-    isize: i64
-    u8: u64
-    u16: u64
-    u32: u64
-    usize: u64
-    f32: f64
+    i8: i64,
+    i16: i64,
+    i32: i64 as Int32Array,
+    isize: i64,
+    u8: u64 as ByteArray,
+    u16: u64,
+    u32: u64,
+    usize: u64,
+    f32: f64 as Float32Array,
 );
 
 macro_rules! to_variant_transmute {
     (
-        $(impl ToVariant for $ty:ident: $ctor:ident;)*
+        $(impl ToVariant for $ty:ident: $ctor:ident $(as $pool:ident)?;)*
     ) => {
         $(
             impl ToVariant for $ty {
@@ -1094,14 +1407,16 @@ macro_rules! to_variant_transmute {
                         Variant::from_sys(dest)
                     }
                 }
+
+                pool_element_to_variant!($($pool)?);
             }
         )*
     }
 }
 
 to_variant_transmute! {
-    impl ToVariant for Vector2 : godot_variant_new_vector2;
-    impl ToVariant for Vector3 : godot_variant_new_vector3;
+    impl ToVariant for Vector2 : godot_variant_new_vector2 as Vector2Array;
+    impl ToVariant for Vector3 : godot_variant_new_vector3 as Vector3Array;
     impl ToVariant for Quat : godot_variant_new_quat;
     impl ToVariant for Rect2 : godot_variant_new_rect2;
     impl ToVariant for Transform2D : godot_variant_new_transform2d;
@@ -1109,7 +1424,7 @@ to_variant_transmute! {
 
 macro_rules! to_variant_as_sys {
     (
-        $(impl ToVariant for $ty:ident: $ctor:ident;)*
+        $(impl ToVariant for $ty:ident: $ctor:ident $(as $pool:ident)?;)*
     ) => {
         $(
             impl ToVariant for $ty {
@@ -1121,6 +1436,8 @@ macro_rules! to_variant_as_sys {
                         Variant::from_sys(dest)
                     }
                 }
+
+                pool_element_to_variant!($($pool)?);
             }
         )*
     }
@@ -1130,11 +1447,11 @@ to_variant_as_sys! {
     impl ToVariant for Plane : godot_variant_new_plane;
     impl ToVariant for Transform : godot_variant_new_transform;
     impl ToVariant for Basis : godot_variant_new_basis;
-    impl ToVariant for Color : godot_variant_new_color;
+    impl ToVariant for Color : godot_variant_new_color as ColorArray;
     impl ToVariant for Aabb : godot_variant_new_aabb;
     impl ToVariant for Rid : godot_variant_new_rid;
     impl ToVariant for NodePath : godot_variant_new_node_path;
-    impl ToVariant for GodotString : godot_variant_new_string;
+    impl ToVariant for GodotString : godot_variant_new_string as StringArray;
     impl ToVariant for VariantArray : godot_variant_new_array;
     impl ToVariant for ByteArray : godot_variant_new_pool_byte_array;
     impl ToVariant for Int32Array : godot_variant_new_pool_int_array;
@@ -1149,7 +1466,7 @@ to_variant_as_sys! {
 macro_rules! from_variant_transmute {
     (
         $(
-            impl FromVariant for $TryType:ident : $try_gd_method:ident;
+            impl FromVariant for $TryType:ident : $try_gd_method:ident $(as $pool:ident)?;
         )*
     ) => (
         $(
@@ -1161,14 +1478,16 @@ macro_rules! from_variant_transmute {
                             .map(|v| transmute(v))
                     }
                 }
+
+                pool_element_from_variant!($($pool)?);
             }
         )*
     );
 }
 
 from_variant_transmute!(
-    impl FromVariant for Vector2 : godot_variant_as_vector2;
-    impl FromVariant for Vector3 : godot_variant_as_vector3;
+    impl FromVariant for Vector2 : godot_variant_as_vector2 as Vector2Array;
+    impl FromVariant for Vector3 : godot_variant_as_vector3 as Vector3Array;
     impl FromVariant for Quat : godot_variant_as_quat;
     impl FromVariant for Rect2 : godot_variant_as_rect2;
     impl FromVariant for Transform2D : godot_variant_as_transform2d;
@@ -1177,7 +1496,7 @@ from_variant_transmute!(
 macro_rules! from_variant_from_sys {
     (
         $(
-            impl FromVariant for $TryType:ident : $try_gd_method:ident;
+            impl FromVariant for $TryType:ident : $try_gd_method:ident $(as $pool:ident)?;
         )*
     ) => (
         $(
@@ -1189,6 +1508,8 @@ macro_rules! from_variant_from_sys {
                             .map($TryType::from_sys)
                     }
                 }
+
+                pool_element_from_variant!($($pool)?);
             }
         )*
     );
@@ -1198,10 +1519,10 @@ from_variant_from_sys!(
     impl FromVariant for Plane : godot_variant_as_plane;
     impl FromVariant for Transform : godot_variant_as_transform;
     impl FromVariant for Basis : godot_variant_as_basis;
-    impl FromVariant for Color : godot_variant_as_color;
+    impl FromVariant for Color : godot_variant_as_color as ColorArray;
     impl FromVariant for Aabb : godot_variant_as_aabb;
     impl FromVariant for NodePath : godot_variant_as_node_path;
-    impl FromVariant for GodotString : godot_variant_as_string;
+    impl FromVariant for GodotString : godot_variant_as_string as StringArray;
     impl FromVariant for Rid : godot_variant_as_rid;
     impl FromVariant for VariantArray : godot_variant_as_array;
     impl FromVariant for ByteArray : godot_variant_as_pool_byte_array;
@@ -1224,12 +1545,25 @@ impl ToVariant for String {
     fn to_variant(&self) -> Variant {
         Variant::from_str(&self)
     }
+
+    fn slice_to_variant(slice: &[Self]) -> Variant {
+        slice
+            .iter()
+            .map(GodotString::from_str)
+            .collect::<StringArray>()
+            .to_variant()
+    }
 }
 
 impl FromVariant for String {
     fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
         GodotString::from_variant(variant).map(|s| s.to_string())
     }
+
+    fn vec_from_variant(variant: &Variant) -> Result<Vec<Self>, FromVariantError> {
+        GodotString::vec_from_variant(variant)
+            .map(|strings| strings.iter().map(GodotString::to_string).collect())
+    }
 }
 
 impl ToVariant for bool {
@@ -1390,43 +1724,147 @@ impl<T: FromVariant, E: FromVariant> FromVariant for Result<T, E> {
 
 impl<T: ToVariant> ToVariant for &[T] {
     fn to_variant(&self) -> Variant {
-        let mut array = VariantArray::new();
-        for val in self.iter() {
-            // there is no real way to avoid CoW allocations right now, as ptrw isn't exposed
-            array.push(&val.to_variant());
-        }
-        array.to_variant()
+        T::slice_to_variant(self)
     }
 }
 
 impl<T: ToVariant> ToVariant for Vec<T> {
     fn to_variant(&self) -> Variant {
-        self.as_slice().to_variant()
+        T::slice_to_variant(self)
     }
 }
 
 impl<T: FromVariant> FromVariant for Vec<T> {
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        T::vec_from_variant(variant)
+    }
+}
+
+impl<T: ToVariant, const N: usize> ToVariant for [T; N] {
+    fn to_variant(&self) -> Variant {
+        T::slice_to_variant(self)
+    }
+}
+
+impl<T: FromVariant, const N: usize> FromVariant for [T; N] {
     fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
         use std::convert::TryInto;
 
-        let arr = VariantArray::from_variant(variant)?;
-        let len: usize = arr
-            .len()
+        T::vec_from_variant(variant)?
             .try_into()
-            .expect("variant array length should fit in usize");
-        let mut vec = Vec::with_capacity(len);
-        for idx in 0..len as i32 {
-            let item =
-                T::from_variant(arr.get_ref(idx)).map_err(|e| FromVariantError::InvalidItem {
-                    index: idx as usize,
+            .map_err(|vec: Vec<T>| FromVariantError::InvalidLength {
+                len: vec.len(),
+                expected: N,
+            })
+    }
+}
+
+macro_rules! impl_variant_for_sequences {
+    ($($ty:ident<T $(: $bound:ident $(+ $bounds:ident)*)?>,)*) => {
+        $(
+            impl<T: ToVariant> ToVariant for $ty<T> {
+                fn to_variant(&self) -> Variant {
+                    self.iter().collect::<VariantArray>().to_variant()
+                }
+            }
+
+            impl<T: FromVariant $(+ $bound $(+ $bounds)*)?> FromVariant for $ty<T> {
+                fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+                    T::vec_from_variant(variant).map(|vec| vec.into_iter().collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_variant_for_sequences!(VecDeque<T>, BTreeSet<T: Ord>,);
+
+impl<T: ToVariant, S> ToVariant for HashSet<T, S> {
+    fn to_variant(&self) -> Variant {
+        self.iter().collect::<VariantArray>().to_variant()
+    }
+}
+
+impl<T, S> FromVariant for HashSet<T, S>
+where
+    T: FromVariant + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        T::vec_from_variant(variant).map(|vec| vec.into_iter().collect())
+    }
+}
+
+/// Converts a `Dictionary` to a map, reporting the index of the invalid entries.
+fn map_from_variant<K, V, M>(variant: &Variant) -> Result<M, FromVariantError>
+where
+    K: FromVariant,
+    V: FromVariant,
+    M: FromIterator<(K, V)>,
+{
+    let dict = Dictionary::from_variant(variant)?;
+    dict.iter()
+        .enumerate()
+        .map(|(index, (key, value))| {
+            K::from_variant(key)
+                .and_then(|key| V::from_variant(value).map(|value| (key, value)))
+                .map_err(|e| FromVariantError::InvalidItem {
+                    index,
                     error: Box::new(e),
-                })?;
-            vec.push(item);
-        }
-        Ok(vec)
+                })
+        })
+        .collect()
+}
+
+impl<K: ToVariant, V: ToVariant, S> ToVariant for HashMap<K, V, S> {
+    fn to_variant(&self) -> Variant {
+        self.iter().collect::<Dictionary>().to_variant()
+    }
+}
+
+impl<K, V, S> FromVariant for HashMap<K, V, S>
+where
+    K: FromVariant + Eq + Hash,
+    V: FromVariant,
+    S: BuildHasher + Default,
+{
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        map_from_variant(variant)
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> ToVariant for BTreeMap<K, V> {
+    fn to_variant(&self) -> Variant {
+        self.iter().collect::<Dictionary>().to_variant()
+    }
+}
+
+impl<K: FromVariant + Ord, V: FromVariant> FromVariant for BTreeMap<K, V> {
+    fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+        map_from_variant(variant)
     }
 }
 
+macro_rules! impl_variant_for_pointers {
+    ($($ty:ident,)*) => {
+        $(
+            impl<T: ToVariant + ?Sized> ToVariant for $ty<T> {
+                fn to_variant(&self) -> Variant {
+                    T::to_variant(self)
+                }
+            }
+
+            impl<T: FromVariant> FromVariant for $ty<T> {
+                fn from_variant(variant: &Variant) -> Result<Self, FromVariantError> {
+                    T::from_variant(variant).map($ty::new)
+                }
+            }
+        )*
+    };
+}
+
+impl_variant_for_pointers!(Box, Rc, Arc,);
+
 macro_rules! tuple_length {
     () => { 0usize };
     ($_x:ident, $($xs:ident,)*) => {
@@ -1602,4 +2040,66 @@ godot_test!(
         let tuple = <(i64, i64)>::from_variant(&variant);
         assert_eq!(Ok((42, 54)), tuple);
     }
+
+    test_variant_collections {
+        use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+        use std::rc::Rc;
+
+        let bytes = vec![1u8, 2, 3].to_variant();
+        assert_eq!(VariantType::ByteArray, bytes.get_type());
+        assert_eq!(Ok(vec![1u8, 2, 3]), Vec::<u8>::from_variant(&bytes));
+        assert_eq!(VariantType::Int32Array, vec![1i32].to_variant().get_type());
+        assert_eq!(VariantType::Float32Array, [1.0f32, 2.0].to_variant().get_type());
+        assert_eq!(VariantType::StringArray, vec!["a".to_string()].to_variant().get_type());
+        assert_eq!(VariantType::Vector2Array, vec![Vector2::new(1.0, 2.0)].to_variant().get_type());
+        assert_eq!(VariantType::VariantArray, vec![1i64].to_variant().get_type());
+
+        // pool element types can still be read from untyped arrays
+        let untyped = vec![1i64, 2].to_variant();
+        assert_eq!(Ok(vec![1i32, 2]), Vec::<i32>::from_variant(&untyped));
+        assert_eq!(Ok([1u8, 2]), <[u8; 2]>::from_variant(&untyped));
+        assert_eq!(
+            Err(FromVariantError::InvalidLength { len: 2, expected: 3 }),
+            <[u8; 3]>::from_variant(&untyped),
+        );
+
+        let strings = vec!["a".to_string(), "b".to_string()].to_variant();
+        assert_eq!(Ok(vec!["a".to_string(), "b".to_string()]), Vec::<String>::from_variant(&strings));
+
+        let deque: VecDeque<i64> = vec![1, 2].into_iter().collect();
+        assert_eq!(Ok(deque.clone()), VecDeque::<i64>::from_variant(&deque.to_variant()));
+
+        let set: HashSet<i64> = vec![1, 2, 2].into_iter().collect();
+        assert_eq!(2, set.to_variant().to_array().len());
+        assert_eq!(Ok(set.clone()), HashSet::<i64>::from_variant(&set.to_variant()));
+
+        let ordered: BTreeSet<String> = vec!["b".to_string(), "a".to_string()].into_iter().collect();
+        assert_eq!(Ok(ordered.clone()), BTreeSet::<String>::from_variant(&ordered.to_variant()));
+
+        let mut map = HashMap::new();
+        map.insert("hp".to_string(), 100i64);
+        let dict = map.to_variant();
+        assert_eq!(VariantType::Dictionary, dict.get_type());
+        assert_eq!(Ok(map), HashMap::<String, i64>::from_variant(&dict));
+
+        let mut ordered_map = BTreeMap::new();
+        ordered_map.insert(1i64, "one".to_string());
+        ordered_map.insert(2i64, "two".to_string());
+        let dict = ordered_map.to_variant();
+        assert_eq!(Ok(ordered_map), BTreeMap::<i64, String>::from_variant(&dict));
+        assert_eq!(
+            Err(FromVariantError::InvalidItem {
+                index: 0,
+                error: Box::new(FromVariantError::InvalidVariantType {
+                    expected: VariantType::Bool,
+                    variant_type: VariantType::GodotString,
+                }),
+            }),
+            BTreeMap::<i64, bool>::from_variant(&dict),
+        );
+
+        let boxed: Box<i64> = Box::new(42);
+        assert_eq!(Some(42), boxed.to_variant().try_to_i64());
+        assert_eq!(Ok(Rc::new(42i64)), Rc::<i64>::from_variant(&Variant::from_i64(42)));
+    }
 );
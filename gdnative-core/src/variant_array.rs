@@ -1,6 +1,13 @@
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+use std::ops::{Bound, RangeBounds};
+
 use crate::get_api;
 use crate::sys;
 
+use crate::FromVariant;
+use crate::FromVariantError;
+use crate::ToVariant;
 use crate::Variant;
 
 /// A reference-counted `Variant` vector. Godot's generic array data type.
@@ -19,7 +26,7 @@ impl VariantArray {
     }
 
     /// Returns a copy of the element at the given offset.
-    pub fn get_val(&mut self, idx: i32) -> Variant {
+    pub fn get_val(&self, idx: i32) -> Variant {
         unsafe { Variant((get_api().godot_array_get)(&self.0, idx)) }
     }
 
@@ -33,8 +40,49 @@ impl VariantArray {
         unsafe { Variant::cast_mut_ref((get_api().godot_array_operator_index)(&mut self.0, idx)) }
     }
 
-    pub fn count(&mut self, val: &Variant) -> i32 {
-        unsafe { (get_api().godot_array_count)(&mut self.0, &val.0) }
+    /// Returns a reference to the element at `idx`, or `None` if it is out of bounds.
+    pub fn get_checked(&self, idx: usize) -> Option<&Variant> {
+        if idx < self.len() as usize {
+            Some(self.get_ref(idx as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `idx`, or `None` if it is out of bounds.
+    pub fn get_mut_checked(&mut self, idx: usize) -> Option<&mut Variant> {
+        if idx < self.len() as usize {
+            Some(self.get_mut_ref(idx as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the element at `idx`, converted to `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let hp: i64 = array.get(0)?;
+    /// ```
+    pub fn get<T: FromVariant>(&self, idx: usize) -> Result<T, FromVariantError> {
+        match self.get_checked(idx) {
+            Some(val) => T::from_variant(val),
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                idx
+            ),
+        }
+    }
+
+    /// Returns the number of times `val` appears in the array.
+    pub fn count(&self, val: &Variant) -> i32 {
+        unsafe { (get_api().godot_array_count)(&self.0, &val.0) }
     }
 
     /// Clears the array, resizing to 0.
@@ -134,20 +182,65 @@ impl VariantArray {
         unsafe { (get_api().godot_array_sort)(&mut self.0) }
     }
 
-    // TODO
-    // pub fn sort_custom(&mut self, obj: ?, s: ?) {
-    //     unimplemented!()
-    // }
+    /// Sorts the array with a comparator function. The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// array.sort_by(|a, b| a.to_string().len().cmp(&b.to_string().len()));
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&Variant, &Variant) -> Ordering,
+    {
+        let mut elements = self.iter().cloned().collect::<Vec<_>>();
+        elements.sort_by(compare);
+        for (idx, val) in elements.iter().enumerate() {
+            self.set(idx as i32, val);
+        }
+    }
+
+    /// Binary searches a sorted array for `val`, using the same ordering as `sort`.
+    ///
+    /// Returns `Ok` with the index of the first matching element if `val` is found, or `Err`
+    /// with the index where it could be inserted while keeping the array sorted otherwise.
+    pub fn binary_search(&self, val: &Variant) -> Result<usize, usize> {
+        // `godot_array_bsearch` takes a mutable pointer, but doesn't modify the array.
+        let idx =
+            unsafe { (get_api().godot_array_bsearch)(&self.0 as *const _ as *mut _, &val.0, true) };
+
+        match self.get_checked(idx as usize) {
+            Some(found) if found == val => Ok(idx as usize),
+            _ => Err(idx as usize),
+        }
+    }
+
+    /// Returns a copy of the array. If `deep` is `true`, nested arrays and dictionaries are
+    /// copied too, otherwise they are shared with this array.
+    pub fn duplicate(&self, deep: bool) -> VariantArray {
+        unsafe { VariantArray((get_api().godot_array_duplicate)(&self.0, deep)) }
+    }
 
-    // pub fn bsearch(&mut self, val: (), before: bool) -> i32 {
-    //     unsafe {
-    //         (get_api().godot_array_bsearch)(&mut self.0, val, before)
-    //     }
-    // }
+    /// Returns a new array with the elements in `range`. The range is clamped to the bounds
+    /// of the array, and nested arrays and dictionaries are shared with this array.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> VariantArray {
+        let len = self.len() as usize;
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
 
-    // pub fn bsearch_custom(&mut self, val: ?, obj: ?, s: ?, before: bool) -> i32 {
-    //     unimplemented!();
-    // }
+        let end = end.min(len);
+        (start.min(end)..end)
+            .map(|idx| self.get_ref(idx as i32))
+            .collect()
+    }
 
     pub fn iter(&self) -> Iter {
         Iter {
@@ -187,6 +280,53 @@ impl_basic_traits!(
     }
 );
 
+impl<'a> IntoIterator for &'a VariantArray {
+    type Item = &'a Variant;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut VariantArray {
+    type Item = &'a mut Variant;
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl IntoIterator for VariantArray {
+    type Item = Variant;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IntoIter {
+            arr: self,
+            range: 0..len,
+        }
+    }
+}
+
+impl<T: ToVariant> FromIterator<T> for VariantArray {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arr = VariantArray::new();
+        arr.extend(iter);
+        arr
+    }
+}
+
+impl<T: ToVariant> Extend<T> for VariantArray {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(&val.to_variant());
+        }
+    }
+}
+
 pub struct Iter<'a> {
     arr: &'a VariantArray,
     range: std::ops::Range<i32>,
@@ -222,6 +362,23 @@ impl<'a> Iterator for IterMut<'a> {
     }
 }
 
+/// Owning iterator over the elements of a `VariantArray`.
+pub struct IntoIter {
+    arr: VariantArray,
+    range: std::ops::Range<i32>,
+}
+
+impl Iterator for IntoIter {
+    type Item = Variant;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|idx| self.arr.get_val(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
 godot_test!(test_array {
     let foo = Variant::from_str("foo");
     let bar = Variant::from_str("bar");
@@ -304,6 +461,49 @@ godot_test!(test_array {
     );
 });
 
+godot_test!(test_array_iter {
+    let mut array: VariantArray = vec![3, 1, 2].into_iter().collect();
+    array.extend(vec![5, 4]);
+    assert_eq!(5, array.len());
+
+    assert_eq!(Ok(3), array.get::<i64>(0));
+    assert!(array.get::<bool>(0).is_err());
+    assert_eq!(Some(&Variant::from_i64(4)), array.get_checked(4));
+    assert_eq!(None, array.get_checked(5));
+
+    for v in &mut array {
+        *v = Variant::from_i64(v.to_i64() * 10);
+    }
+    assert_eq!(150, (&array).into_iter().map(|v| v.to_i64()).sum::<i64>());
+
+    array.sort_by(|a, b| b.to_i64().cmp(&a.to_i64()));
+    assert_eq!(
+        vec![50, 40, 30, 20, 10],
+        array.new_ref().into_iter().map(|v| v.to_i64()).collect::<Vec<_>>(),
+    );
+
+    array.sort();
+    assert_eq!(Ok(2), array.binary_search(&Variant::from_i64(30)));
+    assert_eq!(Err(3), array.binary_search(&Variant::from_i64(35)));
+    assert_eq!(Err(5), array.binary_search(&Variant::from_i64(60)));
+
+    let slice = array.slice(1..3);
+    assert_eq!(
+        vec![20, 30],
+        slice.iter().map(|v| v.to_i64()).collect::<Vec<_>>(),
+    );
+    assert_eq!(2, array.slice(3..10).len());
+    assert!(array.slice(7..).is_empty());
+
+    let mut nested = VariantArray::new();
+    nested.push(&Variant::from_array(&array));
+    let shallow = nested.duplicate(false);
+    let deep = nested.duplicate(true);
+    array.push(&Variant::from_i64(60));
+    assert_eq!(6, shallow.get_ref(0).to_array().len());
+    assert_eq!(5, deep.get_ref(0).to_array().len());
+});
+
 // TODO: clear arrays without affecting clones
 //godot_test!(test_array_clone_clear {
 //    let foo = Variant::from_str("foo");
@@ -1,3 +1,135 @@
+use crate::{Angle, Basis, Vector3, Vector3Axis};
+
+/// Helper methods for `Vector3`.
+///
+/// Trait used to provide additional methods that are equivalent to Godot's methods.
+/// See the official [`Godot documentation`](https://docs.godotengine.org/en/3.1/classes/class_vector3.html).
+pub trait Vector3Godot {
+    /// Returns the vector "bounced off" from a plane defined by the given normal.
+    fn bounce(self, normal: Self) -> Self;
+    /// Cubicly interpolates between this vector and `b` using `pre_a` and `post_b` as handles,
+    /// and returns the result at position `t`. `t` is in the range of 0.0 - 1.0, representing
+    /// the amount of interpolation.
+    fn cubic_interpolate(self, b: Self, pre_a: Self, post_b: Self, t: f32) -> Self;
+    /// Returns the axis of the vector's largest value. If all components are equal, this
+    /// returns the X axis.
+    fn max_axis(self) -> Vector3Axis;
+    /// Returns the axis of the vector's smallest value. If all components are equal, this
+    /// returns the Z axis.
+    fn min_axis(self) -> Vector3Axis;
+    /// Returns the outer product with `b`.
+    fn outer(self, b: Self) -> Basis;
+    /// Returns the vector reflected from a plane defined by the given normal.
+    ///
+    /// Note that this follows Godot and differs from the inherent `reflect` method of
+    /// euclid's vectors, which is equivalent to `bounce`.
+    fn reflect(self, normal: Self) -> Self;
+    /// Returns the vector rotated around `axis` by `angle`. `axis` must be normalized.
+    fn rotated(self, axis: Self, angle: Angle) -> Self;
+    /// Returns the component of the vector along a plane defined by the given normal.
+    fn slide(self, normal: Self) -> Self;
+    /// Returns the vector snapped to a grid with the given size.
+    fn snapped(self, by: Self) -> Self;
+    /// Returns a diagonal matrix with the vector as its main diagonal.
+    fn to_diagonal_matrix(self) -> Basis;
+}
+
+impl Vector3Godot for Vector3 {
+    #[inline]
+    fn bounce(self, normal: Self) -> Self {
+        -Vector3Godot::reflect(self, normal)
+    }
+
+    #[inline]
+    fn cubic_interpolate(self, b: Self, pre_a: Self, post_b: Self, t: f32) -> Self {
+        let v0 = pre_a;
+        let v1 = self;
+        let v2 = b;
+        let v3 = post_b;
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        ((v1 * 2.0)
+            + (-v0 + v2) * t
+            + (v0 * 2.0 - v1 * 5.0 + v2 * 4.0 - v3) * t2
+            + (-v0 + v1 * 3.0 - v2 * 3.0 + v3) * t3)
+            * 0.5
+    }
+
+    #[inline]
+    fn max_axis(self) -> Vector3Axis {
+        if self.x < self.y {
+            if self.y < self.z {
+                Vector3Axis::Z
+            } else {
+                Vector3Axis::Y
+            }
+        } else if self.x < self.z {
+            Vector3Axis::Z
+        } else {
+            Vector3Axis::X
+        }
+    }
+
+    #[inline]
+    fn min_axis(self) -> Vector3Axis {
+        if self.x < self.y {
+            if self.x < self.z {
+                Vector3Axis::X
+            } else {
+                Vector3Axis::Z
+            }
+        } else if self.y < self.z {
+            Vector3Axis::Y
+        } else {
+            Vector3Axis::Z
+        }
+    }
+
+    #[inline]
+    fn outer(self, b: Self) -> Basis {
+        Basis::from_elements([b * self.x, b * self.y, b * self.z])
+    }
+
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        normal * 2.0 * self.dot(normal) - self
+    }
+
+    #[inline]
+    fn rotated(self, axis: Self, angle: Angle) -> Self {
+        Basis::from_axis_angle(axis, angle.radians).xform(self)
+    }
+
+    #[inline]
+    fn slide(self, normal: Self) -> Self {
+        self - normal * self.dot(normal)
+    }
+
+    #[inline]
+    fn snapped(self, by: Self) -> Self {
+        let stepify = |value: f32, step: f32| {
+            if step != 0.0 {
+                (value / step + 0.5).floor() * step
+            } else {
+                value
+            }
+        };
+
+        Vector3::new(
+            stepify(self.x, by.x),
+            stepify(self.y, by.y),
+            stepify(self.z, by.z),
+        )
+    }
+
+    #[inline]
+    fn to_diagonal_matrix(self) -> Basis {
+        Basis::from_diagonal(self)
+    }
+}
+
 godot_test!(
     test_vector3_variants {
         use crate::{FromVariant, ToVariant, Vector3};
@@ -54,7 +186,8 @@ godot_test!(
 
 #[cfg(test)]
 mod tests {
-    use crate::Vector3;
+    use crate::vector3::Vector3Godot;
+    use crate::{Vector3, Vector3Axis};
 
     #[test]
     fn it_is_copy() {
@@ -77,4 +210,85 @@ mod tests {
     fn it_supports_inequality() {
         assert_ne!(Vector3::new(1.0, 10.0, 100.0), Vector3::new(1.0, 2.0, 3.0));
     }
+
+    #[test]
+    fn bounce_and_reflect_are_sane() {
+        use euclid::approxeq::ApproxEq;
+        use Vector3 as V;
+
+        let normal = V::new(0.0, 1.0, 0.0);
+        let v = V::new(1.0, -2.0, 3.0);
+
+        assert!(V::new(-1.0, -2.0, -3.0).approx_eq(&Vector3Godot::reflect(v, normal)));
+        assert!(V::new(1.0, 2.0, 3.0).approx_eq(&v.bounce(normal)));
+    }
+
+    #[test]
+    fn slide_is_sane() {
+        use euclid::approxeq::ApproxEq;
+        use Vector3 as V;
+
+        let v = V::new(3.0, 4.0, -1.0);
+        let normal = V::new(0.0, 0.0, 1.0);
+        assert!(V::new(3.0, 4.0, 0.0).approx_eq(&v.slide(normal)));
+    }
+
+    #[test]
+    fn snapped_is_sane() {
+        use euclid::approxeq::ApproxEq;
+        use Vector3 as V;
+
+        let v = V::new(1.5, 5.6, -6.8);
+        assert!(V::new(2.0, 4.0, -6.9).approx_eq(&v.snapped(V::new(1.0, 4.0, 0.3))));
+        assert!(v.approx_eq(&v.snapped(V::zero())));
+    }
+
+    #[test]
+    fn cubic_interpolate_is_sane() {
+        use euclid::approxeq::ApproxEq;
+        use Vector3 as V;
+
+        let a = V::new(5.4, -6.8, 1.0);
+        let b = V::new(-1.2, 0.8, 2.0);
+        let pre_a = V::new(1.2, 10.3, 0.0);
+        let post_b = V::new(-5.4, 4.2, 3.0);
+
+        let eps = V::new(1e-4, 1e-4, 1e-4);
+
+        assert!(a.approx_eq_eps(&a.cubic_interpolate(b, pre_a, post_b, 0.0), &eps));
+        assert!(b.approx_eq_eps(&a.cubic_interpolate(b, pre_a, post_b, 1.0), &eps));
+        assert!(V::new(4.7328, -6.7936, 1.2)
+            .approx_eq_eps(&a.cubic_interpolate(b, pre_a, post_b, 0.2), &eps));
+    }
+
+    #[test]
+    fn rotated_is_sane() {
+        use crate::Angle;
+        use euclid::approxeq::ApproxEq;
+        use Vector3 as V;
+
+        let v = V::new(1.0, 0.0, 0.0).rotated(V::new(0.0, 1.0, 0.0), Angle::degrees(90.0));
+        assert!(V::new(0.0, 0.0, -1.0).approx_eq(&v));
+    }
+
+    #[test]
+    fn outer_is_sane() {
+        let basis = Vector3::new(1.0, 2.0, 3.0).outer(Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(Vector3::new(8.0, 10.0, 12.0), basis.elements[1]);
+        assert_eq!(Vector3::new(6.0, 12.0, 18.0), basis.get_axis(2));
+    }
+
+    #[test]
+    fn to_diagonal_matrix_is_sane() {
+        let basis = Vector3::new(1.0, 2.0, 3.0).to_diagonal_matrix();
+        assert_eq!(Vector3::new(0.0, 2.0, 0.0), basis.elements[1]);
+    }
+
+    #[test]
+    fn min_and_max_axis_are_sane() {
+        assert_eq!(Vector3Axis::Y, Vector3::new(1.0, 3.0, 2.0).max_axis());
+        assert_eq!(Vector3Axis::X, Vector3::new(1.0, 1.0, 1.0).max_axis());
+        assert_eq!(Vector3Axis::Z, Vector3::new(1.0, 3.0, -2.0).min_axis());
+        assert_eq!(Vector3Axis::Z, Vector3::new(1.0, 1.0, 1.0).min_axis());
+    }
 }
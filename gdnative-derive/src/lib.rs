@@ -9,6 +9,7 @@ use proc_macro::TokenStream;
 
 mod methods;
 mod native_script;
+mod signal;
 mod variant;
 
 #[proc_macro_attribute]
@@ -16,6 +17,28 @@ pub fn methods(meta: TokenStream, input: TokenStream) -> TokenStream {
     methods::derive_methods(meta, input)
 }
 
+/// Derives `NativeClass` for a struct.
+///
+/// Fields marked with `#[property]` are registered as properties. The attribute accepts:
+///
+/// - `path = "group/name"`: the name of the property, defaulting to the field name.
+/// - `default = value`: the default value shown in the editor.
+/// - `hint(...)`: an editor hint, one of `range(min = 0, max = 10, step = 1, or_greater,
+///   or_lesser)`, `enum("A", "B")`, `flags("A", "B")`, `file("*.png")`,
+///   `global_file("*.png")`, `dir`, `global_dir`, `multiline`, `placeholder = "text"`,
+///   `color_no_alpha` or `exp_easing(attenuation, inout)`. Numbers in ranges can be given
+///   as strings, e.g. `min = "-10"`.
+/// - `usage = "STORAGE | NETWORK"`: the `PropertyUsage` flags.
+/// - `get = "Self::get_foo"` and `set = "Self::set_foo"`: custom accessors with the signatures
+///   `fn(&self, owner: Base) -> T` and `fn(&mut self, owner: Base, value: T)`, replacing the
+///   direct field access.
+/// - `group = "Movement"`: the inspector group the property is shown in.
+///
+/// Since the inspector puts every property registered after a group marker in that group,
+/// grouped properties are registered last, which can differ from the order of the fields.
+/// Properties without a group are registered first, followed by the properties registered by
+/// the `#[register_with]` function, if any, and then the properties of each group in the order
+/// the groups first appear.
 #[proc_macro_derive(
     NativeClass,
     attributes(inherit, export, user_data, property, register_with)
@@ -33,3 +56,8 @@ pub fn derive_to_variant(input: TokenStream) -> TokenStream {
 pub fn derive_from_variant(input: TokenStream) -> TokenStream {
     variant::derive_from_variant(input)
 }
+
+#[proc_macro_derive(GodotSignal, attributes(signal))]
+pub fn derive_godot_signal(input: TokenStream) -> TokenStream {
+    signal::derive_godot_signal(input)
+}
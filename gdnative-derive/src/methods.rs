@@ -1,4 +1,9 @@
-use syn::{FnArg, ImplItem, ItemImpl, Pat, PatIdent, Signature, Type};
+use syn::parse::{ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Expr, FnArg, ImplItem, ItemImpl, Lit, Meta, MetaList, NestedMeta, Pat, PatIdent,
+    Signature, Type,
+};
 
 use proc_macro::TokenStream;
 use std::boxed::Box;
@@ -6,11 +11,130 @@ use syn::export::Span;
 
 pub(crate) struct ClassMethodExport {
     pub(crate) class_ty: Box<Type>,
-    pub(crate) methods: Vec<Signature>,
+    pub(crate) methods: Vec<ExportMethod>,
+    pub(crate) errors: Vec<syn::Error>,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) enum RpcMode {
+    #[default]
+    Disabled,
+    Remote,
+    RemoteSync,
+    Master,
+    Puppet,
+    MasterSync,
+    PuppetSync,
+}
+
+impl RpcMode {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "remote" => Some(RpcMode::Remote),
+            "remotesync" | "sync" => Some(RpcMode::RemoteSync),
+            "master" => Some(RpcMode::Master),
+            "puppet" | "slave" => Some(RpcMode::Puppet),
+            "mastersync" => Some(RpcMode::MasterSync),
+            "puppetsync" => Some(RpcMode::PuppetSync),
+            "disabled" => Some(RpcMode::Disabled),
+            _ => None,
+        }
+    }
+}
+
+impl quote::ToTokens for RpcMode {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let variant = match self {
+            RpcMode::Disabled => quote!(Disabled),
+            RpcMode::Remote => quote!(Remote),
+            RpcMode::RemoteSync => quote!(RemoteSync),
+            RpcMode::Master => quote!(Master),
+            RpcMode::Puppet => quote!(Puppet),
+            RpcMode::MasterSync => quote!(MasterSync),
+            RpcMode::PuppetSync => quote!(PuppetSync),
+        };
+        tokens.extend(quote!(gdnative::init::RpcMode::#variant));
+    }
+}
+
+pub(crate) struct ExportMethod {
+    pub(crate) sig: Signature,
+    pub(crate) args: ExportArgs,
+    /// Default values of the optional parameters, one for each input of `sig`.
+    pub(crate) defaults: Vec<Option<Expr>>,
+}
+
+#[derive(Default)]
+pub(crate) struct ExportArgs {
+    pub(crate) rpc_mode: RpcMode,
+}
+
+impl ExportArgs {
+    /// Parses the arguments of an `#[export]` attribute, e.g. `#[export(rpc = "remotesync")]`.
+    fn from_attr(attr: &syn::Attribute) -> Result<Self, syn::Error> {
+        let mut args = ExportArgs::default();
+
+        let nested = match attr.parse_meta()? {
+            Meta::Path(_) => return Ok(args),
+            Meta::List(MetaList { nested, .. }) => nested,
+            meta @ Meta::NameValue(_) => {
+                return Err(syn::Error::new(
+                    meta.span(),
+                    "unexpected syntax, expected `#[export]` or `#[export(key = value)]`",
+                ))
+            }
+        };
+
+        for arg in nested {
+            let pair = match arg {
+                NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                _ => {
+                    return Err(syn::Error::new(
+                        arg.span(),
+                        "unexpected argument, expected `key = value`",
+                    ))
+                }
+            };
+
+            let name = pair
+                .path
+                .get_ident()
+                .ok_or_else(|| syn::Error::new(pair.path.span(), "key should be single ident"))?
+                .to_string();
+
+            match name.as_str() {
+                "rpc" => {
+                    let value = if let Lit::Str(lit_str) = &pair.lit {
+                        lit_str.value()
+                    } else {
+                        return Err(syn::Error::new(
+                            pair.lit.span(),
+                            "rpc value should be a string literal",
+                        ));
+                    };
+
+                    args.rpc_mode = RpcMode::parse(&value).ok_or_else(|| {
+                        syn::Error::new(pair.lit.span(), format!("unknown rpc mode: {}", value))
+                    })?;
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        pair.path.span(),
+                        format!("unknown argument: {}", name),
+                    ))
+                }
+            }
+        }
+
+        Ok(args)
+    }
 }
 
 pub(crate) fn derive_methods(meta: TokenStream, input: TokenStream) -> TokenStream {
-    let (impl_block, export) = parse_method_export(meta, input);
+    let (impl_block, export) = match parse_method_export(meta, input) {
+        Ok(parsed) => parsed,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let output = {
         let class_name = export.class_ty;
@@ -18,24 +142,48 @@ pub(crate) fn derive_methods(meta: TokenStream, input: TokenStream) -> TokenStre
         let methods = export
             .methods
             .into_iter()
-            .map(|m| {
-                let name = m.ident.clone().to_string();
+            .map(|method| {
+                let ExportMethod {
+                    sig,
+                    args,
+                    defaults,
+                } = method;
+                let name = sig.ident.clone().to_string();
+                let rpc_mode = args.rpc_mode;
+
+                let asyncness = &sig.asyncness;
+                let ident = &sig.ident;
+                let output = &sig.output;
+                let checks = method_checks(&class_name, &sig);
+                let inputs = wrap_method_inputs(&sig, defaults);
+
+                let wrap_method = if sig.asyncness.is_some() {
+                    quote!(gdnative::godot_wrap_async_method!)
+                } else {
+                    quote!(gdnative::godot_wrap_method!)
+                };
 
                 quote!(
                     {
-                        let method = gdnative::godot_wrap_method!(
+                        #checks
+
+                        let method = #wrap_method(
                             #class_name,
-                            #m
+                            #asyncness fn #ident(#(#inputs),*) #output
                         );
 
-                        builder.add_method(#name, method);
+                        builder.add_method_with_rpc_mode(#name, method, #rpc_mode);
                     }
                 )
             })
             .collect::<Vec<_>>();
 
+        let errors = export.errors.iter().map(syn::Error::to_compile_error);
+
         quote::quote!(
 
+            #(#errors)*
+
             #impl_block
 
             impl gdnative::NativeClassMethods for #class_name {
@@ -54,19 +202,75 @@ pub(crate) fn derive_methods(meta: TokenStream, input: TokenStream) -> TokenStre
     TokenStream::from(output)
 }
 
+/// Returns statements checking the types of the parameters of an exported method, so that
+/// type errors are reported at the parameters rather than inside the wrapper macros.
+///
+/// The owner must be the `Base` type of the class, and the other parameters must implement
+/// `FromVariant`.
+fn method_checks(class_name: &Type, sig: &Signature) -> proc_macro2::TokenStream {
+    let var_args = has_var_args(sig);
+    let mut types = sig.inputs.iter().filter_map(|arg| match arg {
+        FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+        FnArg::Receiver(_) => None,
+    });
+
+    let mut checks = proc_macro2::TokenStream::new();
+
+    // The first parameter of async methods is the instance, which is checked by the wrapper.
+    if let Some(owner_ty) = types.next() {
+        if sig.asyncness.is_none() {
+            checks.extend(quote_spanned!(owner_ty.span() =>
+                let _: ::std::marker::PhantomData<<#class_name as gdnative::NativeClass>::Base> =
+                    ::std::marker::PhantomData::<#owner_ty>;
+            ));
+        }
+    }
+
+    let mut params = types.collect::<Vec<_>>();
+    if var_args {
+        params.pop();
+    }
+
+    for ty in params {
+        checks.extend(quote_spanned!(ty.span() =>
+            let _ = <#ty as gdnative::FromVariant>::from_variant;
+        ));
+    }
+
+    checks
+}
+
+/// Returns the parameters of `sig` in the syntax of `godot_wrap_method!`, with their default
+/// values, and with the variadic parameter prefixed by `..`.
+fn wrap_method_inputs(
+    sig: &Signature,
+    defaults: Vec<Option<Expr>>,
+) -> Vec<proc_macro2::TokenStream> {
+    let var_args = has_var_args(sig);
+    let num_inputs = sig.inputs.len();
+
+    sig.inputs
+        .iter()
+        .zip(defaults)
+        .enumerate()
+        .map(|(i, input)| match input {
+            (arg, _) if var_args && i + 1 == num_inputs => quote!(..#arg),
+            (arg, Some(default)) => quote!(#arg = #default),
+            (arg, None) => quote!(#arg),
+        })
+        .collect()
+}
+
 /// Parse the input.
 ///
 /// Returns the TokenStream of the impl block together with a description of methods to export.
-fn parse_method_export(_meta: TokenStream, input: TokenStream) -> (ItemImpl, ClassMethodExport) {
-    let ast = match syn::parse_macro_input::parse::<ItemImpl>(input) {
-        Ok(impl_block) => impl_block,
-        Err(err) => {
-            // if the impl block is ill-formed there is no point in error handling.
-            panic!("{}", err);
-        }
-    };
+fn parse_method_export(
+    _meta: TokenStream,
+    input: TokenStream,
+) -> Result<(ItemImpl, ClassMethodExport), syn::Error> {
+    let ast = syn::parse_macro_input::parse::<ItemImpl>(input)?;
 
-    impl_gdnative_expose(ast)
+    Ok(impl_gdnative_expose(ast))
 }
 
 /// Extract the data to export from the impl block.
@@ -83,9 +287,10 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
     let mut export = ClassMethodExport {
         class_ty: ast.self_ty,
         methods: vec![],
+        errors: vec![],
     };
 
-    let mut methods_to_export = Vec::<Signature>::new();
+    let mut methods_to_export = Vec::<ExportMethod>::new();
 
     // extract all methods that have the #[export] attribute.
     // add all items back to the impl block again.
@@ -109,10 +314,30 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
                 });
 
                 if let Some(idx) = attribute_pos {
-                    // TODO renaming? rpc modes?
-                    let _attr = method.attrs.remove(idx);
+                    // TODO renaming?
+                    let attr = method.attrs.remove(idx);
+
+                    let parsed = ExportArgs::from_attr(&attr).and_then(|args| {
+                        check_receiver(&method.sig)?;
+                        if method.sig.asyncness.is_some() && has_var_args(&method.sig) {
+                            return Err(syn::Error::new(
+                                method.sig.span(),
+                                "variadic parameters are not supported in async methods",
+                            ));
+                        }
 
-                    methods_to_export.push(method.sig.clone());
+                        let defaults = optional_args(&mut method.sig)?;
+                        Ok((args, defaults))
+                    });
+
+                    match parsed {
+                        Ok((args, defaults)) => methods_to_export.push(ExportMethod {
+                            sig: method.sig.clone(),
+                            args,
+                            defaults,
+                        }),
+                        Err(err) => export.errors.push(err),
+                    }
                 }
 
                 ImplItem::Method(method)
@@ -126,26 +351,47 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
     // check if the export methods have the proper "shape", the write them
     // into the list of things to export.
     {
-        for mut method in methods_to_export {
-            let generics = &method.generics;
+        for ExportMethod {
+            mut sig,
+            args,
+            defaults,
+        } in methods_to_export
+        {
+            let generics = &sig.generics;
+
+            let error = if let Some(param) = generics.type_params().next() {
+                Some((
+                    param.span(),
+                    "type parameters not allowed in exported functions",
+                ))
+            } else if let Some(param) = generics.lifetimes().next() {
+                Some((
+                    param.span(),
+                    "lifetime parameters not allowed in exported functions",
+                ))
+            } else if let Some(param) = generics.const_params().next() {
+                Some((
+                    param.span(),
+                    "const parameters not allowed in exported functions",
+                ))
+            } else {
+                sig.inputs.iter().find_map(|arg| match arg {
+                    FnArg::Typed(cap) => match &*cap.pat {
+                        Pat::Wild(_) | Pat::Ident(_) => None,
+                        pat => Some((pat.span(), "patterns not allowed in exported functions")),
+                    },
+                    _ => None,
+                })
+            };
 
-            if generics.type_params().count() > 0 {
-                eprintln!("type parameters not allowed in exported functions");
-                continue;
-            }
-            if generics.lifetimes().count() > 0 {
-                eprintln!("lifetime parameters not allowed in exported functions");
-                continue;
-            }
-            if generics.const_params().count() > 0 {
-                eprintln!("const parameters not allowed in exported functions");
+            if let Some((span, message)) = error {
+                export.errors.push(syn::Error::new(span, message));
                 continue;
             }
 
             // remove "mut" from arguments.
             // give every wildcard a (hopefully) unique name.
-            method
-                .inputs
+            sig.inputs
                 .iter_mut()
                 .enumerate()
                 .for_each(|(i, arg)| match arg {
@@ -172,11 +418,246 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
 
             // The calling site is already in an unsafe block, so removing it from just the
             // exported binding is fine.
-            method.unsafety = None;
+            sig.unsafety = None;
 
-            export.methods.push(method);
+            export.methods.push(ExportMethod {
+                sig,
+                args,
+                defaults,
+            });
         }
     }
 
     (result, export)
 }
+
+/// Checks that an exported method takes `&self` or `&mut self`, followed by the owner.
+/// Exported async methods take the instance as their first parameter instead.
+fn check_receiver(sig: &Signature) -> Result<(), syn::Error> {
+    let mut inputs = sig.inputs.iter();
+
+    if sig.asyncness.is_some() {
+        return match inputs.next() {
+            Some(FnArg::Typed(_)) => Ok(()),
+            Some(FnArg::Receiver(receiver)) => Err(syn::Error::new(
+                receiver.span(),
+                "exported async methods should take the instance, e.g. `this: Instance<Self>`, instead of `self`",
+            )),
+            None => Err(syn::Error::new(
+                sig.paren_token.span,
+                "exported async methods should take the instance as their first parameter",
+            )),
+        };
+    }
+
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => {}
+        Some(arg) => {
+            return Err(syn::Error::new(
+                arg.span(),
+                "exported methods should take `&self` or `&mut self`",
+            ))
+        }
+        None => {
+            return Err(syn::Error::new(
+                sig.paren_token.span,
+                "exported methods should take `&self` or `&mut self`",
+            ))
+        }
+    }
+
+    match inputs.next() {
+        Some(FnArg::Typed(_)) => Ok(()),
+        _ => Err(syn::Error::new(
+            sig.paren_token.span,
+            "exported methods should take the owner as their first parameter after `self`",
+        )),
+    }
+}
+
+/// Extracts the default values of the optional parameters of an exported method, removing the
+/// `#[opt]` and `#[default = ...]` attributes from the signature.
+///
+/// Parameters marked with `#[opt]` default to `Default::default()`, and trailing parameters of
+/// type `Option<T>` default to `None`. Only trailing parameters can be optional, so that callers
+/// can omit them. Returns one entry for each input of the signature.
+fn optional_args(sig: &mut Signature) -> Result<Vec<Option<Expr>>, syn::Error> {
+    let mut defaults = Vec::with_capacity(sig.inputs.len());
+    let mut seen_owner = false;
+    let mut num_fixed = 0;
+
+    for arg in sig.inputs.iter_mut() {
+        // The receiver and the owner can't be optional.
+        let (attrs, is_param) = match arg {
+            FnArg::Receiver(receiver) => (&mut receiver.attrs, false),
+            FnArg::Typed(pat_type) => (
+                &mut pat_type.attrs,
+                std::mem::replace(&mut seen_owner, true),
+            ),
+        };
+
+        if !is_param {
+            num_fixed = defaults.len() + 1;
+        }
+
+        let mut default = None;
+        let mut error = None;
+
+        attrs.retain(|attr| {
+            let value = if attr.path.is_ident("opt") {
+                if attr.tokens.is_empty() {
+                    Ok(parse_quote!(::std::default::Default::default()))
+                } else {
+                    Err(syn::Error::new(
+                        attr.tokens.span(),
+                        "unexpected arguments, expected `#[opt]`",
+                    ))
+                }
+            } else if attr.path.is_ident("default") {
+                parse_default(attr)
+            } else {
+                return true;
+            };
+
+            let result = value.and_then(|value| {
+                if !is_param {
+                    Err(syn::Error::new(
+                        attr.span(),
+                        "only parameters after the owner can be optional",
+                    ))
+                } else if default.is_some() {
+                    Err(syn::Error::new(
+                        attr.span(),
+                        "parameter already has a default value",
+                    ))
+                } else {
+                    Ok(value)
+                }
+            });
+
+            match result {
+                Ok(value) => default = Some(value),
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+
+            false
+        });
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        defaults.push(default);
+    }
+
+    // The variadic parameter receives the arguments after the optional ones.
+    let mut num_params = defaults.len();
+    if has_var_args(sig) {
+        num_params -= 1;
+        if defaults[num_params].is_some() {
+            return Err(syn::Error::new(
+                sig.inputs[num_params].span(),
+                "variadic parameters can't be optional",
+            ));
+        }
+    }
+
+    // Trailing `Option<T>` parameters are optional even without attributes.
+    let params = sig
+        .inputs
+        .iter()
+        .zip(defaults.iter_mut())
+        .take(num_params)
+        .skip(num_fixed);
+    for (arg, default) in params.rev() {
+        if default.is_some() {
+            continue;
+        }
+
+        match arg {
+            FnArg::Typed(pat_type) if is_option(&pat_type.ty) => {
+                *default = Some(parse_quote!(::std::option::Option::None));
+            }
+            _ => break,
+        }
+    }
+
+    let first_optional = defaults.iter().position(Option::is_some);
+    if let Some(first_optional) = first_optional {
+        let required = sig
+            .inputs
+            .iter()
+            .zip(defaults.iter())
+            .take(num_params)
+            .skip(first_optional)
+            .find(|(_, default)| default.is_none());
+
+        if let Some((arg, _)) = required {
+            return Err(syn::Error::new(
+                arg.span(),
+                "only trailing parameters can be optional",
+            ));
+        }
+    }
+
+    Ok(defaults)
+}
+
+/// Returns true if the last parameter of `sig`, after the owner, is variadic. Variadic
+/// parameters are spelled as `&[Variant]` or `VarArgs`.
+fn has_var_args(sig: &Signature) -> bool {
+    let mut typed = sig.inputs.iter().filter_map(|arg| match arg {
+        FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+        FnArg::Receiver(_) => None,
+    });
+
+    // Skip the owner.
+    typed.next();
+
+    let ty = match typed.last() {
+        Some(ty) => ty,
+        None => return false,
+    };
+
+    match ty {
+        Type::Reference(reference) => match &*reference.elem {
+            Type::Slice(slice) => is_path_to(&slice.elem, "Variant"),
+            _ => false,
+        },
+        ty => is_path_to(ty, "VarArgs"),
+    }
+}
+
+/// Parses the value of a `#[default = value]` attribute.
+fn parse_default(attr: &Attribute) -> Result<Expr, syn::Error> {
+    let parser = |input: ParseStream| {
+        input.parse::<Token![=]>()?;
+        input.parse::<Expr>()
+    };
+
+    parser.parse2(attr.tokens.clone()).map_err(|_| {
+        syn::Error::new(
+            attr.span(),
+            "unexpected syntax, expected `#[default = value]`",
+        )
+    })
+}
+
+/// Returns true if `ty` is spelled as `Option<T>`.
+fn is_option(ty: &Type) -> bool {
+    is_path_to(ty, "Option")
+}
+
+/// Returns true if `ty` is a path whose last segment is `name`, ignoring generic arguments.
+fn is_path_to(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == name),
+        _ => false,
+    }
+}
@@ -1,6 +1,6 @@
 use proc_macro::TokenStream;
-use std::collections::HashMap;
-use syn::{Data, DeriveInput, Fields, Ident, Meta, MetaList, NestedMeta, Path, Type};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, Ident, Meta, MetaList, Path, Type};
 
 mod property_args;
 use property_args::{PropertyAttrArgs, PropertyAttrArgsBuilder};
@@ -10,11 +10,15 @@ pub(crate) struct DeriveData {
     pub(crate) base: Type,
     pub(crate) register_callback: Option<Path>,
     pub(crate) user_data: Type,
-    pub(crate) properties: HashMap<Ident, PropertyAttrArgs>,
+    /// Exported properties, in the order of the fields.
+    pub(crate) properties: Vec<(Ident, Type, PropertyAttrArgs)>,
 }
 
 pub(crate) fn derive_native_class(input: TokenStream) -> TokenStream {
-    let data = parse_derive_input(input);
+    let data = match parse_derive_input(input) {
+        Ok(data) => data,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     // generate NativeClass impl
     let trait_impl = {
@@ -25,22 +29,42 @@ pub(crate) fn derive_native_class(input: TokenStream) -> TokenStream {
             .register_callback
             .map(|function_path| quote!(#function_path(builder);))
             .unwrap_or(quote!({}));
-        let properties = data.properties.into_iter().map(|(ident, config)| {
-            let with_default = if let Some(default_value) = &config.default {
-                Some(quote!(.with_default(#default_value)))
-            } else {
-                None
+        let (ungrouped, grouped) = group_properties(data.properties);
+        let property = |(group, ident, ty, config): GroupedProperty| {
+            let add_group = group.map(|group| quote!(builder.add_property_group(#group, "");));
+            let with_default = config
+                .default
+                .map(|default_value| quote!(.with_default(#default_value)));
+            let with_hint = config
+                .hint
+                .map(|hint| quote!(.with_hint(::std::convert::Into::into(#hint))));
+            let with_usage = config.usage.map(|usage| quote!(.with_usage(#usage)));
+
+            let with_getter = match config.get {
+                Some(get) => quote!(.with_getter(#get)),
+                None => quote!(.with_ref_getter(|this: &#name, _| &this.#ident)),
+            };
+            let with_setter = match config.set {
+                Some(set) => quote!(.with_setter(#set)),
+                None => quote!(.with_setter(|this: &mut #name, _, v| this.#ident = v)),
             };
 
             let label = config.path.unwrap_or_else(|| format!("{}", ident));
             quote!({
-                builder.add_property(#label)
+                #add_group
+                builder.add_property::<#ty>(#label)
                     #with_default
-                    .with_ref_getter(|this: &#name, _| &this.#ident)
-                    .with_setter(|this: &mut #name, _, v| this.#ident = v)
+                    #with_hint
+                    #with_usage
+                    #with_getter
+                    #with_setter
                     .done();
             })
-        });
+        };
+        // The callback is called before the groups are registered, so that its properties
+        // aren't shown in the last group.
+        let ungrouped = ungrouped.into_iter().map(&property);
+        let grouped = grouped.into_iter().map(&property);
 
         // string variant needed for the `class_name` function.
         let name_str = quote!(#name).to_string();
@@ -59,8 +83,9 @@ pub(crate) fn derive_native_class(input: TokenStream) -> TokenStream {
                 }
 
                 fn register_properties(builder: &gdnative::init::ClassBuilder<Self>) {
-                    #(#properties)*;
+                    #(#ungrouped)*
                     #register_callback
+                    #(#grouped)*
                 }
             }
         )
@@ -70,13 +95,8 @@ pub(crate) fn derive_native_class(input: TokenStream) -> TokenStream {
     trait_impl.into()
 }
 
-fn parse_derive_input(input: TokenStream) -> DeriveData {
-    let input = match syn::parse_macro_input::parse::<DeriveInput>(input) {
-        Ok(val) => val,
-        Err(err) => {
-            panic!("{}", err);
-        }
-    };
+fn parse_derive_input(input: TokenStream) -> Result<DeriveData, syn::Error> {
+    let input = syn::parse_macro_input::parse::<DeriveInput>(input)?;
 
     let ident = input.ident;
 
@@ -84,92 +104,147 @@ fn parse_derive_input(input: TokenStream) -> DeriveData {
         .attrs
         .iter()
         .find(|a| a.path.is_ident("inherit"))
-        .expect("No \"inherit\" attribute found");
+        .ok_or_else(|| {
+            syn::Error::new(
+                ident.span(),
+                "missing `#[inherit(Base)]` attribute, e.g. `#[inherit(Node)]`",
+            )
+        })?;
 
     // read base class
-    let base = inherit_attr
-        .parse_args::<Type>()
-        .expect("`inherits` attribute requires the base type as an argument.");
+    let base = inherit_attr.parse_args::<Type>()?;
 
     let register_callback = input
         .attrs
         .iter()
         .find(|a| a.path.is_ident("register_with"))
-        .map(|attr| {
-            attr.parse_args::<Path>()
-                .expect("`register_with` attributes requires a function as an argument.")
-        });
+        .map(|attr| attr.parse_args::<Path>())
+        .transpose()?;
 
     let user_data = input
         .attrs
         .iter()
         .find(|a| a.path.is_ident("user_data"))
-        .map(|attr| {
-            attr.parse_args::<Type>()
-                .expect("`userdata` attribute requires a type as an argument.")
-        })
-        .unwrap_or_else(|| {
-            syn::parse::<Type>(quote! { ::gdnative::user_data::DefaultUserData<#ident> }.into())
-                .expect("quoted tokens should be a valid type")
-        });
+        .map(|attr| attr.parse_args::<Type>())
+        .transpose()?
+        .unwrap_or_else(|| parse_quote! { ::gdnative::user_data::DefaultUserData<#ident> });
 
     // make sure it's a struct
-    let struct_data = if let Data::Struct(data) = input.data {
-        data
-    } else {
-        panic!("NativeClass derive macro only works on structs.");
+    let struct_data = match input.data {
+        Data::Struct(data) => data,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span,
+                "NativeClass derive macro only works on structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "NativeClass derive macro only works on structs",
+            ))
+        }
     };
 
     // read exported properties
-    let properties = if let Fields::Named(names) = &struct_data.fields {
-        names
-            .named
-            .iter()
-            .filter_map(|field| {
-                let mut property_args = None;
-
-                for attr in field.attrs.iter() {
-                    if !attr.path.is_ident("property") {
+    let mut properties = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+
+    if let Fields::Named(names) = &struct_data.fields {
+        for field in names.named.iter() {
+            let mut property_args = None;
+
+            for attr in field.attrs.iter() {
+                if !attr.path.is_ident("property") {
+                    continue;
+                }
+
+                let meta = match attr.parse_meta() {
+                    Ok(meta) => meta,
+                    Err(err) => {
+                        combine(&mut errors, err);
                         continue;
                     }
+                };
 
-                    let meta = attr
-                        .parse_meta()
-                        .expect("should be able to parse attribute arguments");
-
-                    match meta {
-                        Meta::List(MetaList { nested, .. }) => {
-                            property_args
-                                .get_or_insert_with(PropertyAttrArgsBuilder::default)
-                                .extend(nested.iter().map(|arg| match arg {
-                                    NestedMeta::Meta(Meta::NameValue(ref pair)) => pair,
-                                    _ => panic!("unexpected argument: {:?}", arg),
-                                }));
-                        }
-                        Meta::Path(_) => {
-                            property_args.get_or_insert_with(PropertyAttrArgsBuilder::default);
+                let builder = property_args.get_or_insert_with(PropertyAttrArgsBuilder::default);
+
+                match meta {
+                    Meta::List(MetaList { nested, .. }) => {
+                        for arg in nested.iter() {
+                            builder.add_meta(arg);
                         }
-                        _ => {
-                            panic!("unexpected meta variant: {:?}", meta);
+                    }
+                    Meta::Path(_) => {}
+                    meta => builder.add_error(syn::Error::new(
+                        meta.span(),
+                        "unexpected syntax, expected `#[property]` or `#[property(key = value)]`",
+                    )),
+                }
+            }
+
+            if let Some(builder) = property_args {
+                match builder.done() {
+                    Ok(args) => {
+                        // fields are named, so this always succeeds
+                        if let Some(ident) = field.ident.clone() {
+                            properties.push((ident, field.ty.clone(), args));
                         }
                     }
+                    Err(err) => combine(&mut errors, err),
                 }
+            }
+        }
+    }
 
-                property_args.map(|builder| {
-                    let ident = field.ident.clone().expect("fields should be named");
-                    (ident, builder.done())
-                })
-            })
-            .collect::<HashMap<_, _>>()
-    } else {
-        HashMap::new()
-    };
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
 
-    DeriveData {
+    Ok(DeriveData {
         name: ident,
         base,
         register_callback,
         user_data,
         properties,
+    })
+}
+
+/// A property along with the name of the group marker to register before it, if any.
+type GroupedProperty = (Option<String>, Ident, Type, PropertyAttrArgs);
+
+/// Splits the properties into the ones without a group and the ones with, ordering the latter
+/// so that the ones in the same group are registered together. This changes the order of
+/// fields in different groups, or declared before ungrouped ones. Groups keep the order of
+/// their first property. The name of the group is returned along with the first property of
+/// each group, which is where the group marker needs to be registered.
+fn group_properties(
+    properties: Vec<(Ident, Type, PropertyAttrArgs)>,
+) -> (Vec<GroupedProperty>, Vec<GroupedProperty>) {
+    let mut ungrouped = Vec::new();
+    let mut groups: Vec<(String, Vec<_>)> = Vec::new();
+
+    for (ident, ty, config) in properties {
+        match config.group.clone() {
+            None => ungrouped.push((None, ident, ty, config)),
+            Some(group) => match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, members)) => members.push((None, ident, ty, config)),
+                None => groups.push((group.clone(), vec![(Some(group), ident, ty, config)])),
+            },
+        }
+    }
+
+    let grouped = groups
+        .into_iter()
+        .flat_map(|(_, members)| members)
+        .collect();
+    (ungrouped, grouped)
+}
+
+/// Adds `error` to the errors collected in `errors`.
+fn combine(errors: &mut Option<syn::Error>, error: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(error),
+        None => *errors = Some(error),
     }
 }
@@ -1,53 +1,333 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::{Lit, Meta, MetaList, NestedMeta};
+
 pub struct PropertyAttrArgs {
     pub path: Option<String>,
     pub default: Option<syn::Lit>,
+    /// Expression evaluating to a hint, which is converted to the hint type of the property.
+    pub hint: Option<TokenStream2>,
+    /// Expression evaluating to the `Usage` flags of the property.
+    pub usage: Option<TokenStream2>,
+    pub get: Option<syn::Path>,
+    pub set: Option<syn::Path>,
+    /// Name of the inspector group the property is shown in.
+    pub group: Option<String>,
 }
 
 #[derive(Default)]
 pub struct PropertyAttrArgsBuilder {
     path: Option<String>,
     default: Option<syn::Lit>,
+    hint: Option<TokenStream2>,
+    usage: Option<TokenStream2>,
+    get: Option<syn::Path>,
+    set: Option<syn::Path>,
+    group: Option<String>,
+    errors: Option<syn::Error>,
+}
+
+impl PropertyAttrArgsBuilder {
+    pub fn add_meta(&mut self, meta: &NestedMeta) {
+        let result = match meta {
+            NestedMeta::Meta(Meta::NameValue(pair)) => self.try_add_pair(pair),
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("hint") => {
+                self.try_add_hint(list)
+            }
+            _ => Err(syn::Error::new(
+                meta.span(),
+                "unexpected argument, expected `key = value` or `hint(...)`",
+            )),
+        };
+
+        if let Err(err) = result {
+            self.add_error(err);
+        }
+    }
+
+    pub fn add_error(&mut self, error: syn::Error) {
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    fn try_add_pair(&mut self, pair: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        let name = pair
+            .path
+            .get_ident()
+            .ok_or_else(|| syn::Error::new(pair.path.span(), "key should be single ident"))?
+            .to_string();
+
+        match name.as_str() {
+            "default" => {
+                if self.default.replace(pair.lit.clone()).is_some() {
+                    return Err(syn::Error::new(
+                        pair.span(),
+                        "there is already a default value set",
+                    ));
+                }
+            }
+            "path" => {
+                let string = str_value(&pair.lit, "path")?;
+                if self.path.replace(string).is_some() {
+                    return Err(syn::Error::new(pair.span(), "there is already a path set"));
+                }
+            }
+            "usage" => {
+                let usage = parse_usage(&pair.lit)?;
+                if self.usage.replace(usage).is_some() {
+                    return Err(syn::Error::new(pair.span(), "there is already a usage set"));
+                }
+            }
+            "get" => {
+                let path = parse_path(&pair.lit, "get")?;
+                if self.get.replace(path).is_some() {
+                    return Err(syn::Error::new(
+                        pair.span(),
+                        "there is already a getter set",
+                    ));
+                }
+            }
+            "set" => {
+                let path = parse_path(&pair.lit, "set")?;
+                if self.set.replace(path).is_some() {
+                    return Err(syn::Error::new(
+                        pair.span(),
+                        "there is already a setter set",
+                    ));
+                }
+            }
+            "group" => {
+                let string = str_value(&pair.lit, "group")?;
+                if self.group.replace(string).is_some() {
+                    return Err(syn::Error::new(pair.span(), "there is already a group set"));
+                }
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    pair.path.span(),
+                    format!("unexpected argument: {}", &name),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn try_add_hint(&mut self, list: &MetaList) -> Result<(), syn::Error> {
+        let mut nested = list.nested.iter();
+        let hint = match (nested.next(), nested.next()) {
+            (Some(NestedMeta::Meta(hint)), None) => parse_hint(hint)?,
+            _ => {
+                return Err(syn::Error::new(
+                    list.span(),
+                    "expected a single hint, e.g. `hint(range(min = 0, max = 10))`",
+                ))
+            }
+        };
+
+        if self.hint.replace(hint).is_some() {
+            return Err(syn::Error::new(list.span(), "there is already a hint set"));
+        }
+
+        Ok(())
+    }
+
+    pub fn done(self) -> Result<PropertyAttrArgs, syn::Error> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(PropertyAttrArgs {
+                path: self.path,
+                default: self.default,
+                hint: self.hint,
+                usage: self.usage,
+                get: self.get,
+                set: self.set,
+                group: self.group,
+            }),
+        }
+    }
+}
+
+fn str_value(lit: &Lit, key: &str) -> Result<String, syn::Error> {
+    match lit {
+        Lit::Str(lit_str) => Ok(lit_str.value()),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("{} value is not a string literal", key),
+        )),
+    }
+}
+
+fn parse_path(lit: &Lit, key: &str) -> Result<syn::Path, syn::Error> {
+    match lit {
+        Lit::Str(lit_str) => lit_str.parse(),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("{} value is not a string literal", key),
+        )),
+    }
+}
+
+/// Parses usage flags separated by `|`, e.g. `"STORAGE | NETWORK"`.
+fn parse_usage(lit: &Lit) -> Result<TokenStream2, syn::Error> {
+    let string = str_value(lit, "usage")?;
+
+    let flags = string
+        .split('|')
+        .map(|flag| {
+            let flag = flag.trim();
+            syn::parse_str::<syn::Ident>(flag)
+                .map(|ident| syn::Ident::new(&ident.to_string(), lit.span()))
+                .map_err(|_| syn::Error::new(lit.span(), format!("invalid usage flag: `{}`", flag)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(quote!(#(::gdnative::init::PropertyUsage::#flags)|*))
 }
 
-impl<'a> Extend<&'a syn::MetaNameValue> for PropertyAttrArgsBuilder {
-    fn extend<I>(&mut self, iter: I)
-    where
-        I: IntoIterator<Item = &'a syn::MetaNameValue>,
-    {
-        for pair in iter.into_iter() {
-            let name = pair
-                .path
-                .get_ident()
-                .expect("should be single identifier")
-                .to_string();
-            match name.as_str() {
-                "default" => {
-                    if let Some(old) = self.default.replace(pair.lit.clone()) {
-                        panic!("there is already a default value set: {:?}", old);
+/// Parses a hint, e.g. `range(min = 0, max = 10, step = 2)` or `multiline`, into an expression
+/// evaluating to one of the types in `init::property::hint`.
+fn parse_hint(meta: &Meta) -> Result<TokenStream2, syn::Error> {
+    let name = meta
+        .path()
+        .get_ident()
+        .ok_or_else(|| syn::Error::new(meta.path().span(), "hint should be single ident"))?
+        .to_string();
+
+    let hint = quote!(::gdnative::init::property::hint);
+
+    let expr = match (name.as_str(), meta) {
+        ("range", Meta::List(list)) => {
+            let mut min = None;
+            let mut max = None;
+            let mut step = None;
+            let mut modifiers = Vec::new();
+
+            for arg in list.nested.iter() {
+                match arg {
+                    NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("min") => {
+                        min = Some(number(&pair.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("max") => {
+                        max = Some(number(&pair.lit)?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("step") => {
+                        let step_value = number(&pair.lit)?;
+                        step = Some(quote!(.with_step(#step_value)));
+                    }
+                    NestedMeta::Meta(Meta::Path(path))
+                        if path.is_ident("or_greater") || path.is_ident("or_lesser") =>
+                    {
+                        modifiers.push(quote!(.#path()));
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            arg.span(),
+                            "unexpected argument, expected `min`, `max`, `step`, `or_greater` or `or_lesser`",
+                        ))
                     }
                 }
-                "path" => {
-                    let string = if let syn::Lit::Str(lit_str) = &pair.lit {
-                        lit_str.value()
-                    } else {
-                        panic!("path value is not a string literal");
-                    };
+            }
+
+            let (min, max) = match (min, max) {
+                (Some(min), Some(max)) => (min, max),
+                _ => {
+                    return Err(syn::Error::new(
+                        list.span(),
+                        "range hints require both `min` and `max`",
+                    ))
+                }
+            };
+
+            quote!(#hint::RangeHint::new(#min, #max) #step #(#modifiers)*)
+        }
+        ("enum", Meta::List(list)) => {
+            let values = strings(list)?;
+            quote!(#hint::EnumHint::new(vec![#(#values.into()),*]))
+        }
+        ("flags", Meta::List(list)) => {
+            let values = strings(list)?;
+            quote!(#hint::IntHint::Flags(#hint::EnumHint::new(vec![#(#values.into()),*])))
+        }
+        ("file", Meta::Path(_))
+        | ("file", Meta::List(_))
+        | ("global_file", Meta::Path(_))
+        | ("global_file", Meta::List(_)) => {
+            let filters = match meta {
+                Meta::List(list) => strings(list)?,
+                _ => Vec::new(),
+            };
+            let variant = if name == "file" {
+                quote!(File)
+            } else {
+                quote!(GlobalFile)
+            };
+            quote!(#hint::StringHint::#variant(#hint::EnumHint::new(vec![#(#filters.into()),*])))
+        }
+        ("dir", Meta::Path(_)) => quote!(#hint::StringHint::Dir),
+        ("global_dir", Meta::Path(_)) => quote!(#hint::StringHint::GlobalDir),
+        ("multiline", Meta::Path(_)) => quote!(#hint::StringHint::Multiline),
+        ("placeholder", Meta::NameValue(pair)) => {
+            let placeholder = str_value(&pair.lit, "placeholder")?;
+            quote!(#hint::StringHint::Placeholder { placeholder: #placeholder.into() })
+        }
+        ("color_no_alpha", Meta::Path(_)) => quote!(#hint::ColorHint::NoAlpha),
+        ("exp_easing", Meta::Path(_)) | ("exp_easing", Meta::List(_)) => {
+            let mut is_attenuation = false;
+            let mut is_in_out = false;
 
-                    if let Some(old) = self.path.replace(string) {
-                        panic!("there is already a path set: {:?}", old);
+            if let Meta::List(list) = meta {
+                for arg in list.nested.iter() {
+                    match arg {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("attenuation") => {
+                            is_attenuation = true;
+                        }
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("inout") => {
+                            is_in_out = true;
+                        }
+                        _ => {
+                            return Err(syn::Error::new(
+                                arg.span(),
+                                "unexpected argument, expected `attenuation` or `inout`",
+                            ))
+                        }
                     }
                 }
-                _ => panic!("unexpected argument: {}", &name),
             }
+
+            quote!(#hint::ExpEasingHint {
+                is_attenuation: #is_attenuation,
+                is_in_out: #is_in_out,
+            })
         }
-    }
+        _ => {
+            return Err(syn::Error::new(
+                meta.span(),
+                format!("unknown or malformed hint: {}", name),
+            ))
+        }
+    };
+
+    Ok(expr)
 }
 
-impl PropertyAttrArgsBuilder {
-    pub fn done(self) -> PropertyAttrArgs {
-        PropertyAttrArgs {
-            path: self.path,
-            default: self.default,
-        }
+/// Parses a number, which can be given as a string to allow negative values and expressions.
+fn number(lit: &Lit) -> Result<TokenStream2, syn::Error> {
+    match lit {
+        Lit::Int(_) | Lit::Float(_) => Ok(quote!(#lit)),
+        Lit::Str(lit_str) => lit_str.parse::<syn::Expr>().map(|expr| quote!(#expr)),
+        _ => Err(syn::Error::new(lit.span(), "expected a number")),
     }
 }
+
+fn strings(list: &MetaList) -> Result<Vec<String>, syn::Error> {
+    list.nested
+        .iter()
+        .map(|arg| match arg {
+            NestedMeta::Lit(Lit::Str(lit_str)) => Ok(lit_str.value()),
+            _ => Err(syn::Error::new(arg.span(), "expected a string literal")),
+        })
+        .collect()
+}
@@ -0,0 +1,252 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta, Type};
+
+/// A signal declared by a struct, or by a variant of an enum.
+struct SignalDecl {
+    name: String,
+    /// Path used to construct or match the value, e.g. `Self` or `Self::Variant`.
+    path: TokenStream2,
+    /// Name of the generated emit helper.
+    emit_fn: Ident,
+    fields: Fields,
+    args: Vec<SignalArg>,
+}
+
+struct SignalArg {
+    name: String,
+    binding: Ident,
+    ty: Type,
+}
+
+pub(crate) fn derive_godot_signal(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let output = match expand_godot_signal(input) {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error(),
+    };
+
+    TokenStream::from(output)
+}
+
+fn expand_godot_signal(input: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    if input.generics.params.iter().next().is_some() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "GodotSignal can't be derived for generic types",
+        ));
+    }
+
+    let type_name = parse_name(&input.attrs)?;
+
+    let signals = match &input.data {
+        Data::Struct(data) => {
+            let name = type_name.unwrap_or_else(|| to_snake_case(&input.ident.to_string()));
+            vec![signal_decl(
+                name,
+                quote!(Self),
+                Ident::new("emit", input.ident.span()),
+                &data.fields,
+            )?]
+        }
+        Data::Enum(data) => {
+            if type_name.is_some() {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "signal names of enums should be set on the variants",
+                ));
+            }
+
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let snake_case = to_snake_case(&ident.to_string());
+                    let name = parse_name(&variant.attrs)?.unwrap_or_else(|| snake_case.clone());
+                    let emit_fn = Ident::new(&format!("emit_{}", snake_case), ident.span());
+                    signal_decl(name, quote!(Self::#ident), emit_fn, &variant.fields)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "GodotSignal can't be derived for unions",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+
+    let register = signals.iter().map(|signal| {
+        let name = &signal.name;
+        let args = signal.args.iter().map(|arg| {
+            let arg_name = &arg.name;
+            let ty = &arg.ty;
+            quote!(gdnative::init::SignalArgument::builder::<#ty>(#arg_name).done())
+        });
+
+        quote!(
+            builder.add_signal(gdnative::init::Signal {
+                name: #name,
+                args: &[#(#args,)*],
+            });
+        )
+    });
+
+    let emit_arms = signals.iter().map(|signal| {
+        let name = &signal.name;
+        let pattern = signal_pattern(signal);
+        let bindings = signal.args.iter().map(|arg| &arg.binding);
+
+        quote!(
+            #pattern => gdnative::init::emit_signal(
+                owner,
+                #name,
+                &[#(gdnative::ToVariant::to_variant(#bindings),)*],
+            ),
+        )
+    });
+
+    let emit_fns = signals.iter().map(|signal| {
+        let name = &signal.name;
+        let emit_fn = &signal.emit_fn;
+        let bindings = signal.args.iter().map(|arg| &arg.binding);
+        let types = signal.args.iter().map(|arg| &arg.ty);
+        let value = signal_pattern(signal);
+        let doc = format!("Emits the `{}` signal on `owner`.", name);
+
+        quote!(
+            #[doc = #doc]
+            ///
+            /// # Safety
+            ///
+            /// `owner` must point to a valid Godot object.
+            #[allow(clippy::too_many_arguments)]
+            #vis unsafe fn #emit_fn<O: gdnative::GodotObject>(owner: &O, #(#bindings: #types),*) {
+                gdnative::init::GodotSignal::emit_to(&#value, owner);
+            }
+        )
+    });
+
+    Ok(quote!(
+        impl gdnative::init::GodotSignal for #ident {
+            fn register_signals<C: gdnative::NativeClass>(
+                builder: &gdnative::init::ClassBuilder<C>,
+            ) {
+                #(#register)*
+            }
+
+            #[allow(unreachable_code, unused_variables)]
+            unsafe fn emit_to<O: gdnative::GodotObject>(&self, owner: &O) {
+                match self {
+                    #(#emit_arms)*
+                }
+            }
+        }
+
+        impl #ident {
+            #(#emit_fns)*
+        }
+    ))
+}
+
+fn signal_decl(
+    name: String,
+    path: TokenStream2,
+    emit_fn: Ident,
+    fields: &Fields,
+) -> Result<SignalDecl, syn::Error> {
+    let args = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let (default_name, binding) = match &field.ident {
+                Some(ident) => (ident.to_string(), ident.clone()),
+                None => (
+                    format!("arg{}", i),
+                    Ident::new(&format!("arg{}", i), field.span()),
+                ),
+            };
+
+            let name = parse_name(&field.attrs)?.unwrap_or(default_name);
+
+            Ok(SignalArg {
+                name,
+                binding,
+                ty: field.ty.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+
+    Ok(SignalDecl {
+        name,
+        path,
+        emit_fn,
+        fields: fields.clone(),
+        args,
+    })
+}
+
+/// Expression or pattern listing all fields of a signal by their bindings.
+fn signal_pattern(signal: &SignalDecl) -> TokenStream2 {
+    let path = &signal.path;
+    let bindings = signal.args.iter().map(|arg| &arg.binding);
+
+    match &signal.fields {
+        Fields::Named(_) => quote!(#path { #(#bindings,)* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings,)* )),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// Parses the `name` argument of `#[signal(name = "...")]` attributes.
+fn parse_name(attrs: &[Attribute]) -> Result<Option<String>, syn::Error> {
+    let mut name = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("signal")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(syn::Error::new(meta.span(), "expected #[signal(...)]")),
+        };
+
+        for nested in list.nested.iter() {
+            let pair = match nested {
+                NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("name") => pair,
+                _ => return Err(syn::Error::new(nested.span(), "unknown argument")),
+            };
+
+            let value = match &pair.lit {
+                Lit::Str(lit_str) => lit_str.value(),
+                lit => return Err(syn::Error::new(lit.span(), "expected string literal")),
+            };
+
+            if name.replace(value).is_some() {
+                return Err(syn::Error::new(
+                    pair.span(),
+                    "the argument name is already set",
+                ));
+            }
+        }
+    }
+
+    Ok(name)
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut snake_case = String::with_capacity(s.len() + 4);
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+    snake_case
+}
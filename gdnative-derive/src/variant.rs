@@ -4,11 +4,21 @@ use syn::{Data, DeriveInput, Generics, Ident};
 mod attr;
 mod bounds;
 mod from;
+mod rename;
 mod repr;
 mod to;
 
+use attr::ContainerAttr;
 use bounds::extend_bounds;
-use repr::{Repr, VariantRepr};
+use rename::RenameRule;
+use repr::{EnumTagging, EnumVariant, Repr, VariantRepr};
+
+/// The conversion trait being derived.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum Direction {
+    ToVariant,
+    FromVariant,
+}
 
 pub(crate) struct DeriveData {
     pub(crate) ident: Ident,
@@ -16,46 +26,94 @@ pub(crate) struct DeriveData {
     pub(crate) generics: Generics,
 }
 
-pub(crate) fn parse_derive_input(input: TokenStream, bound: &syn::Path) -> DeriveData {
-    let input = match syn::parse_macro_input::parse::<DeriveInput>(input) {
-        Ok(val) => val,
-        Err(err) => {
-            panic!("{}", err);
-        }
+pub(crate) fn parse_derive_input(
+    input: TokenStream,
+    bound: &syn::Path,
+    direction: Direction,
+) -> Result<DeriveData, syn::Error> {
+    let input = syn::parse_macro_input::parse::<DeriveInput>(input)?;
+
+    let container_attr = repr::parse_container_attrs(&input.attrs)?;
+
+    let rename_all = match &container_attr.rename_all {
+        Some(rule) => Some(RenameRule::from_str(&rule.value()).ok_or_else(|| {
+            syn::Error::new(rule.span(), "unknown case convention for rename_all")
+        })?),
+        None => None,
     };
 
     let repr = match input.data {
-        Data::Struct(struct_data) => Repr::Struct(VariantRepr::repr_for(&struct_data.fields)),
-        Data::Enum(enum_data) => Repr::Enum(
-            enum_data
+        Data::Struct(struct_data) => {
+            let ContainerAttr {
+                tag,
+                content,
+                untagged,
+                as_int,
+                ..
+            } = &container_attr;
+
+            if tag.is_some() || content.is_some() || untagged.is_some() || as_int.is_some() {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "enum representation attributes are only supported on enums",
+                ));
+            }
+
+            Repr::Struct(VariantRepr::repr_for(&struct_data.fields, rename_all)?)
+        }
+        Data::Enum(enum_data) => {
+            let variants = enum_data
                 .variants
                 .iter()
                 .map(|variant| {
-                    (
-                        variant.ident.clone(),
-                        VariantRepr::repr_for(&variant.fields),
-                    )
+                    let name = match rename_all {
+                        Some(rule) => rule.apply_to_variant(&variant.ident.to_string()),
+                        None => variant.ident.to_string(),
+                    };
+
+                    Ok(EnumVariant {
+                        ident: variant.ident.clone(),
+                        name,
+                        repr: VariantRepr::repr_for(&variant.fields, None)?,
+                    })
                 })
-                .collect(),
-        ),
-        Data::Union(_) => panic!("Variant conversion derive macro does not work on unions."),
+                .collect::<Result<Vec<_>, syn::Error>>()?;
+
+            let tagging = EnumTagging::from_attr(container_attr, &enum_data, &variants)?;
+
+            Repr::Enum(tagging, variants)
+        }
+        Data::Union(union_data) => {
+            return Err(syn::Error::new(
+                union_data.union_token.span,
+                "Variant conversion derive macro does not work on unions.",
+            ))
+        }
     };
 
-    let generics = extend_bounds(input.generics, &repr, bound);
+    let generics = extend_bounds(input.generics, &repr, bound, direction);
 
-    DeriveData {
+    Ok(DeriveData {
         ident: input.ident,
         repr,
         generics,
-    }
+    })
 }
 
 pub(crate) fn derive_to_variant(input: TokenStream) -> TokenStream {
     let bound: syn::Path = syn::parse2(quote! { ::gdnative::ToVariant }).unwrap();
-    to::expand_to_variant(parse_derive_input(input, &bound))
+    match parse_derive_input(input, &bound, Direction::ToVariant) {
+        Ok(derive_data) => to::expand_to_variant(derive_data),
+        Err(err) => err.to_compile_error().into(),
+    }
 }
 
 pub(crate) fn derive_from_variant(input: TokenStream) -> TokenStream {
     let bound: syn::Path = syn::parse2(quote! { ::gdnative::FromVariant }).unwrap();
-    from::expand_from_variant(parse_derive_input(input, &bound))
+    match parse_derive_input(input, &bound, Direction::FromVariant)
+        .and_then(from::expand_from_variant)
+    {
+        Ok(output) => output,
+        Err(err) => err.to_compile_error().into(),
+    }
 }
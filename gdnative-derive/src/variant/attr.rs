@@ -6,12 +6,31 @@ use syn::spanned::Spanned;
 pub struct Attr {
     pub to_variant_with: Option<syn::Path>,
     pub from_variant_with: Option<syn::Path>,
+    pub skip_to_variant: bool,
+    pub skip_from_variant: bool,
+    pub rename: Option<syn::LitStr>,
+    pub default: Option<FieldDefault>,
+    pub flatten: bool,
+}
+
+/// Value used for a field missing from the input, set with `#[variant(default)]`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum FieldDefault {
+    /// `Default::default()`
+    Default,
+    /// A function with the signature `fn() -> T`.
+    Path(syn::Path),
 }
 
 #[derive(Debug, Default)]
 pub struct AttrBuilder {
     to_variant_with: Option<syn::Path>,
     from_variant_with: Option<syn::Path>,
+    skip_to_variant: Option<syn::Path>,
+    skip_from_variant: Option<syn::Path>,
+    rename: Option<syn::LitStr>,
+    default: Option<FieldDefault>,
+    flatten: Option<syn::Path>,
     errors: Vec<syn::Error>,
 }
 
@@ -35,8 +54,43 @@ impl AttrBuilder {
     }
 
     fn set_flag(&mut self, flag: &syn::Path) {
-        self.errors
-            .push(syn::Error::new(flag.span(), "unknown flag"));
+        let err = self.try_set_flag(flag).err();
+        self.errors.extend(err);
+    }
+
+    fn try_set_flag(&mut self, flag: &syn::Path) -> Result<(), syn::Error> {
+        let name = flag
+            .get_ident()
+            .ok_or_else(|| syn::Error::new(flag.span(), "flag should be single ident"))?
+            .to_string();
+
+        let slots = match name.as_str() {
+            "skip" => vec![&mut self.skip_to_variant, &mut self.skip_from_variant],
+            "skip_to_variant" => vec![&mut self.skip_to_variant],
+            "skip_from_variant" => vec![&mut self.skip_from_variant],
+            "flatten" => vec![&mut self.flatten],
+            "default" => {
+                if self.default.replace(FieldDefault::Default).is_some() {
+                    return Err(syn::Error::new(
+                        flag.span(),
+                        "the argument default is already set",
+                    ));
+                }
+                return Ok(());
+            }
+            _ => return Err(syn::Error::new(flag.span(), "unknown flag")),
+        };
+
+        for slot in slots {
+            if slot.replace(flag.clone()).is_some() {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    format!("the flag {} is already set", name),
+                ));
+            }
+        }
+
+        Ok(())
     }
 
     fn set_pair(&mut self, pair: &syn::MetaNameValue) {
@@ -89,6 +143,36 @@ impl AttrBuilder {
         }
 
         match name.as_str() {
+            "rename" => {
+                let lit_str = match lit {
+                    syn::Lit::Str(lit_str) => lit_str.clone(),
+                    _ => return Err(syn::Error::new(lit.span(), "expected string literal")),
+                };
+
+                if self.rename.replace(lit_str).is_some() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "the argument rename is already set",
+                    ));
+                }
+
+                return Ok(());
+            }
+            "default" => {
+                let path = match lit {
+                    syn::Lit::Str(lit_str) => lit_str.parse::<syn::Path>()?,
+                    _ => return Err(syn::Error::new(lit.span(), "expected string literal")),
+                };
+
+                if self.default.replace(FieldDefault::Path(path)).is_some() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "the argument default is already set",
+                    ));
+                }
+
+                return Ok(());
+            }
             "with" => {
                 let path = match lit {
                     syn::Lit::Str(lit_str) => lit_str.parse::<syn::Path>()?,
@@ -145,9 +229,126 @@ impl AttrBuilder {
             Ok(Attr {
                 to_variant_with: self.to_variant_with,
                 from_variant_with: self.from_variant_with,
+                skip_to_variant: self.skip_to_variant.is_some(),
+                skip_from_variant: self.skip_from_variant.is_some(),
+                rename: self.rename,
+                default: self.default,
+                flatten: self.flatten.is_some(),
             })
         } else {
             Err(self.errors)
         }
     }
 }
+
+/// Attributes on the type itself.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ContainerAttr {
+    pub tag: Option<syn::LitStr>,
+    pub content: Option<syn::LitStr>,
+    pub untagged: Option<syn::Path>,
+    pub as_int: Option<syn::Path>,
+    pub rename_all: Option<syn::LitStr>,
+}
+
+#[derive(Debug, Default)]
+pub struct ContainerAttrBuilder {
+    attr: ContainerAttr,
+    errors: Vec<syn::Error>,
+}
+
+impl ContainerAttrBuilder {
+    fn extend_meta(&mut self, meta: &syn::Meta) {
+        match meta {
+            syn::Meta::Path(flag) => self.set_flag(flag),
+            syn::Meta::NameValue(pair) => self.set_pair(pair),
+            syn::Meta::List(list) => {
+                for nested in list.nested.iter() {
+                    match nested {
+                        syn::NestedMeta::Meta(meta) => self.extend_meta(meta),
+                        _ => {
+                            self.errors
+                                .push(syn::Error::new(nested.span(), "unexpected nested meta"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn set_flag(&mut self, flag: &syn::Path) {
+        let slot = if flag.is_ident("untagged") {
+            &mut self.attr.untagged
+        } else if flag.is_ident("as_int") {
+            &mut self.attr.as_int
+        } else {
+            self.errors
+                .push(syn::Error::new(flag.span(), "unknown flag"));
+            return;
+        };
+
+        if slot.replace(flag.clone()).is_some() {
+            self.errors
+                .push(syn::Error::new(flag.span(), "the flag is already set"));
+        }
+    }
+
+    fn set_pair(&mut self, pair: &syn::MetaNameValue) {
+        let err = self.try_set_pair(pair).err();
+        self.errors.extend(err);
+    }
+
+    fn try_set_pair(&mut self, pair: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        let syn::MetaNameValue { path, lit, .. } = pair;
+
+        let slot = if path.is_ident("tag") {
+            &mut self.attr.tag
+        } else if path.is_ident("content") {
+            &mut self.attr.content
+        } else if path.is_ident("rename_all") {
+            &mut self.attr.rename_all
+        } else {
+            return Err(syn::Error::new(path.span(), "unknown argument"));
+        };
+
+        let lit_str = match lit {
+            syn::Lit::Str(lit_str) => lit_str.clone(),
+            _ => return Err(syn::Error::new(lit.span(), "expected string literal")),
+        };
+
+        if slot.replace(lit_str).is_some() {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "the argument {} is already set",
+                    path.get_ident().expect("should be ident")
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromIterator<syn::Meta> for ContainerAttrBuilder {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = syn::Meta>,
+    {
+        let mut builder = ContainerAttrBuilder::default();
+        for meta in iter {
+            builder.extend_meta(&meta);
+        }
+        builder
+    }
+}
+
+impl ContainerAttrBuilder {
+    pub fn done(self) -> Result<ContainerAttr, Vec<syn::Error>> {
+        if self.errors.is_empty() {
+            Ok(self.attr)
+        } else {
+            Err(self.errors)
+        }
+    }
+}
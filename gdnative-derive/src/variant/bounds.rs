@@ -3,9 +3,16 @@ use std::collections::HashSet;
 use syn::visit::{self, Visit};
 use syn::{Generics, Ident, Type, TypePath};
 
+use super::attr::FieldDefault;
 use super::repr::{Field, Repr, VariantRepr};
+use super::Direction;
 
-pub(crate) fn extend_bounds(generics: Generics, repr: &Repr, bound: &syn::Path) -> Generics {
+pub(crate) fn extend_bounds(
+    generics: Generics,
+    repr: &Repr,
+    bound: &syn::Path,
+    direction: Direction,
+) -> Generics {
     // recursively visit all the field types to find what types should be bounded
     struct Visitor<'ast> {
         all_type_params: HashSet<Ident>,
@@ -35,41 +42,63 @@ pub(crate) fn extend_bounds(generics: Generics, repr: &Repr, bound: &syn::Path)
         }
     }
 
-    let all_type_params = generics
+    let all_type_params: HashSet<Ident> = generics
         .type_params()
         .map(|param| param.ident.clone())
         .collect();
 
     let mut visitor = Visitor {
-        all_type_params: all_type_params,
+        all_type_params: all_type_params.clone(),
+        used: HashSet::new(),
+    };
+
+    // types of fields that are initialized with `Default::default()` need to be bounded by
+    // `Default` instead
+    let mut default_visitor = Visitor {
+        all_type_params,
         used: HashSet::new(),
     };
 
     // iterate through parsed variant representations and visit the types of each field
-    fn visit_var_repr<'ast>(visitor: &mut Visitor<'ast>, repr: &'ast VariantRepr) {
-        match repr {
-            VariantRepr::Unit => {}
-            VariantRepr::Tuple(tys) => {
-                for Field { ty, .. } in tys.iter() {
-                    visitor.visit_type(ty);
-                }
+    fn visit_var_repr<'ast>(
+        visitor: &mut Visitor<'ast>,
+        default_visitor: &mut Visitor<'ast>,
+        repr: &'ast VariantRepr,
+        direction: Direction,
+    ) {
+        let fields = match repr {
+            VariantRepr::Unit => return,
+            VariantRepr::Tuple(fields) | VariantRepr::Struct(fields) => fields,
+        };
+
+        for Field { ty, attr, .. } in fields.iter() {
+            let (skip, uses_default) = match direction {
+                Direction::ToVariant => (attr.skip_to_variant, false),
+                Direction::FromVariant => (
+                    attr.skip_from_variant,
+                    (attr.skip_from_variant || attr.default.is_some())
+                        && !matches!(attr.default, Some(FieldDefault::Path(_))),
+                ),
+            };
+
+            if !skip {
+                visitor.visit_type(ty);
             }
-            VariantRepr::Struct(fields) => {
-                for Field { ty, .. } in fields.iter() {
-                    visitor.visit_type(ty);
-                }
+
+            if uses_default {
+                default_visitor.visit_type(ty);
             }
         }
     }
 
     match repr {
-        Repr::Enum(ref variants) => {
-            for (_, var_repr) in variants.iter() {
-                visit_var_repr(&mut visitor, var_repr);
+        Repr::Enum(_, ref variants) => {
+            for variant in variants.iter() {
+                visit_var_repr(&mut visitor, &mut default_visitor, &variant.repr, direction);
             }
         }
         Repr::Struct(var_repr) => {
-            visit_var_repr(&mut visitor, var_repr);
+            visit_var_repr(&mut visitor, &mut default_visitor, var_repr, direction);
         }
     }
 
@@ -91,11 +120,18 @@ pub(crate) fn extend_bounds(generics: Generics, repr: &Repr, bound: &syn::Path)
     }
 
     // place bounds on all used type parameters and associated types
-    let new_predicates = visitor
-        .used
-        .into_iter()
-        .cloned()
-        .map(|bounded_ty| where_predicate(syn::Type::Path(bounded_ty), bound.clone()));
+    let default_bound: syn::Path = syn::parse2(quote! { ::std::default::Default }).unwrap();
+
+    let new_predicates =
+        visitor
+            .used
+            .into_iter()
+            .cloned()
+            .map(|bounded_ty| where_predicate(syn::Type::Path(bounded_ty), bound.clone()))
+            .chain(default_visitor.used.into_iter().cloned().map(|bounded_ty| {
+                where_predicate(syn::Type::Path(bounded_ty), default_bound.clone())
+            }))
+            .collect::<Vec<_>>();
 
     let mut generics = generics.clone();
     generics
@@ -1,12 +1,12 @@
 use proc_macro::TokenStream;
-use proc_macro2::{Literal, Span};
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
 
-use syn::Ident;
+use syn::{Ident, LitStr};
 
-use super::repr::Repr;
+use super::repr::{EnumTagging, EnumVariant, Repr, VariantRepr};
 use super::DeriveData;
 
-pub(crate) fn expand_from_variant(derive_data: DeriveData) -> TokenStream {
+pub(crate) fn expand_from_variant(derive_data: DeriveData) -> Result<TokenStream, syn::Error> {
     let DeriveData {
         ident,
         repr,
@@ -28,69 +28,230 @@ pub(crate) fn expand_from_variant(derive_data: DeriveData) -> TokenStream {
                 }
             }
         }
-        Repr::Enum(variants) => {
+        Repr::Enum(tagging, variants) => {
             if variants.is_empty() {
-                panic!("cannot derive FromVariant for an uninhabited enum");
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "cannot derive FromVariant for an uninhabited enum",
+                ));
             }
 
             let var_input_ident = Ident::new("__enum_variant", Span::call_site());
 
-            let var_ident_strings: Vec<String> = variants
+            let var_ident_string_literals = variants
                 .iter()
-                .map(|(var_ident, _)| format!("{}", var_ident))
-                .collect();
+                .map(|variant| Literal::string(&variant.name))
+                .collect::<Vec<_>>();
+
+            let ref_var_ident_string_literals = &var_ident_string_literals;
 
-            let var_ident_string_literals = var_ident_strings
+            let var_from_variants = variants
                 .iter()
-                .map(|string| Literal::string(&string))
+                .map(
+                    |EnumVariant {
+                         ident: var_ident,
+                         repr: var_repr,
+                         ..
+                     }| {
+                        var_repr.from_variant(&var_input_ident, &quote! { #ident::#var_ident })
+                    },
+                )
                 .collect::<Vec<_>>();
 
-            let ref_var_ident_string_literals = &var_ident_string_literals;
+            match tagging {
+                EnumTagging::External => {
+                    let match_arms = var_ident_string_literals
+                        .iter()
+                        .zip(&var_from_variants)
+                        .map(|(var_ident_string_literal, var_from_variant)| {
+                            quote! {
+                                #var_ident_string_literal => {
+                                    let #var_input_ident = __dict.get_ref(__keys.get_ref(0));
+                                    (#var_from_variant).map_err(|err| FVE::InvalidEnumVariant {
+                                        variant: #var_ident_string_literal,
+                                        error: Box::new(err),
+                                    })
+                                },
+                            }
+                        });
 
-            let var_from_variants = variants.iter().map(|(var_ident, var_repr)| {
-                var_repr.from_variant(&var_input_ident, &quote! { #ident::#var_ident })
-            });
+                    quote! {
+                        {
+                            let __dict = ::gdnative::Dictionary::from_variant(#input_ident)
+                                .map_err(|__err| FVE::InvalidEnumRepr {
+                                    expected: VariantEnumRepr::ExternallyTagged,
+                                    error: Box::new(__err),
+                                })?;
 
-            let var_input_ident_iter = std::iter::repeat(&var_input_ident);
+                            let __keys = __dict.keys();
+                            if __keys.len() != 1 {
+                                Err(FVE::InvalidEnumRepr {
+                                    expected: VariantEnumRepr::ExternallyTagged,
+                                    error: Box::new(FVE::InvalidLength {
+                                        expected: 1,
+                                        len: __keys.len() as usize,
+                                    }),
+                                })
+                            }
+                            else {
+                                let __key = String::from_variant(__keys.get_ref(0))
+                                    .map_err(|__err| FVE::InvalidEnumRepr {
+                                        expected: VariantEnumRepr::ExternallyTagged,
+                                        error: Box::new(__err),
+                                    })?;
+                                match __key.as_str() {
+                                    #( #match_arms )*
+                                    variant => Err(FVE::UnknownEnumVariant {
+                                        variant: variant.to_string(),
+                                        expected: &[#(#ref_var_ident_string_literals),*],
+                                    }),
+                                }
+                            }
+                        }
+                    }
+                }
+                EnumTagging::Internal { tag } => {
+                    let expected = quote! { VariantEnumRepr::InternallyTagged };
+                    let match_arms = variants.iter().zip(&var_ident_string_literals).zip(&var_from_variants).map(
+                        |((EnumVariant { ident: var_ident, repr: var_repr, .. }, var_ident_string_literal), var_from_variant)| {
+                            match var_repr {
+                                VariantRepr::Unit => quote! {
+                                    #var_ident_string_literal => Ok(#ident::#var_ident),
+                                },
+                                _ => quote! {
+                                    #var_ident_string_literal => {
+                                        let #var_input_ident = #input_ident;
+                                        (#var_from_variant).map_err(|err| FVE::InvalidEnumVariant {
+                                            variant: #var_ident_string_literal,
+                                            error: Box::new(err),
+                                        })
+                                    },
+                                },
+                            }
+                        },
+                    );
 
-            quote! {
-                {
-                    let __dict = ::gdnative::Dictionary::from_variant(#input_ident)
-                        .map_err(|__err| FVE::InvalidEnumRepr {
-                            expected: VariantEnumRepr::ExternallyTagged,
-                            error: Box::new(__err),
-                        })?;
-
-                    let __keys = __dict.keys();
-                    if __keys.len() != 1 {
-                        Err(FVE::InvalidEnumRepr {
-                            expected: VariantEnumRepr::ExternallyTagged,
-                            error: Box::new(FVE::InvalidLength {
-                                expected: 1,
-                                len: __keys.len() as usize,
-                            }),
-                        })
+                    let read_tag = read_tag(&input_ident, &expected, &tag);
+
+                    quote! {
+                        {
+                            #read_tag
+                            match __tag.as_str() {
+                                #( #match_arms )*
+                                variant => Err(FVE::UnknownEnumVariant {
+                                    variant: variant.to_string(),
+                                    expected: &[#(#ref_var_ident_string_literals),*],
+                                }),
+                            }
+                        }
                     }
-                    else {
-                        let __key = String::from_variant(__keys.get_ref(0))
-                            .map_err(|__err| FVE::InvalidEnumRepr {
-                                expected: VariantEnumRepr::ExternallyTagged,
-                                error: Box::new(__err),
-                            })?;
-                        match __key.as_str() {
-                            #(
-                                #ref_var_ident_string_literals => {
-                                    let #var_input_ident_iter = __dict.get_ref(__keys.get_ref(0));
-                                    (#var_from_variants).map_err(|err| FVE::InvalidEnumVariant {
-                                        variant: "Ok",
-                                        error: Box::new(err),
-                                    })
+                }
+                EnumTagging::Adjacent { tag, content } => {
+                    let expected = quote! { VariantEnumRepr::AdjacentlyTagged };
+                    let match_arms = variants.iter().zip(&var_ident_string_literals).zip(&var_from_variants).map(
+                        |((EnumVariant { ident: var_ident, repr: var_repr, .. }, var_ident_string_literal), var_from_variant)| {
+                            match var_repr {
+                                VariantRepr::Unit => quote! {
+                                    #var_ident_string_literal => Ok(#ident::#var_ident),
+                                },
+                                _ => quote! {
+                                    #var_ident_string_literal => {
+                                        let __content_key = ::gdnative::GodotString::from(#content).to_variant();
+                                        if !__dict.contains(&__content_key) {
+                                            return Err(FVE::InvalidEnumRepr {
+                                                expected: #expected,
+                                                error: Box::new(FVE::InvalidField {
+                                                    field_name: #content,
+                                                    error: Box::new(FVE::InvalidNil),
+                                                }),
+                                            });
+                                        }
+                                        let #var_input_ident = __dict.get_ref(&__content_key);
+                                        (#var_from_variant).map_err(|err| FVE::InvalidEnumVariant {
+                                            variant: #var_ident_string_literal,
+                                            error: Box::new(err),
+                                        })
+                                    },
                                 },
+                            }
+                        },
+                    );
+
+                    let read_tag = read_tag(&input_ident, &expected, &tag);
+
+                    quote! {
+                        {
+                            #read_tag
+                            match __tag.as_str() {
+                                #( #match_arms )*
+                                variant => Err(FVE::UnknownEnumVariant {
+                                    variant: variant.to_string(),
+                                    expected: &[#(#ref_var_ident_string_literals),*],
+                                }),
+                            }
+                        }
+                    }
+                }
+                EnumTagging::Untagged => {
+                    let attempts = variants.iter().zip(&var_from_variants).map(
+                        |(
+                            EnumVariant {
+                                ident: var_ident,
+                                repr: var_repr,
+                                ..
+                            },
+                            var_from_variant,
+                        )| match var_repr {
+                            VariantRepr::Unit => quote! {
+                                if #var_input_ident.is_nil() {
+                                    return Ok(#ident::#var_ident);
+                                }
+                            },
+                            _ => quote! {
+                                let __result: Result<Self, FVE> = #var_from_variant;
+                                if let Ok(__value) = __result {
+                                    return Ok(__value);
+                                }
+                            },
+                        },
+                    );
+
+                    let message = Literal::string(&format!(
+                        "data did not match any variant of untagged enum {}",
+                        ident
+                    ));
+
+                    quote! {
+                        {
+                            let #var_input_ident = #input_ident;
+                            #( #attempts )*
+                            Err(FVE::InvalidEnumRepr {
+                                expected: VariantEnumRepr::Untagged,
+                                error: Box::new(FVE::custom(#message)),
+                            })
+                        }
+                    }
+                }
+                EnumTagging::AsInt { .. } => {
+                    let var_idents = variants.iter().map(|variant| &variant.ident);
+                    let var_idents_ctor = variants.iter().map(|variant| &variant.ident);
+
+                    quote! {
+                        {
+                            let __value = i64::from_variant(#input_ident)
+                                .map_err(|__err| FVE::InvalidEnumRepr {
+                                    expected: VariantEnumRepr::Int,
+                                    error: Box::new(__err),
+                                })?;
+                            #(
+                                if __value == #ident::#var_idents as i64 {
+                                    return Ok(#ident::#var_idents_ctor);
+                                }
                             )*
-                            variant => Err(FVE::UnknownEnumVariant {
-                                variant: variant.to_string(),
+                            Err(FVE::UnknownEnumVariant {
+                                variant: __value.to_string(),
                                 expected: &[#(#ref_var_ident_string_literals),*],
-                            }),
+                            })
                         }
                     }
                 }
@@ -116,5 +277,28 @@ pub(crate) fn expand_from_variant(derive_data: DeriveData) -> TokenStream {
         }
     };
 
-    result.into()
+    Ok(result.into())
+}
+
+/// Reads the tag of an internally or adjacently tagged enum into `__tag`, and the dictionary
+/// into `__dict`.
+fn read_tag(input_ident: &Ident, expected: &TokenStream2, tag: &LitStr) -> TokenStream2 {
+    quote! {
+        let __dict = ::gdnative::Dictionary::from_variant(#input_ident)
+            .map_err(|__err| FVE::InvalidEnumRepr {
+                expected: #expected,
+                error: Box::new(__err),
+            })?;
+
+        let __tag = __dict
+            .get_as::<str, String>(#tag)
+            .and_then(|__tag| __tag.ok_or(FVE::InvalidNil))
+            .map_err(|__err| FVE::InvalidEnumRepr {
+                expected: #expected,
+                error: Box::new(FVE::InvalidField {
+                    field_name: #tag,
+                    error: Box::new(__err),
+                }),
+            })?;
+    }
 }
@@ -0,0 +1,122 @@
+/// Case conventions that can be used with `#[variant(rename_all = "...")]`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    pub(crate) fn from_str(rule: &str) -> Option<Self> {
+        let rule = match rule {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            _ => return None,
+        };
+
+        Some(rule)
+    }
+
+    /// Applies the rule to a field name, which is expected to be in `snake_case`.
+    pub(crate) fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_owned(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal | RenameRule::Camel => {
+                let mut renamed = String::with_capacity(field.len());
+                let mut capitalize = self == RenameRule::Pascal;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        renamed.extend(c.to_uppercase());
+                        capitalize = false;
+                    } else {
+                        renamed.push(c);
+                    }
+                }
+                renamed
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+
+    /// Applies the rule to an enum variant name, which is expected to be in `PascalCase`.
+    pub(crate) fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_owned(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            }
+            _ => {
+                let mut snake = String::with_capacity(variant.len() + 4);
+                for (i, c) in variant.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.extend(c.to_lowercase());
+                }
+                self.apply_to_field(&snake)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rename_fields() {
+        let cases = [
+            (RenameRule::Lower, "max_hp"),
+            (RenameRule::Upper, "MAX_HP"),
+            (RenameRule::Pascal, "MaxHp"),
+            (RenameRule::Camel, "maxHp"),
+            (RenameRule::Snake, "max_hp"),
+            (RenameRule::ScreamingSnake, "MAX_HP"),
+            (RenameRule::Kebab, "max-hp"),
+            (RenameRule::ScreamingKebab, "MAX-HP"),
+        ];
+
+        for &(rule, expected) in cases.iter() {
+            assert_eq!(expected, rule.apply_to_field("max_hp"));
+        }
+    }
+
+    #[test]
+    fn rename_variants() {
+        let cases = [
+            (RenameRule::Lower, "fireball"),
+            (RenameRule::Upper, "FIREBALL"),
+            (RenameRule::Pascal, "FireBall"),
+            (RenameRule::Camel, "fireBall"),
+            (RenameRule::Snake, "fire_ball"),
+            (RenameRule::ScreamingSnake, "FIRE_BALL"),
+            (RenameRule::Kebab, "fire-ball"),
+            (RenameRule::ScreamingKebab, "FIRE-BALL"),
+        ];
+
+        for &(rule, expected) in cases.iter() {
+            assert_eq!(expected, rule.apply_to_variant("FireBall"));
+        }
+    }
+}
@@ -1,12 +1,39 @@
 use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
-use syn::{Fields, Ident, Type};
+use syn::spanned::Spanned;
+use syn::{DataEnum, Fields, Ident, LitStr, Type};
 
-use super::attr::{Attr, AttrBuilder};
+use super::attr::{Attr, AttrBuilder, ContainerAttr, ContainerAttrBuilder, FieldDefault};
+use super::rename::RenameRule;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub(crate) enum Repr {
     Struct(VariantRepr),
-    Enum(Vec<(Ident, VariantRepr)>),
+    Enum(EnumTagging, Vec<EnumVariant>),
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) struct EnumVariant {
+    pub ident: Ident,
+    /// Name of the variant in tags.
+    pub name: String,
+    pub repr: VariantRepr,
+}
+
+/// Representation of enums, set with container attributes.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) enum EnumTagging {
+    /// `{ "Variant": value }`, the default.
+    External,
+    /// `{ "tag": "Variant", "field": value }`, with `#[variant(tag = "tag")]`.
+    Internal { tag: LitStr },
+    /// `{ "tag": "Variant", "content": value }`, with
+    /// `#[variant(tag = "tag", content = "content")]`.
+    Adjacent { tag: LitStr, content: LitStr },
+    /// `value`, with `#[variant(untagged)]`.
+    Untagged,
+    /// The discriminant as an integer, with `#[variant(as_int)]`. `explicit_discriminants` is
+    /// set if any of the variants has an explicitly specified discriminant.
+    AsInt { explicit_discriminants: bool },
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -19,11 +46,26 @@ pub(crate) enum VariantRepr {
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub(crate) struct Field {
     pub ident: Ident,
+    /// Key of the field in dictionaries, or its index in tuples.
+    pub name: String,
     pub ty: Type,
     pub attr: Attr,
 }
 
-fn parse_attrs<'a, I>(attrs: I) -> Result<Attr, Vec<syn::Error>>
+fn combine_errors(errors: Vec<syn::Error>) -> syn::Error {
+    errors
+        .into_iter()
+        .fold(None, |combined: Option<syn::Error>, err| match combined {
+            Some(mut combined) => {
+                combined.combine(err);
+                Some(combined)
+            }
+            None => Some(err),
+        })
+        .expect("done should only fail with errors")
+}
+
+fn parse_attrs<'a, I>(attrs: I) -> Result<Attr, syn::Error>
 where
     I: IntoIterator<Item = &'a syn::Attribute>,
 {
@@ -31,14 +73,110 @@ where
         .into_iter()
         .filter(|attr| attr.path.is_ident("variant"))
         .map(|attr| attr.parse_meta())
-        .collect::<Result<AttrBuilder, syn::Error>>()
-        .map_err(|err| vec![err])?
+        .collect::<Result<AttrBuilder, syn::Error>>()?
         .done()
+        .map_err(combine_errors)
+}
+
+pub(crate) fn parse_container_attrs<'a, I>(attrs: I) -> Result<ContainerAttr, syn::Error>
+where
+    I: IntoIterator<Item = &'a syn::Attribute>,
+{
+    attrs
+        .into_iter()
+        .filter(|attr| attr.path.is_ident("variant"))
+        .map(|attr| attr.parse_meta())
+        .collect::<Result<ContainerAttrBuilder, syn::Error>>()?
+        .done()
+        .map_err(combine_errors)
+}
+
+impl EnumTagging {
+    pub(crate) fn from_attr(
+        attr: ContainerAttr,
+        enum_data: &DataEnum,
+        variants: &[EnumVariant],
+    ) -> Result<Self, syn::Error> {
+        let ContainerAttr {
+            tag,
+            content,
+            untagged,
+            as_int,
+            ..
+        } = attr;
+
+        let tagging = match (tag, content, untagged, as_int) {
+            (None, None, None, None) => EnumTagging::External,
+            (Some(tag), None, None, None) => EnumTagging::Internal { tag },
+            (Some(tag), Some(content), None, None) => EnumTagging::Adjacent { tag, content },
+            (None, None, Some(_), None) => EnumTagging::Untagged,
+            (None, None, None, Some(_)) => EnumTagging::AsInt {
+                explicit_discriminants: enum_data
+                    .variants
+                    .iter()
+                    .any(|variant| variant.discriminant.is_some()),
+            },
+            (None, Some(content), None, None) => {
+                return Err(syn::Error::new(
+                    content.span(),
+                    "the argument content requires tag to be set",
+                ))
+            }
+            (_, _, Some(untagged), _) => {
+                return Err(syn::Error::new(
+                    untagged.span(),
+                    "untagged cannot be combined with other representation attributes",
+                ))
+            }
+            (_, _, _, Some(as_int)) => {
+                return Err(syn::Error::new(
+                    as_int.span(),
+                    "as_int cannot be combined with other representation attributes",
+                ))
+            }
+        };
+
+        for (variant, enum_variant) in enum_data.variants.iter().zip(variants) {
+            match (&tagging, &enum_variant.repr) {
+                (EnumTagging::Internal { .. }, VariantRepr::Tuple(_)) => {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        "internally tagged enums only support unit and struct variants",
+                    ));
+                }
+                (EnumTagging::Internal { tag }, VariantRepr::Struct(fields)) => {
+                    let tag = tag.value();
+                    if let Some(field) = fields.iter().find(|f| !f.attr.flatten && f.name == tag) {
+                        return Err(syn::Error::new(
+                            field.ident.span(),
+                            format!(
+                                "field {} conflicts with the tag of {}",
+                                field.ident, enum_variant.ident
+                            ),
+                        ));
+                    }
+                }
+                (EnumTagging::AsInt { .. }, VariantRepr::Tuple(_))
+                | (EnumTagging::AsInt { .. }, VariantRepr::Struct(_)) => {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        "as_int is only supported on enums with unit variants only",
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(tagging)
+    }
 }
 
 impl VariantRepr {
-    pub(crate) fn repr_for(fields: &Fields) -> Self {
-        match fields {
+    pub(crate) fn repr_for(
+        fields: &Fields,
+        rename_all: Option<RenameRule>,
+    ) -> Result<Self, syn::Error> {
+        let repr = match fields {
             Fields::Named(fields) => VariantRepr::Struct(
                 fields
                     .named
@@ -46,11 +184,29 @@ impl VariantRepr {
                     .map(|f| {
                         let ident = f.ident.clone().expect("fields should be named");
                         let ty = f.ty.clone();
-                        let attr =
-                            parse_attrs(&f.attrs).expect("should be able to parse attribute");
-                        Field { ident, ty, attr }
+                        let attr = parse_attrs(&f.attrs)?;
+
+                        if attr.flatten && (attr.rename.is_some() || attr.default.is_some()) {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                "flatten cannot be combined with rename or default",
+                            ));
+                        }
+
+                        let name = match (&attr.rename, rename_all) {
+                            (Some(rename), _) => rename.value(),
+                            (None, Some(rule)) => rule.apply_to_field(&ident.to_string()),
+                            (None, None) => ident.to_string(),
+                        };
+
+                        Ok(Field {
+                            ident,
+                            name,
+                            ty,
+                            attr,
+                        })
                     })
-                    .collect(),
+                    .collect::<Result<_, syn::Error>>()?,
             ),
             Fields::Unnamed(fields) => VariantRepr::Tuple(
                 fields
@@ -60,27 +216,55 @@ impl VariantRepr {
                     .map(|(n, f)| {
                         let ident = Ident::new(&format!("__field_{}", n), Span::call_site());
                         let ty = f.ty.clone();
-                        let attr =
-                            parse_attrs(&f.attrs).expect("should be able to parse attribute");
-                        Field { ident, ty, attr }
+                        let attr = parse_attrs(&f.attrs)?;
+
+                        if attr.rename.is_some() || attr.default.is_some() || attr.flatten {
+                            return Err(syn::Error::new(
+                                f.span(),
+                                "rename, default and flatten are only supported on named fields",
+                            ));
+                        }
+
+                        Ok(Field {
+                            name: n.to_string(),
+                            ident,
+                            ty,
+                            attr,
+                        })
                     })
-                    .collect(),
+                    .collect::<Result<_, syn::Error>>()?,
             ),
             Fields::Unit => VariantRepr::Unit,
-        }
+        };
+
+        Ok(repr)
     }
 
     pub(crate) fn destructure_pattern(&self) -> TokenStream2 {
         match self {
             VariantRepr::Unit => quote! {},
             VariantRepr::Tuple(fields) => {
-                let names = fields.iter().map(|f| &f.ident);
+                let names = fields.iter().map(|f| {
+                    if f.attr.skip_to_variant {
+                        quote!(_)
+                    } else {
+                        let ident = &f.ident;
+                        quote!(#ident)
+                    }
+                });
                 quote! {
                     ( #( #names ),* )
                 }
             }
             VariantRepr::Struct(fields) => {
-                let names = fields.iter().map(|f| &f.ident);
+                let names = fields.iter().map(|f| {
+                    let ident = &f.ident;
+                    if f.attr.skip_to_variant {
+                        quote!(#ident: _)
+                    } else {
+                        quote!(#ident)
+                    }
+                });
                 quote! {
                     { #( #names ),* }
                 }
@@ -94,11 +278,14 @@ impl VariantRepr {
                 quote! { ::gdnative::Dictionary::new().to_variant() }
             }
             VariantRepr::Tuple(fields) => {
-                if fields.len() == 1 {
+                if fields.len() == 1 && !fields[0].attr.skip_to_variant {
                     // as newtype
-                    fields.get(0).unwrap().to_variant()
+                    fields[0].to_variant()
                 } else {
-                    let exprs = fields.iter().map(Field::to_variant);
+                    let exprs = fields
+                        .iter()
+                        .filter(|f| !f.attr.skip_to_variant)
+                        .map(Field::to_variant);
                     quote! {
                         {
                             let mut __array = ::gdnative::VariantArray::new();
@@ -110,26 +297,12 @@ impl VariantRepr {
                     }
                 }
             }
-            VariantRepr::Struct(fields) => {
-                let names: Vec<&Ident> = fields.iter().map(|f| &f.ident).collect();
-
-                let name_strings: Vec<String> =
-                    names.iter().map(|ident| format!("{}", ident)).collect();
-
-                let name_string_literals =
-                    name_strings.iter().map(|string| Literal::string(&string));
-
-                let exprs = fields.iter().map(Field::to_variant);
-
+            VariantRepr::Struct(_) => {
+                let set_fields = self.set_fields();
                 quote! {
                     {
                         let mut __dict = ::gdnative::Dictionary::new();
-                        #(
-                            {
-                                let __key = ::gdnative::GodotString::from(#name_string_literals).to_variant();
-                                __dict.set(&__key, &#exprs);
-                            }
-                        )*
+                        #set_fields
                         __dict.to_variant()
                     }
                 }
@@ -137,6 +310,52 @@ impl VariantRepr {
         }
     }
 
+    /// Sets the fields of a struct variant on a `Dictionary` named `__dict`.
+    pub(crate) fn set_fields(&self) -> TokenStream2 {
+        let fields = match self {
+            VariantRepr::Struct(fields) => fields,
+            _ => return quote! {},
+        };
+
+        let stmts = fields
+            .iter()
+            .filter(|f| !f.attr.skip_to_variant)
+            .map(|f| {
+                let expr = f.to_variant();
+                let name_string_literal = Literal::string(&f.name);
+                if f.attr.flatten {
+                    // values that aren't dictionaries are kept under the name of the field
+                    quote! {
+                        {
+                            let __value = #expr;
+                            match __value.try_to_dictionary() {
+                                Some(__flattened) => {
+                                    for (__key, __value) in __flattened.iter() {
+                                        __dict.set(&__key, &__value);
+                                    }
+                                }
+                                None => {
+                                    let __key = ::gdnative::GodotString::from(#name_string_literal).to_variant();
+                                    __dict.set(&__key, &__value);
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    quote! {
+                        {
+                            let __key = ::gdnative::GodotString::from(#name_string_literal).to_variant();
+                            __dict.set(&__key, &#expr);
+                        }
+                    }
+                }
+            });
+
+        quote! {
+            #( #stmts )*
+        }
+    }
+
     pub(crate) fn from_variant(&self, variant: &Ident, ctor: &TokenStream2) -> TokenStream2 {
         match self {
             VariantRepr::Unit => {
@@ -153,23 +372,28 @@ impl VariantRepr {
                 }
             }
             VariantRepr::Tuple(fields) => {
-                if fields.len() == 1 {
+                if fields.len() == 1 && !fields[0].attr.skip_from_variant {
                     // as newtype
-                    let expr = fields.get(0).unwrap().from_variant(&quote!(#variant));
+                    let expr = fields[0].from_variant(&quote!(#variant));
                     quote! {
                         {
                             #expr.map(#ctor)
                         }
                     }
                 } else {
-                    let types: Vec<&Type> = fields.iter().map(|f| &f.ty).collect();
+                    let (skipped, fields): (Vec<&Field>, Vec<&Field>) =
+                        fields.iter().partition(|f| f.attr.skip_from_variant);
+
+                    let skipped_idents = skipped.iter().map(|f| &f.ident);
+                    let skipped_defaults = skipped.iter().map(|f| f.default_value());
+
                     let idents: Vec<&Ident> = fields.iter().map(|f| &f.ident).collect();
 
                     let decl_idents = idents.iter();
-                    let ctor_idents = idents.iter();
+                    let ctor_idents = self.field_idents();
 
-                    let self_len = Literal::i32_suffixed(types.len() as i32);
-                    let indices = (0..fields.len() as i32).map(|n| Literal::i32_suffixed(n));
+                    let self_len = Literal::usize_suffixed(fields.len());
+                    let indices = (0..fields.len() as i32).map(Literal::i32_suffixed);
 
                     let expr_variant = &quote!(__array.get_ref(__index));
                     let exprs = fields.iter().map(|f| f.from_variant(expr_variant));
@@ -191,11 +415,14 @@ impl VariantRepr {
                                         #(
                                             let __index = #indices;
                                             let #decl_idents = #exprs
-                                                .map_err(|err| FromVariantError::InvalidItem {
+                                                .map_err(|err| FVE::InvalidItem {
                                                     index: __index as usize,
                                                     error: Box::new(err),
                                                 })?;
                                         )*
+                                        #(
+                                            let #skipped_idents = #skipped_defaults;
+                                        )*
                                         Ok(#ctor( #(#ctor_idents),* ))
                                     }
                                 })
@@ -204,19 +431,67 @@ impl VariantRepr {
                 }
             }
             VariantRepr::Struct(fields) => {
-                let names: Vec<&Ident> = fields.iter().map(|f| &f.ident).collect();
+                let ctor_idents = self.field_idents();
 
-                let name_strings: Vec<String> =
-                    names.iter().map(|ident| format!("{}", ident)).collect();
+                let decls = fields.iter().map(|f| {
+                    let ident = &f.ident;
+                    if f.attr.skip_from_variant {
+                        let default_value = f.default_value();
+                        return quote! {
+                            let #ident = #default_value;
+                        };
+                    }
 
-                let name_string_literals =
-                    name_strings.iter().map(|string| Literal::string(&string));
+                    if f.attr.flatten {
+                        let field_name_literal = Literal::string(&f.ident.to_string());
+                        let name_string_literal = Literal::string(&f.name);
+                        let expr = f.from_variant(&quote!(#variant));
+                        let key_expr = f.from_variant(&quote!(__dict.get_ref(&__key)));
+                        return quote! {
+                            let #ident = #expr
+                                .or_else(|err| {
+                                    // fall back to the value written when it wasn't a dictionary
+                                    let __key = ::gdnative::GodotString::from(#name_string_literal).to_variant();
+                                    if __dict.contains(&__key) {
+                                        #key_expr
+                                    } else {
+                                        Err(err)
+                                    }
+                                })
+                                .map_err(|err| FVE::InvalidField {
+                                    field_name: #field_name_literal,
+                                    error: Box::new(err),
+                                })?;
+                        };
+                    }
 
-                let decl_idents = names.iter();
-                let ctor_idents = names.iter();
+                    let name_string_literal = Literal::string(&f.name);
+                    let expr = f.from_variant(&quote!(__dict.get_ref(&__key)));
+                    let missing_expr = if f.attr.default.is_some() {
+                        let default_value = f.default_value();
+                        quote!(Ok(#default_value))
+                    } else {
+                        // missing keys are treated as `Nil`
+                        f.from_variant(&quote!(&::gdnative::Variant::new()))
+                    };
+                    let expr = quote! {
+                        if __dict.contains(&__key) {
+                            #expr
+                        } else {
+                            #missing_expr
+                        }
+                    };
 
-                let expr_variant = &quote!(__dict.get_ref(&__key));
-                let exprs = fields.iter().map(|f| f.from_variant(expr_variant));
+                    quote! {
+                        let __field_name = #name_string_literal;
+                        let __key = ::gdnative::GodotString::from(__field_name).to_variant();
+                        let #ident = #expr
+                            .map_err(|err| FVE::InvalidField {
+                                field_name: __field_name,
+                                error: Box::new(err),
+                            })?;
+                    }
+                });
 
                 quote! {
                     {
@@ -226,15 +501,7 @@ impl VariantRepr {
                                 error: Box::new(__err),
                             })
                             .and_then(|__dict| {
-                                #(
-                                    let __field_name = #name_string_literals;
-                                    let __key = ::gdnative::GodotString::from(__field_name).to_variant();
-                                    let #decl_idents = #exprs
-                                        .map_err(|err| FVE::InvalidField {
-                                            field_name: __field_name,
-                                            error: Box::new(err),
-                                        })?;
-                                )*
+                                #( #decls )*
                                 Ok(#ctor { #( #ctor_idents ),* })
                             })
                     }
@@ -242,6 +509,15 @@ impl VariantRepr {
             }
         }
     }
+
+    fn field_idents(&self) -> Vec<&Ident> {
+        match self {
+            VariantRepr::Unit => Vec::new(),
+            VariantRepr::Tuple(fields) | VariantRepr::Struct(fields) => {
+                fields.iter().map(|f| &f.ident).collect()
+            }
+        }
+    }
 }
 
 impl Field {
@@ -261,4 +537,11 @@ impl Field {
             quote!(FromVariant::from_variant(#variant))
         }
     }
+
+    fn default_value(&self) -> TokenStream2 {
+        match &self.attr.default {
+            Some(FieldDefault::Path(path)) => quote!(#path()),
+            _ => quote!(::std::default::Default::default()),
+        }
+    }
 }
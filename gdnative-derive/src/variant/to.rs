@@ -1,7 +1,8 @@
 use proc_macro::TokenStream;
-use proc_macro2::Literal;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use syn::Ident;
 
-use super::repr::Repr;
+use super::repr::{EnumTagging, EnumVariant, Repr, VariantRepr};
 use super::DeriveData;
 
 pub(crate) fn expand_to_variant(derive_data: DeriveData) -> TokenStream {
@@ -15,6 +16,8 @@ pub(crate) fn expand_to_variant(derive_data: DeriveData) -> TokenStream {
         param.default = None;
     }
 
+    let mut export_impl = quote! {};
+
     let return_expr = match repr {
         Repr::Struct(var_repr) => {
             let destructure_pattern = var_repr.destructure_pattern();
@@ -26,29 +29,29 @@ pub(crate) fn expand_to_variant(derive_data: DeriveData) -> TokenStream {
                 }
             }
         }
-        Repr::Enum(variants) => {
+        Repr::Enum(tagging, variants) => {
+            if let EnumTagging::AsInt {
+                explicit_discriminants,
+            } = tagging
+            {
+                export_impl = expand_export(&ident, &variants, explicit_discriminants);
+            }
+
             if variants.is_empty() {
                 quote! {
                     unreachable!("this is an uninhabitable enum");
                 }
             } else {
-                let match_arms = variants
-                    .iter()
-                    .map(|(var_ident, var_repr)| {
-                        let destructure_pattern = var_repr.destructure_pattern();
-                        let to_variant = var_repr.to_variant();
-                        let var_ident_string = format!("{}", var_ident);
-                        let var_ident_string_literal = Literal::string(&var_ident_string);
-                        quote! {
-                            #ident::#var_ident #destructure_pattern => {
-                                let mut __dict = ::gdnative::Dictionary::new();
-                                let __key = ::gdnative::GodotString::from(#var_ident_string_literal).to_variant();
-                                let __value = #to_variant;
-                                __dict.set(&__key, &__value);
-                                __dict.to_variant()
-                            }
+                let match_arms = variants.iter().map(|variant| {
+                    let var_ident = &variant.ident;
+                    let destructure_pattern = variant.repr.destructure_pattern();
+                    let to_variant = enum_variant_to_variant(&ident, &tagging, variant);
+                    quote! {
+                        #ident::#var_ident #destructure_pattern => {
+                            #to_variant
                         }
-                    });
+                    }
+                });
 
                 quote! {
                     match &self {
@@ -70,7 +73,111 @@ pub(crate) fn expand_to_variant(derive_data: DeriveData) -> TokenStream {
                 #return_expr
             }
         }
+
+        #export_impl
     };
 
     result.into()
 }
+
+fn enum_variant_to_variant(
+    ident: &Ident,
+    tagging: &EnumTagging,
+    variant: &EnumVariant,
+) -> TokenStream2 {
+    let EnumVariant {
+        ident: var_ident,
+        name,
+        repr: var_repr,
+    } = variant;
+    let var_ident_string_literal = Literal::string(name);
+
+    match tagging {
+        EnumTagging::External => {
+            let to_variant = var_repr.to_variant();
+            quote! {
+                let mut __dict = ::gdnative::Dictionary::new();
+                let __key = ::gdnative::GodotString::from(#var_ident_string_literal).to_variant();
+                let __value = #to_variant;
+                __dict.set(&__key, &__value);
+                __dict.to_variant()
+            }
+        }
+        EnumTagging::Internal { tag } => {
+            let set_fields = var_repr.set_fields();
+            quote! {
+                let mut __dict = ::gdnative::Dictionary::new();
+                let __tag_key = ::gdnative::GodotString::from(#tag).to_variant();
+                let __tag = ::gdnative::GodotString::from(#var_ident_string_literal).to_variant();
+                __dict.set(&__tag_key, &__tag);
+                #set_fields
+                __dict.to_variant()
+            }
+        }
+        EnumTagging::Adjacent { tag, content } => {
+            let set_content = match var_repr {
+                VariantRepr::Unit => quote! {},
+                _ => {
+                    let to_variant = var_repr.to_variant();
+                    quote! {
+                        let __content_key = ::gdnative::GodotString::from(#content).to_variant();
+                        let __content = #to_variant;
+                        __dict.set(&__content_key, &__content);
+                    }
+                }
+            };
+            quote! {
+                let mut __dict = ::gdnative::Dictionary::new();
+                let __tag_key = ::gdnative::GodotString::from(#tag).to_variant();
+                let __tag = ::gdnative::GodotString::from(#var_ident_string_literal).to_variant();
+                __dict.set(&__tag_key, &__tag);
+                #set_content
+                __dict.to_variant()
+            }
+        }
+        EnumTagging::Untagged => match var_repr {
+            VariantRepr::Unit => quote! { ::gdnative::Variant::new() },
+            _ => var_repr.to_variant(),
+        },
+        EnumTagging::AsInt { .. } => quote! {
+            (#ident::#var_ident as i64).to_variant()
+        },
+    }
+}
+
+/// Exports fieldless enums as integer properties, hinted with the names of the variants.
+fn expand_export(
+    ident: &Ident,
+    variants: &[EnumVariant],
+    explicit_discriminants: bool,
+) -> TokenStream2 {
+    let names = variants.iter().map(
+        |EnumVariant {
+             ident: var_ident,
+             name,
+             ..
+         }| {
+            let var_ident_string_literal = Literal::string(name);
+            if explicit_discriminants {
+                quote! { format!("{}:{}", #var_ident_string_literal, #ident::#var_ident as i64) }
+            } else {
+                quote! { String::from(#var_ident_string_literal) }
+            }
+        },
+    );
+
+    quote! {
+        impl ::gdnative::init::property::Export for #ident {
+            type Hint = ::gdnative::init::property::hint::IntHint<i64>;
+
+            fn export_info(hint: Option<Self::Hint>) -> ::gdnative::init::property::ExportInfo {
+                hint.unwrap_or_else(|| {
+                    ::gdnative::init::property::hint::IntHint::Enum(
+                        ::gdnative::init::property::hint::EnumHint::new(vec![ #( #names ),* ]),
+                    )
+                })
+                .export_info()
+            }
+        }
+    }
+}
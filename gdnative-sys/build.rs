@@ -301,6 +301,7 @@ mod api_wrapper {
             .expect(&"File ({:?}) does not contain expected JSON");
         let struct_fields = godot_api_functions(&api_root);
         let impl_constructor = api_constructor(&api_root);
+        let impl_stub = api_stub(&api_root);
         let wrapper = quote! {
             pub struct GodotApi{
                 #struct_fields
@@ -308,6 +309,10 @@ mod api_wrapper {
             impl GodotApi {
                 #impl_constructor
             }
+            #[cfg(feature = "stub_api")]
+            impl GodotApi {
+                #impl_stub
+            }
         };
         let mut wrapper_file = File::create(to.join(file_name)).expect(&format!(
             "Couldn't create output file: {:?}",
@@ -361,6 +366,37 @@ mod api_wrapper {
         }
     }
 
+    fn api_stub(api: &ApiRoot) -> TokenStream {
+        let mut stub_struct_fields = TokenStream::new();
+        for api in api.all_apis() {
+            for function in &api.functions {
+                let function_name = function.rust_name();
+                let name = &function.name;
+                let arg_types = function.arguments.iter().map(|arg| arg.rust_type());
+                let return_type = function.rust_return_type();
+                stub_struct_fields.extend(quote! {
+                    #function_name: {
+                        unsafe extern "C" fn #function_name(#(_: #arg_types),*) -> #return_type {
+                            stub_api_called(#name)
+                        }
+                        #function_name
+                    },
+                });
+            }
+        }
+        quote! {
+            /// Returns an API table where every function aborts the process when called.
+            ///
+            /// Used as a base for in-process implementations of the API, such as the mock API
+            /// of `gdnative-core`, which replace the functions they implement.
+            pub fn stub() -> Self {
+                GodotApi{
+                    #stub_struct_fields
+                }
+            }
+        }
+    }
+
     fn parse_c_type(mut c_type: &str) -> (bool, i8, &str) {
         c_type = c_type.trim();
         let is_const = c_type.starts_with("const ");
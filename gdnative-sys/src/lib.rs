@@ -18,6 +18,17 @@ pub enum InitError {
     },
 }
 
+/// Reports a call to a function of `GodotApi::stub` and aborts the process.
+#[cfg(feature = "stub_api")]
+#[doc(hidden)]
+pub fn stub_api_called(name: &str) -> ! {
+    eprintln!(
+        "gdnative-sys: API function {} was called, but is not implemented by the stub API",
+        name
+    );
+    std::process::abort()
+}
+
 fn map_option_to_init_error<T>(t: Option<T>, message: &'static str) -> Result<T, InitError> {
     match t {
         Some(t) => Ok(t),
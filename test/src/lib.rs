@@ -1,9 +1,11 @@
 use gdnative::*;
 
+mod test_async;
 mod test_derive;
 mod test_free_ub;
 mod test_register;
 mod test_return_leak;
+mod test_serde;
 mod test_variant_call_args;
 
 #[no_mangle]
@@ -15,14 +17,19 @@ pub extern "C" fn run_tests(
     status &= gdnative::test_string();
 
     status &= gdnative::test_dictionary();
+    status &= gdnative::test_dictionary_iter();
+    status &= gdnative::test_typed_dictionary();
     // status &= gdnative::test_dictionary_clone_clear();
 
     status &= gdnative::test_array();
+    status &= gdnative::test_array_iter();
+    status &= gdnative::test_typed_array();
     // status &= gdnative::test_array_clone_clear();
 
     status &= gdnative::test_variant_nil();
     status &= gdnative::test_variant_i64();
     status &= gdnative::test_variant_bool();
+    status &= gdnative::test_variant_evaluate();
 
     status &= gdnative::test_vector2_variants();
 
@@ -32,6 +39,9 @@ pub extern "C" fn run_tests(
     status &= gdnative::test_variant_result();
     status &= gdnative::test_to_variant_iter();
     status &= gdnative::test_variant_tuple();
+    status &= gdnative::test_variant_collections();
+
+    status &= gdnative::marshal::test_marshal_variant_round_trip();
 
     status &= gdnative::test_byte_array_access();
     status &= gdnative::test_int32_array_access();
@@ -40,15 +50,18 @@ pub extern "C" fn run_tests(
     status &= gdnative::test_string_array_access();
     status &= gdnative::test_vector2_array_access();
     status &= gdnative::test_vector3_array_access();
+    status &= gdnative::test_pool_array_slices();
 
     status &= test_constructor();
     status &= test_underscore_method_binding();
     status &= test_rust_class_construction();
 
+    status &= test_async::run_tests();
     status &= test_derive::run_tests();
     status &= test_free_ub::run_tests();
     status &= test_register::run_tests();
     status &= test_return_leak::run_tests();
+    status &= test_serde::run_tests();
     status &= test_variant_call_args::run_tests();
 
     gdnative::Variant::from_bool(status).forget()
@@ -179,10 +192,12 @@ fn test_rust_class_construction() -> bool {
 fn init(handle: init::InitHandle) {
     handle.add_class::<Foo>();
 
+    test_async::register(&handle);
     test_derive::register(&handle);
     test_free_ub::register(&handle);
     test_register::register(&handle);
     test_return_leak::register(&handle);
+    test_serde::register(&handle);
     test_variant_call_args::register(&handle);
 }
 
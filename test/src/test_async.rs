@@ -0,0 +1,129 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gdnative::*;
+
+pub(crate) fn run_tests() -> bool {
+    let mut status = true;
+
+    status &= test_async_signal_future();
+    status &= test_async_method();
+
+    status
+}
+
+pub(crate) fn register(handle: &init::InitHandle) {
+    tasks::register_runtime(handle);
+    handle.add_class::<AsyncEmitter>();
+}
+
+struct AsyncEmitter;
+
+impl NativeClass for AsyncEmitter {
+    type Base = Reference;
+    type UserData = user_data::LocalCellData<AsyncEmitter>;
+    fn class_name() -> &'static str {
+        "AsyncEmitter"
+    }
+    fn init(_owner: Reference) -> AsyncEmitter {
+        AsyncEmitter
+    }
+    fn register_properties(builder: &init::ClassBuilder<Self>) {
+        builder.add_signal(init::Signal {
+            name: "ping",
+            args: &[init::SignalArgument {
+                name: "value",
+                default: Variant::new(),
+                export_info: init::ExportInfo::new(VariantType::I64),
+                usage: init::PropertyUsage::DEFAULT,
+            }],
+        });
+    }
+}
+
+#[methods]
+impl AsyncEmitter {
+    #[export]
+    async fn wait_for_ping(this: Instance<Self>, offset: i64) -> i64 {
+        let args = this.signal_future("ping").unwrap().await;
+        args[0].try_to_i64().unwrap() + offset
+    }
+}
+
+/// Spawns a task storing the output of `future` into the returned cell.
+fn spawn_into_cell(future: tasks::SignalFuture) -> Rc<RefCell<Option<Vec<Variant>>>> {
+    let cell = Rc::new(RefCell::new(None));
+    let task_cell = cell.clone();
+    tasks::spawn(async move {
+        let args = future.await;
+        *task_cell.borrow_mut() = Some(args);
+    });
+    cell
+}
+
+fn test_async_signal_future() -> bool {
+    println!(" -- test_async_signal_future");
+
+    let ok = std::panic::catch_unwind(|| {
+        let emitter = Instance::<AsyncEmitter>::new();
+
+        let result = spawn_into_cell(emitter.signal_future("ping").unwrap());
+
+        tasks::poll();
+        assert!(result.borrow().is_none());
+
+        let mut base = emitter.base().new_ref();
+        unsafe {
+            base.emit_signal("ping".into(), &[42.to_variant()]);
+        }
+
+        assert!(result.borrow().is_none());
+        tasks::poll();
+        assert_eq!(Some(vec![42.to_variant()]), *result.borrow());
+
+        // The connection is one-shot, so later emissions are ignored.
+        unsafe {
+            base.emit_signal("ping".into(), &[43.to_variant()]);
+        }
+        tasks::poll();
+        assert_eq!(Some(vec![42.to_variant()]), *result.borrow());
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_async_signal_future failed");
+    }
+
+    ok
+}
+
+fn test_async_method() -> bool {
+    println!(" -- test_async_method");
+
+    let ok = std::panic::catch_unwind(|| {
+        let emitter = Instance::<AsyncEmitter>::new();
+        let mut base = emitter.base().new_ref();
+
+        let state = unsafe { base.call("wait_for_ping".into(), &[1.to_variant()]) };
+        let state = state.try_to_object::<Reference>().unwrap();
+
+        let result = spawn_into_cell(state.signal_future("completed").unwrap());
+
+        tasks::poll();
+        assert!(result.borrow().is_none());
+
+        unsafe {
+            base.emit_signal("ping".into(), &[41.to_variant()]);
+        }
+
+        tasks::poll();
+        assert_eq!(Some(vec![42.to_variant()]), *result.borrow());
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_async_method failed");
+    }
+
+    ok
+}
@@ -4,6 +4,8 @@ pub(crate) fn run_tests() -> bool {
     let mut status = true;
 
     status &= test_derive_to_variant();
+    status &= test_derive_enum_repr();
+    status &= test_derive_field_attrs();
 
     status
 }
@@ -89,3 +91,253 @@ fn test_derive_to_variant() -> bool {
 
     ok
 }
+
+fn test_derive_enum_repr() -> bool {
+    println!(" -- test_derive_enum_repr");
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(tag = "type")]
+    enum Internal {
+        Foo,
+        Bar { bar: i64 },
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(tag = "t", content = "c")]
+    enum Adjacent {
+        Foo,
+        Bar(i64, bool),
+        Baz { baz: String },
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(untagged)]
+    enum Untagged {
+        Foo,
+        Bar(i64),
+        Baz { baz: String },
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(as_int)]
+    enum AsInt {
+        Foo,
+        Bar,
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(as_int)]
+    enum AsIntExplicit {
+        Foo = 1,
+        Bar = 4,
+    }
+
+    let ok = std::panic::catch_unwind(|| {
+        let dict = Internal::Bar { bar: 42 }
+            .to_variant()
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert_eq!(Some("Bar".into()), dict.get(&"type".into()).try_to_string());
+        assert_eq!(Some(42), dict.get(&"bar".into()).try_to_i64());
+        for value in &[Internal::Foo, Internal::Bar { bar: 42 }] {
+            assert_eq!(
+                Ok(value),
+                Internal::from_variant(&value.to_variant()).as_ref()
+            );
+        }
+
+        let dict = Adjacent::Bar(42, true)
+            .to_variant()
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert_eq!(Some("Bar".into()), dict.get(&"t".into()).try_to_string());
+        assert_eq!(
+            Some(2),
+            dict.get(&"c".into()).try_to_array().map(|arr| arr.len())
+        );
+        for value in &[
+            Adjacent::Foo,
+            Adjacent::Bar(42, true),
+            Adjacent::Baz { baz: "baz".into() },
+        ] {
+            assert_eq!(
+                Ok(value),
+                Adjacent::from_variant(&value.to_variant()).as_ref()
+            );
+        }
+
+        assert!(Untagged::Foo.to_variant().is_nil());
+        assert_eq!(Some(42), Untagged::Bar(42).to_variant().try_to_i64());
+        for value in &[
+            Untagged::Foo,
+            Untagged::Bar(42),
+            Untagged::Baz { baz: "baz".into() },
+        ] {
+            assert_eq!(
+                Ok(value),
+                Untagged::from_variant(&value.to_variant()).as_ref()
+            );
+        }
+        assert!(Untagged::from_variant(&"baz".to_variant()).is_err());
+
+        assert_eq!(Some(1), AsInt::Bar.to_variant().try_to_i64());
+        assert_eq!(Some(4), AsIntExplicit::Bar.to_variant().try_to_i64());
+        assert_eq!(
+            Ok(AsIntExplicit::Foo),
+            AsIntExplicit::from_variant(&1.to_variant())
+        );
+        assert!(AsIntExplicit::from_variant(&2.to_variant()).is_err());
+
+        use gdnative::init::property::Export;
+        let info = AsInt::export_info(None);
+        assert_eq!(VariantType::I64, info.variant_type);
+        assert_eq!(GodotString::from("Foo,Bar"), info.hint_string);
+        let info = AsIntExplicit::export_info(None);
+        assert_eq!(GodotString::from("Foo:1,Bar:4"), info.hint_string);
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_derive_enum_repr failed");
+    }
+
+    ok
+}
+
+fn test_derive_field_attrs() -> bool {
+    println!(" -- test_derive_field_attrs");
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(rename_all = "camelCase")]
+    struct Player {
+        max_hp: i64,
+        #[variant(rename = "NAME")]
+        name: String,
+        #[variant(skip)]
+        cache: Vec<i64>,
+        #[variant(default = "default_level")]
+        level: i64,
+        #[variant(flatten)]
+        stats: Stats,
+        #[variant(skip_to_variant, default)]
+        scratch: bool,
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    struct Stats {
+        strength: i64,
+        agility: i64,
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    struct Npc {
+        id: i64,
+        #[variant(flatten)]
+        stats: Option<Stats>,
+    }
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    struct Pair(i64, #[variant(skip)] Option<String>, bool);
+
+    #[derive(Clone, Eq, PartialEq, Debug, ToVariant, FromVariant)]
+    #[variant(rename_all = "snake_case")]
+    enum Spell {
+        FireBall,
+        IceShard { damage: i64 },
+    }
+
+    fn default_level() -> i64 {
+        1
+    }
+
+    let ok = std::panic::catch_unwind(|| {
+        let player = Player {
+            max_hp: 100,
+            name: "foo".into(),
+            cache: vec![1, 2, 3],
+            level: 5,
+            stats: Stats {
+                strength: 3,
+                agility: 4,
+            },
+            scratch: true,
+        };
+
+        let dict = player
+            .to_variant()
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert_eq!(Some(100), dict.get(&"maxHp".into()).try_to_i64());
+        assert_eq!(Some("foo".into()), dict.get(&"NAME".into()).try_to_string());
+        assert_eq!(Some(3), dict.get(&"strength".into()).try_to_i64());
+        assert_eq!(Some(4), dict.get(&"agility".into()).try_to_i64());
+        assert!(!dict.contains(&"cache".into()));
+        assert!(!dict.contains(&"scratch".into()));
+        assert!(!dict.contains(&"stats".into()));
+
+        let expected = Player {
+            cache: Vec::new(),
+            scratch: false,
+            ..player.clone()
+        };
+        assert_eq!(
+            Ok(&expected),
+            Player::from_variant(&dict.to_variant()).as_ref()
+        );
+
+        let mut dict = dict.new_ref();
+        dict.erase(&"level".into());
+        let from_dict = Player::from_variant(&dict.to_variant()).expect("should be valid");
+        assert_eq!(1, from_dict.level);
+
+        dict.erase(&"maxHp".into());
+        assert!(Player::from_variant(&dict.to_variant()).is_err());
+
+        // values that aren't dictionaries are kept under the name of the field
+        let npc = Npc { id: 1, stats: None };
+        let dict = npc
+            .to_variant()
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert!(dict.contains(&"stats".into()));
+        assert_eq!(Ok(&npc), Npc::from_variant(&dict.to_variant()).as_ref());
+
+        let npc = Npc {
+            id: 2,
+            stats: Some(Stats {
+                strength: 5,
+                agility: 6,
+            }),
+        };
+        let dict = npc
+            .to_variant()
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert!(!dict.contains(&"stats".into()));
+        assert_eq!(Ok(&npc), Npc::from_variant(&dict.to_variant()).as_ref());
+
+        let pair = Pair(42, Some("skipped".into()), true);
+        let arr = pair.to_variant().try_to_array().expect("should be array");
+        assert_eq!(2, arr.len());
+        assert_eq!(
+            Ok(Pair(42, None, true)),
+            Pair::from_variant(&arr.to_variant())
+        );
+
+        let dict = Spell::IceShard { damage: 10 }
+            .to_variant()
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert!(dict.contains(&"ice_shard".into()));
+        for spell in &[Spell::FireBall, Spell::IceShard { damage: 10 }] {
+            assert_eq!(Ok(spell), Spell::from_variant(&spell.to_variant()).as_ref());
+        }
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_derive_field_attrs failed");
+    }
+
+    ok
+}
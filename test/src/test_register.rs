@@ -4,6 +4,11 @@ pub(crate) fn run_tests() -> bool {
     let mut status = true;
 
     status &= test_register_property();
+    status &= test_register_property_attrs();
+    status &= test_register_property_groups();
+    status &= test_register_dynamic_properties();
+    status &= test_register_signal_defaults();
+    status &= test_register_typed_signal();
 
     status
 }
@@ -11,6 +16,10 @@ pub(crate) fn run_tests() -> bool {
 pub(crate) fn register(handle: &init::InitHandle) {
     handle.add_class::<RegisterSignal>();
     handle.add_class::<RegisterProperty>();
+    handle.add_class::<RegisterPropertyAttrs>();
+    handle.add_class::<RegisterPropertyGroups>();
+    handle.add_class::<RegisterDynamicProperties>();
+    handle.add_class::<RegisterTypedSignal>();
 }
 
 struct RegisterSignal;
@@ -34,12 +43,52 @@ impl NativeClass for RegisterSignal {
                 usage: gdnative::init::PropertyUsage::DEFAULT,
             }],
         });
+        builder.add_signal(gdnative::init::Signal {
+            name: "moved",
+            args: &[
+                init::SignalArgument::builder::<Vector2>("position").done(),
+                init::SignalArgument::builder::<f64>("speed")
+                    .with_default(1.5)
+                    .done(),
+                init::SignalArgument::builder::<i64>("steps")
+                    .with_default(3)
+                    .done(),
+            ],
+        });
     }
 }
 
 #[methods]
 impl RegisterSignal {}
 
+#[derive(GodotSignal)]
+enum TypedSignal {
+    Progress {
+        amount: i64,
+    },
+    #[signal(name = "done")]
+    Finished(#[signal(name = "message")] GodotString),
+}
+
+struct RegisterTypedSignal;
+
+impl NativeClass for RegisterTypedSignal {
+    type Base = Reference;
+    type UserData = user_data::ArcData<RegisterTypedSignal>;
+    fn class_name() -> &'static str {
+        "RegisterTypedSignal"
+    }
+    fn init(_owner: Reference) -> RegisterTypedSignal {
+        RegisterTypedSignal
+    }
+    fn register_properties(builder: &init::ClassBuilder<Self>) {
+        builder.add_signals::<TypedSignal>();
+    }
+}
+
+#[methods]
+impl RegisterTypedSignal {}
+
 struct RegisterProperty {
     value: i64,
 }
@@ -76,6 +125,299 @@ impl RegisterProperty {
     }
 }
 
+#[derive(NativeClass)]
+#[inherit(Reference)]
+#[user_data(user_data::MutexData<RegisterPropertyAttrs>)]
+struct RegisterPropertyAttrs {
+    #[property(hint(range(min = "-10", max = 10, step = 2, or_greater)))]
+    range: i64,
+    #[property(hint(enum("Foo", "Bar")), usage = "STORAGE | NETWORK")]
+    choice: i64,
+    #[property(hint(multiline))]
+    text: GodotString,
+    #[property(hint(exp_easing(inout)))]
+    easing: f64,
+    #[property(get = "Self::get_doubled", set = "Self::set_halved")]
+    doubled: i64,
+}
+
+impl RegisterPropertyAttrs {
+    fn _init(_owner: Reference) -> Self {
+        RegisterPropertyAttrs {
+            range: 0,
+            choice: 0,
+            text: GodotString::new(),
+            easing: 1.0,
+            doubled: 21,
+        }
+    }
+
+    fn get_doubled(&self, _owner: Reference) -> i64 {
+        self.doubled * 2
+    }
+
+    fn set_halved(&mut self, _owner: Reference, value: i64) {
+        self.doubled = value / 2;
+    }
+}
+
+#[methods]
+impl RegisterPropertyAttrs {}
+
+fn test_register_property_attrs() -> bool {
+    println!(" -- test_register_property_attrs");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<RegisterPropertyAttrs>::new();
+
+        let mut base = obj.into_base();
+
+        unsafe {
+            assert_eq!(Some(42), base.get("doubled".into()).try_to_i64());
+            base.set("doubled".into(), 10.to_variant());
+            assert_eq!(Some(10), base.get("doubled".into()).try_to_i64());
+
+            let properties = base.get_property_list();
+            let property = |name: &str| {
+                properties
+                    .iter()
+                    .filter_map(|property| property.try_to_dictionary())
+                    .find(|property| property.get(&"name".into()) == name.to_variant())
+                    .expect("property should be registered")
+            };
+
+            let range = property("range");
+            assert_eq!(
+                Some("-10,10,2,or_greater".into()),
+                range.get(&"hint_string".into()).try_to_string()
+            );
+
+            let choice = property("choice");
+            assert_eq!(
+                Some("Foo,Bar".into()),
+                choice.get(&"hint_string".into()).try_to_string()
+            );
+            assert_eq!(
+                Some(init::PropertyUsage::NOEDITOR.bits() as i64),
+                choice.get(&"usage".into()).try_to_i64()
+            );
+
+            let easing = property("easing");
+            assert_eq!(
+                Some("inout".into()),
+                easing.get(&"hint_string".into()).try_to_string()
+            );
+        }
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_register_property_attrs failed");
+    }
+
+    ok
+}
+
+#[derive(NativeClass)]
+#[inherit(Reference)]
+#[register_with(Self::register)]
+struct RegisterPropertyGroups {
+    #[property(group = "Movement")]
+    speed: f64,
+    #[property]
+    name: GodotString,
+    #[property(group = "Stats")]
+    health: i64,
+    #[property(group = "Movement")]
+    jump_height: f64,
+}
+
+impl RegisterPropertyGroups {
+    fn _init(_owner: Reference) -> Self {
+        RegisterPropertyGroups {
+            speed: 10.0,
+            name: GodotString::new(),
+            health: 100,
+            jump_height: 2.0,
+        }
+    }
+
+    fn register(builder: &init::ClassBuilder<Self>) {
+        builder
+            .add_property::<bool>("visible")
+            .with_default(true)
+            .with_getter(|_, _| true)
+            .done();
+        builder.add_property_category("Debug");
+        builder.add_property_group("Flags", "flag_");
+        builder
+            .add_property::<bool>("flag_verbose")
+            .with_default(false)
+            .with_getter(|_, _| false)
+            .done();
+    }
+}
+
+#[methods]
+impl RegisterPropertyGroups {}
+
+fn test_register_property_groups() -> bool {
+    println!(" -- test_register_property_groups");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<RegisterPropertyGroups>::new();
+
+        let base = obj.into_base();
+
+        unsafe {
+            let expected = [
+                ("name", init::PropertyUsage::DEFAULT),
+                ("visible", init::PropertyUsage::DEFAULT),
+                ("Debug", init::PropertyUsage::CATEGORY),
+                ("Flags", init::PropertyUsage::GROUP),
+                ("flag_verbose", init::PropertyUsage::DEFAULT),
+                ("Movement", init::PropertyUsage::GROUP),
+                ("speed", init::PropertyUsage::DEFAULT),
+                ("jump_height", init::PropertyUsage::DEFAULT),
+                ("Stats", init::PropertyUsage::GROUP),
+                ("health", init::PropertyUsage::DEFAULT),
+            ];
+
+            let properties = base
+                .get_property_list()
+                .iter()
+                .filter_map(|property| property.try_to_dictionary())
+                .filter(|property| {
+                    expected
+                        .iter()
+                        .any(|(name, _)| property.get(&"name".into()) == name.to_variant())
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(expected.len(), properties.len());
+
+            for ((name, usage), property) in expected.iter().zip(properties.iter()) {
+                assert_eq!(
+                    Some((*name).into()),
+                    property.get(&"name".into()).try_to_string()
+                );
+                assert_eq!(
+                    Some(usage.bits() as i64),
+                    property.get(&"usage".into()).try_to_i64()
+                );
+            }
+
+            let flags = &properties[3];
+            assert_eq!(
+                Some("flag_".into()),
+                flags.get(&"hint_string".into()).try_to_string()
+            );
+        }
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_register_property_groups failed");
+    }
+
+    ok
+}
+
+#[derive(NativeClass)]
+#[inherit(Reference)]
+#[user_data(user_data::MutexData<RegisterDynamicProperties>)]
+#[register_with(Self::register)]
+struct RegisterDynamicProperties {
+    stats: Vec<(String, i64)>,
+}
+
+impl RegisterDynamicProperties {
+    fn _init(_owner: Reference) -> Self {
+        RegisterDynamicProperties {
+            stats: vec![("strength".into(), 10), ("agility".into(), 5)],
+        }
+    }
+
+    fn register(builder: &init::ClassBuilder<Self>) {
+        builder.add_dynamic_properties();
+    }
+}
+
+#[methods]
+impl RegisterDynamicProperties {}
+
+impl init::DynamicProperties for RegisterDynamicProperties {
+    fn get_property(&self, _owner: Reference, name: &str) -> Option<Variant> {
+        self.stats
+            .iter()
+            .find(|(stat, _)| stat == name)
+            .map(|(_, value)| value.to_variant())
+    }
+
+    fn set_property(&mut self, _owner: Reference, name: &str, value: Variant) -> bool {
+        let stat = self.stats.iter_mut().find(|(stat, _)| stat == name);
+        match (stat, value.try_to_i64()) {
+            (Some((_, stat)), Some(value)) => {
+                *stat = value;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn property_list(&self, _owner: Reference) -> Vec<init::PropertyInfo> {
+        self.stats
+            .iter()
+            .map(|(name, _)| {
+                init::PropertyInfo::of::<i64, _>(name.as_str(), Some((0..=100).into()))
+            })
+            .collect()
+    }
+}
+
+fn test_register_dynamic_properties() -> bool {
+    println!(" -- test_register_dynamic_properties");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<RegisterDynamicProperties>::new();
+
+        let mut base = obj.into_base();
+
+        unsafe {
+            assert_eq!(Some(10), base.get("strength".into()).try_to_i64());
+            base.set("agility".into(), 7.to_variant());
+            assert_eq!(Some(7), base.get("agility".into()).try_to_i64());
+            assert!(base.get("charisma".into()).is_nil());
+
+            let properties = base
+                .get_property_list()
+                .iter()
+                .filter_map(|property| property.try_to_dictionary())
+                .collect::<Vec<_>>();
+
+            let strength = properties
+                .iter()
+                .find(|property| property.get(&"name".into()) == "strength".to_variant())
+                .expect("dynamic property should be listed");
+            assert_eq!(
+                Some(VariantType::I64 as i64),
+                strength.get(&"type".into()).try_to_i64()
+            );
+            assert_eq!(
+                Some("0,100".into()),
+                strength.get(&"hint_string".into()).try_to_string()
+            );
+        }
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_register_dynamic_properties failed");
+    }
+
+    ok
+}
+
 fn test_register_property() -> bool {
     println!(" -- test_register_property");
 
@@ -104,3 +446,78 @@ fn test_register_property() -> bool {
 
     ok
 }
+
+fn test_register_typed_signal() -> bool {
+    println!(" -- test_register_typed_signal");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<RegisterTypedSignal>::new();
+        let base = obj.base();
+
+        let progress = obj.signal_future("progress").unwrap();
+        let done = obj.signal_future("done").unwrap();
+
+        let results = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let task_results = results.clone();
+        tasks::spawn(async move {
+            task_results.borrow_mut().push(progress.await);
+            task_results.borrow_mut().push(done.await);
+        });
+
+        unsafe {
+            TypedSignal::emit_progress(base, 42);
+            TypedSignal::Finished("ok".into()).emit_to(base);
+        }
+
+        tasks::poll();
+
+        assert_eq!(
+            vec![
+                vec![42.to_variant()],
+                vec![GodotString::from_str("ok").to_variant()],
+            ],
+            *results.borrow()
+        );
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_register_typed_signal failed");
+    }
+
+    ok
+}
+
+fn test_register_signal_defaults() -> bool {
+    println!(" -- test_register_signal_defaults");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<RegisterSignal>::new();
+
+        let signals = unsafe { obj.base().get_signal_list() };
+        let moved = signals
+            .iter()
+            .filter_map(|signal| signal.try_to_dictionary())
+            .find(|signal| signal.get(&"name".to_variant()) == "moved".to_variant())
+            .expect("signal should be registered");
+
+        let default_args = moved.get(&"default_args".to_variant()).to_array();
+        assert_eq!(2, default_args.len());
+        assert_eq!(Some(1.5), default_args.get_ref(0).try_to_f64());
+        assert_eq!(Some(3), default_args.get_ref(1).try_to_i64());
+
+        let args = moved.get(&"args".to_variant()).to_array();
+        let position = args.get_ref(0).to_dictionary();
+        assert_eq!(
+            Some(VariantType::Vector2 as i64),
+            position.get(&"type".to_variant()).try_to_i64()
+        );
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_register_signal_defaults failed");
+    }
+
+    ok
+}
@@ -0,0 +1,173 @@
+use gdnative::*;
+use ::serde::{Deserialize, Serialize};
+
+pub(crate) fn run_tests() -> bool {
+    let mut status = true;
+
+    status &= test_serde_to_from_variant();
+    status &= test_serde_typed_variants();
+    status &= test_serde_variant_json();
+
+    status
+}
+
+pub(crate) fn register(_handle: &init::InitHandle) {}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct SaveGame {
+    level: String,
+    score: u32,
+    position: Vector2,
+    tint: Color,
+    inventory: Vec<Item>,
+    checkpoint: Option<i64>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+enum Item {
+    Key,
+    Potion(u8),
+    Weapon { name: String, damage: f32 },
+}
+
+mod user {
+    use ::serde::{Deserialize, Serialize};
+
+    /// Has the same name as `gdnative::Variant`, which must not change how it is serialized.
+    #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+    pub(crate) enum Variant {
+        Nil,
+        Bool(bool),
+    }
+}
+
+fn test_serde_to_from_variant() -> bool {
+    println!(" -- test_serde_to_from_variant");
+
+    let ok = std::panic::catch_unwind(|| {
+        let save = SaveGame {
+            level: "dungeon".into(),
+            score: 42,
+            position: Vector2::new(1.0, 2.0),
+            tint: Color::rgba(0.5, 0.25, 1.0, 1.0),
+            inventory: vec![
+                Item::Key,
+                Item::Potion(3),
+                Item::Weapon {
+                    name: "sword".into(),
+                    damage: 1.5,
+                },
+            ],
+            checkpoint: None,
+        };
+
+        let variant = gdnative::serde::to_variant(&save).expect("should serialize");
+        let dictionary = variant.try_to_dictionary().expect("should be dictionary");
+        assert_eq!(Some(42), dictionary.get(&"score".into()).try_to_i64());
+        assert_eq!(
+            Some("dungeon".into()),
+            dictionary.get(&"level".into()).try_to_string()
+        );
+        assert!(dictionary.get(&"checkpoint".into()).is_nil());
+
+        let mut inventory = dictionary
+            .get(&"inventory".into())
+            .try_to_array()
+            .expect("should be array");
+        assert_eq!(Some("Key".into()), inventory.get_val(0).try_to_string());
+        let potion = inventory
+            .get_val(1)
+            .try_to_dictionary()
+            .expect("should be dictionary");
+        assert_eq!(Some(3), potion.get(&"Potion".into()).try_to_i64());
+
+        assert_eq!(Ok(save), gdnative::serde::from_variant(&variant));
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_serde_to_from_variant failed");
+    }
+
+    ok
+}
+
+fn test_serde_typed_variants() -> bool {
+    println!(" -- test_serde_typed_variants");
+
+    let ok = std::panic::catch_unwind(|| {
+        let vector = Vector3::new(1.0, 2.0, 3.0);
+        let variant = Variant::from_vector3(&vector);
+        assert_eq!(Ok(vector), gdnative::serde::from_variant(&variant));
+
+        let copy = gdnative::serde::to_variant(&variant).expect("should serialize");
+        assert_eq!(Some(vector), copy.try_to_vector3());
+
+        let mut ints = Int32Array::new();
+        ints.push(1);
+        ints.push(2);
+        let copy = gdnative::serde::to_variant(&ints.to_variant()).expect("should serialize");
+        let ints = copy.try_to_int32_array().expect("should be Int32Array");
+        assert_eq!(&[1, 2], &*ints.read());
+
+        let color = Color::rgb(1.0, 0.0, 0.0);
+        let loose = gdnative::serde::to_variant(&color).expect("should serialize");
+        assert_eq!(VariantType::Dictionary, loose.get_type());
+        assert_eq!(Ok(color), gdnative::serde::from_variant(&loose));
+
+        let nil = gdnative::serde::to_variant(&user::Variant::Nil).expect("should serialize");
+        assert_eq!(Some("Nil".into()), nil.try_to_string());
+        assert_eq!(Ok(user::Variant::Nil), gdnative::serde::from_variant(&nil));
+
+        let tagged =
+            gdnative::serde::to_variant(&user::Variant::Bool(true)).expect("should serialize");
+        let dictionary = tagged.try_to_dictionary().expect("should be dictionary");
+        assert_eq!(Some(true), dictionary.get(&"Bool".into()).try_to_bool());
+        assert_eq!(
+            Ok(user::Variant::Bool(true)),
+            gdnative::serde::from_variant(&tagged)
+        );
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_serde_typed_variants failed");
+    }
+
+    ok
+}
+
+fn test_serde_variant_json() -> bool {
+    println!(" -- test_serde_variant_json");
+
+    let ok = std::panic::catch_unwind(|| {
+        let mut dictionary = Dictionary::new();
+        dictionary.set(&"name".into(), &"player".into());
+        dictionary.set(&"hp".into(), &Variant::from_i64(100));
+        dictionary.set(&"position".into(), &Vector2::new(1.0, 2.0).to_variant());
+        let variant = dictionary.to_variant();
+
+        let json = serde_json::to_string(&variant).expect("should serialize");
+        assert!(json.contains(r#""position":{"Vector2":[1.0,2.0]}"#));
+        assert!(json.contains(r#""hp":100"#));
+
+        let back: Variant = serde_json::from_str(&json).expect("should deserialize");
+        let back = back.try_to_dictionary().expect("should be dictionary");
+        assert_eq!(Some(100), back.get(&"hp".into()).try_to_i64());
+        assert_eq!(
+            Some("player".into()),
+            back.get(&"name".into()).try_to_string()
+        );
+        assert_eq!(
+            Some(Vector2::new(1.0, 2.0)),
+            back.get(&"position".into()).try_to_vector2()
+        );
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_serde_variant_json failed");
+    }
+
+    ok
+}
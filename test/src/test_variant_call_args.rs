@@ -4,6 +4,8 @@ pub(crate) fn run_tests() -> bool {
     let mut status = true;
 
     status &= test_variant_call_args();
+    status &= test_variant_call_optional_args();
+    status &= test_variant_call_var_args();
 
     status
 }
@@ -47,6 +49,32 @@ impl VariantCallArgs {
     fn three(&mut self, _owner: Reference, a: i32, b: i32, c: i32) -> i32 {
         a * 42 + b * c
     }
+
+    #[export]
+    fn optional(
+        &mut self,
+        _owner: Reference,
+        a: i32,
+        #[opt] b: i32,
+        #[default = 2] c: i32,
+        d: Option<i32>,
+    ) -> i32 {
+        a * 42 + b * c + d.unwrap_or(-1)
+    }
+
+    #[export]
+    fn count(&mut self, _owner: Reference, a: i32, args: &[Variant]) -> i32 {
+        a * 42 + args.len() as i32
+    }
+
+    #[export]
+    fn sum(&mut self, _owner: Reference, mut args: init::VarArgs) -> Option<i64> {
+        let mut sum = 0;
+        while !args.is_empty() {
+            sum += args.next::<i64>().ok()?;
+        }
+        Some(sum)
+    }
 }
 
 fn test_variant_call_args() -> bool {
@@ -101,3 +129,102 @@ fn test_variant_call_args() -> bool {
 
     ok
 }
+
+fn test_variant_call_optional_args() -> bool {
+    println!(" -- test_variant_call_optional_args");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<VariantCallArgs>::new();
+
+        let mut base = obj.into_base().to_variant();
+
+        let mut call = |args: &[i64]| {
+            let args = args
+                .iter()
+                .map(|&a| Variant::from_i64(a))
+                .collect::<Vec<_>>();
+            base.call(&"optional".into(), &args).unwrap().try_to_i64()
+        };
+
+        assert_eq!(None, call(&[]));
+        assert_eq!(Some(41), call(&[1]));
+        assert_eq!(Some(47), call(&[1, 3]));
+        assert_eq!(Some(53), call(&[1, 3, 4]));
+        assert_eq!(Some(59), call(&[1, 3, 4, 5]));
+        assert_eq!(None, call(&[1, 3, 4, 5, 6]));
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_variant_call_optional_args failed");
+    }
+
+    ok
+}
+
+fn test_variant_call_var_args() -> bool {
+    println!(" -- test_variant_call_var_args");
+
+    let ok = std::panic::catch_unwind(|| {
+        let obj = Instance::<VariantCallArgs>::new();
+
+        let mut base = obj.into_base().to_variant();
+
+        let mut call =
+            |method: &str, args: &[Variant]| base.call(&method.into(), args).unwrap().try_to_i64();
+
+        assert_eq!(None, call("count", &[]));
+        assert_eq!(Some(42), call("count", &[Variant::from_i64(1)]));
+        assert_eq!(
+            Some(44),
+            call(
+                "count",
+                &[
+                    Variant::from_i64(1),
+                    Variant::new(),
+                    Variant::from_str("foo"),
+                ]
+            )
+        );
+
+        assert_eq!(Some(0), call("sum", &[]));
+        assert_eq!(
+            Some(6),
+            call(
+                "sum",
+                &[
+                    Variant::from_i64(1),
+                    Variant::from_i64(2),
+                    Variant::from_i64(3),
+                ]
+            )
+        );
+        assert_eq!(
+            None,
+            call("sum", &[Variant::from_i64(1), Variant::from_str("foo")])
+        );
+
+        let args = [Variant::from_i64(1), Variant::from_str("foo")];
+        let mut args = init::VarArgs::new(&args, 2);
+        assert_eq!(Ok(1), args.next::<i64>());
+        assert_eq!(
+            Some(3),
+            match args.next::<i64>() {
+                Err(init::ArgumentError::InvalidType { index, .. }) => Some(index),
+                _ => None,
+            }
+        );
+        assert_eq!(
+            Err(init::ArgumentError::Missing { index: 4 }),
+            args.next::<i64>()
+        );
+        assert_eq!(Ok(()), args.done());
+    })
+    .is_ok();
+
+    if !ok {
+        godot_error!("   !! Test test_variant_call_var_args failed");
+    }
+
+    ok
+}